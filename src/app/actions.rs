@@ -2,6 +2,7 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use super::commands::ActionCommand;
+use crate::containers::ContainerControlAction;
 use crate::services::ServiceControlAction;
 
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
@@ -77,9 +78,9 @@ impl ActionPlan {
                 true,
                 None,
             ),
-            ActionCommand::SetAffinity { pid, mask } => Self::new(
+            ActionCommand::SetAffinity { pid, mask, group } => Self::new(
                 command.clone(),
-                format!("Set process {pid} CPU affinity to {mask:#x}"),
+                format!("Set process {pid} CPU affinity to {group} ({mask:#x})"),
                 "Constrains execution to specified logical CPU processor cores.",
                 RiskLevel::Medium,
                 true,
@@ -88,7 +89,19 @@ impl ActionPlan {
             ActionCommand::CleanRam => Self::new(
                 command.clone(),
                 "Trim process working sets".into(),
-                "Windows may need to page trimmed memory back in; short-lived slowdowns are possible.",
+                if cfg!(target_os = "windows") {
+                    "Windows may need to page trimmed memory back in; short-lived slowdowns are possible."
+                } else {
+                    "Idle process memory will be paged out; it is read back from swap or disk on next use."
+                },
+                RiskLevel::Medium,
+                true,
+                None,
+            ),
+            ActionCommand::DropPageCache => Self::new(
+                command.clone(),
+                "Drop the page cache".into(),
+                "Dirty pages are flushed and cached file data is discarded; disk reads will be slower until it refills.",
                 RiskLevel::Medium,
                 true,
                 None,
@@ -122,14 +135,68 @@ impl ActionPlan {
                     undo,
                 )
             }
-            ActionCommand::SetPowerPlan(guid) => Self::new(
-                command.clone(),
-                "Change active power plan".into(),
-                format!("Windows will activate power scheme {guid}."),
-                RiskLevel::Low,
-                false,
-                None,
-            ),
+            ActionCommand::ControlContainer {
+                socket,
+                id,
+                name,
+                action,
+            } => {
+                let undo_with = |action| {
+                    Some(ActionCommand::ControlContainer {
+                        socket: socket.clone(),
+                        id: id.clone(),
+                        name: name.clone(),
+                        action,
+                    })
+                };
+                let (verb, summary, risk, undo) = match action {
+                    ContainerControlAction::Start => (
+                        "Start",
+                        "The container's entrypoint will run again with its existing configuration.",
+                        RiskLevel::Medium,
+                        undo_with(ContainerControlAction::Stop),
+                    ),
+                    ContainerControlAction::Stop => (
+                        "Stop",
+                        "The engine sends SIGTERM and kills the container after its grace period; clients lose their connections.",
+                        RiskLevel::High,
+                        undo_with(ContainerControlAction::Start),
+                    ),
+                    ContainerControlAction::Restart => (
+                        "Restart",
+                        "The container is stopped and started again; in-flight requests and unsaved in-memory state are lost.",
+                        RiskLevel::High,
+                        None,
+                    ),
+                };
+                // Access is governed by the socket's permissions (e.g. the docker group), not elevation.
+                Self::new(
+                    command.clone(),
+                    format!("{verb} container {name}"),
+                    summary,
+                    risk,
+                    false,
+                    undo,
+                )
+            }
+            ActionCommand::SetPowerPlan(guid) => {
+                let summary = if cfg!(target_os = "windows") {
+                    format!("Windows will activate power scheme {guid}.")
+                } else {
+                    format!("The {guid} power profile will be activated.")
+                };
+                let mut plan = Self::new(
+                    command.clone(),
+                    "Change active power plan".into(),
+                    summary,
+                    RiskLevel::Low,
+                    crate::power::plan_requires_admin(guid),
+                    None,
+                );
+                // The undo target is whichever plan is active when the switch runs.
+                plan.reversible = true;
+                plan
+            }
             ActionCommand::DisableStartup { item_name, locator } => Self::new(
                 command.clone(),
                 format!("Disable startup item {item_name}"),
@@ -258,6 +325,33 @@ mod tests {
         assert!(!plan.reversible);
     }
 
+    #[test]
+    fn power_plan_switch_is_reversible() {
+        let plan = ActionPlan::from_command(ActionCommand::SetPowerPlan("ppd:performance".into()));
+        assert!(plan.reversible);
+        assert!(plan.undo.is_none());
+        assert!(matches!(plan.risk, RiskLevel::Low));
+    }
+
+    #[test]
+    fn container_stop_is_high_risk_with_start_undo() {
+        let plan = ActionPlan::from_command(ActionCommand::ControlContainer {
+            socket: "/var/run/docker.sock".into(),
+            id: "abc".into(),
+            name: "web".into(),
+            action: ContainerControlAction::Stop,
+        });
+        assert!(matches!(plan.risk, RiskLevel::High));
+        assert_eq!(plan.title, "Stop container web");
+        assert!(matches!(
+            plan.undo,
+            Some(ActionCommand::ControlContainer {
+                action: ContainerControlAction::Start,
+                ..
+            })
+        ));
+    }
+
     #[test]
     fn suspend_has_resume_undo() {
         let plan = ActionPlan::from_command(ActionCommand::SuspendProcess(42));
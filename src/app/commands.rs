@@ -1,4 +1,4 @@
-use crate::{services::ServiceControlAction, startup::StartupLocator, AppSettings};
+use crate::{containers::ContainerControlAction, services::ServiceControlAction, startup::StartupLocator, AppSettings};
 
 #[derive(Debug, Clone)]
 pub(crate) enum MonitoringCommand {
@@ -19,13 +19,44 @@ pub(crate) enum ActionCommand {
     KillProcessTree(u32),
     SuspendProcess(u32),
     ResumeProcess(u32),
-    SetPriority { pid: u32, priority: String },
-    SetAffinity { pid: u32, mask: usize },
+    SetPriority {
+        pid: u32,
+        priority: String,
+    },
+    /// `group` names the CPU set `mask` was built from, for confirmation and audit text.
+    SetAffinity {
+        pid: u32,
+        mask: usize,
+        group: String,
+    },
     CleanRam,
-    ControlService { name: String, action: ServiceControlAction },
+    DropPageCache,
+    ControlService {
+        name: String,
+        action: ServiceControlAction,
+    },
+    /// `socket` is the Engine API socket the container was listed from.
+    ControlContainer {
+        socket: std::path::PathBuf,
+        id: String,
+        name: String,
+        action: ContainerControlAction,
+    },
     SetPowerPlan(String),
-    DisableStartup { item_name: String, locator: StartupLocator },
-    EnableStartup { item_name: String, locator: StartupLocator },
-    QuarantineStartup { item_name: String, locator: StartupLocator },
-    RestoreStartup { item_name: String, quarantine_id: String },
+    DisableStartup {
+        item_name: String,
+        locator: StartupLocator,
+    },
+    EnableStartup {
+        item_name: String,
+        locator: StartupLocator,
+    },
+    QuarantineStartup {
+        item_name: String,
+        locator: StartupLocator,
+    },
+    RestoreStartup {
+        item_name: String,
+        quarantine_id: String,
+    },
 }
@@ -84,6 +84,7 @@ pub(crate) struct CpuCoreInfo {
     pub(crate) usage: f32,
     #[allow(dead_code)]
     pub(crate) name: String,
+    pub(crate) frequency_mhz: u64,
 }
 
 #[derive(Clone, Serialize)]
@@ -109,13 +110,22 @@ pub(crate) fn cpu_cores_from_telemetry(snapshot: &crate::telemetry::TelemetrySna
                     core_id,
                     usage: *usage as f32,
                     name: format!("Core {core_id}"),
+                    frequency_mhz: snapshot
+                        .metrics
+                        .get(&format!("cpu.core.{core_id}.frequency"))
+                        .map_or(0, |mhz| *mhz as u64),
                 })
         })
         .collect()
 }
 
 pub(crate) fn gpus_from_telemetry(snapshot: &crate::telemetry::TelemetrySnapshot) -> Vec<GpuInfo> {
-    let count = snapshot.metrics.get("gpu.device_count").copied().unwrap_or_default() as usize;
+    // NVML and the Linux DRM provider both publish `gpu.device_count`, numbering
+    // their devices one after the other, so also count the run of named devices.
+    let named = (0..)
+        .take_while(|index| snapshot.labels.contains_key(&format!("gpu.{index}.name")))
+        .count();
+    let count = (snapshot.metrics.get("gpu.device_count").copied().unwrap_or_default() as usize).max(named);
     let mut gpus: Vec<_> = (0..count)
         .map(|index| {
             let prefix = format!("gpu.{index}");
@@ -176,6 +186,155 @@ pub(crate) fn gpus_from_telemetry(snapshot: &crate::telemetry::TelemetrySnapshot
     gpus
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub(crate) struct PciDeviceInfo {
+    /// Bus address such as `0000:01:00.0`.
+    pub(crate) address: String,
+    pub(crate) vendor_id: String,
+    pub(crate) device_id: String,
+    pub(crate) class: String,
+    pub(crate) vendor: Option<String>,
+    pub(crate) name: Option<String>,
+    pub(crate) driver: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub(crate) struct UsbDeviceInfo {
+    /// Bus and port path such as `1-2.3`.
+    pub(crate) address: String,
+    pub(crate) vendor_id: String,
+    pub(crate) product_id: String,
+    pub(crate) manufacturer: Option<String>,
+    pub(crate) product: Option<String>,
+    pub(crate) speed_mbps: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub(crate) struct MemoryModuleInfo {
+    pub(crate) locator: String,
+    pub(crate) bank: Option<String>,
+    pub(crate) kind: Option<String>,
+    pub(crate) manufacturer: Option<String>,
+    pub(crate) part_number: Option<String>,
+    pub(crate) size_bytes: u64,
+    pub(crate) speed_mts: Option<u64>,
+}
+
+/// Device lists for the System Info page, from the Linux inventory provider.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub(crate) struct HardwareInventory {
+    pub(crate) pci_devices: Vec<PciDeviceInfo>,
+    pub(crate) usb_devices: Vec<UsbDeviceInfo>,
+    pub(crate) memory_modules: Vec<MemoryModuleInfo>,
+}
+
+pub(crate) fn inventory_from_telemetry(snapshot: &crate::telemetry::TelemetrySnapshot) -> HardwareInventory {
+    let count = |key: &str| snapshot.metrics.get(key).copied().unwrap_or_default() as usize;
+    // Optional device fields are published empty when absent.
+    let label = |prefix: &str, name: &str| {
+        snapshot
+            .labels
+            .get(&format!("{prefix}.{name}"))
+            .filter(|value| !value.is_empty())
+            .cloned()
+    };
+    let required = |prefix: &str, name: &str| label(prefix, name).unwrap_or_default();
+    HardwareInventory {
+        pci_devices: (0..count("pci.count"))
+            .map(|index| {
+                let prefix = format!("pci.{index}");
+                PciDeviceInfo {
+                    address: required(&prefix, "address"),
+                    vendor_id: required(&prefix, "vendor_id"),
+                    device_id: required(&prefix, "device_id"),
+                    class: required(&prefix, "class"),
+                    vendor: label(&prefix, "vendor"),
+                    name: label(&prefix, "name"),
+                    driver: label(&prefix, "driver"),
+                }
+            })
+            .collect(),
+        usb_devices: (0..count("usb.count"))
+            .map(|index| {
+                let prefix = format!("usb.{index}");
+                UsbDeviceInfo {
+                    address: required(&prefix, "address"),
+                    vendor_id: required(&prefix, "vendor_id"),
+                    product_id: required(&prefix, "product_id"),
+                    manufacturer: label(&prefix, "manufacturer"),
+                    product: label(&prefix, "product"),
+                    speed_mbps: label(&prefix, "speed_mbps"),
+                }
+            })
+            .collect(),
+        memory_modules: (0..count("memory.dimm_count"))
+            .map(|index| {
+                let prefix = format!("memory.dimm.{index}");
+                let metric = |name: &str| {
+                    snapshot
+                        .metrics
+                        .get(&format!("{prefix}.{name}"))
+                        .map(|value| *value as u64)
+                };
+                MemoryModuleInfo {
+                    locator: required(&prefix, "locator"),
+                    bank: label(&prefix, "bank"),
+                    kind: label(&prefix, "type"),
+                    manufacturer: label(&prefix, "manufacturer"),
+                    part_number: label(&prefix, "part_number"),
+                    size_bytes: metric("size_bytes").unwrap_or_default(),
+                    speed_mts: metric("speed_mts"),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Containers from the Engine API provider; empty while the provider is failing,
+/// since its last labels would otherwise linger.
+pub(crate) fn containers_from_telemetry(
+    snapshot: &crate::telemetry::TelemetrySnapshot,
+) -> Vec<crate::containers::ContainerInfo> {
+    if snapshot.provider_status.get("containers") != Some(&true) {
+        return Vec::new();
+    }
+    let count = snapshot.metrics.get("container.count").copied().unwrap_or_default() as usize;
+    (0..count)
+        .map(|index| {
+            let label = |name: &str| {
+                snapshot
+                    .labels
+                    .get(&format!("container.{index}.{name}"))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let metric = |name: &str| {
+                snapshot
+                    .metrics
+                    .get(&format!("container.{index}.{name}"))
+                    .copied()
+                    .unwrap_or_default()
+            };
+            let memory_limit = metric("memory_limit_bytes") as u64;
+            crate::containers::ContainerInfo {
+                id: label("id"),
+                name: label("name"),
+                image: label("image"),
+                state: label("state"),
+                status: label("status"),
+                pid: label("pid").parse().ok(),
+                cpu_usage: metric("cpu_percent") as f32,
+                memory_usage: metric("memory_bytes") as u64,
+                memory_limit: (memory_limit > 0).then_some(memory_limit),
+                net_rx_rate: metric("net_rx_bytes_per_sec"),
+                net_tx_rate: metric("net_tx_bytes_per_sec"),
+                block_read_rate: metric("block_read_bytes_per_sec"),
+                block_write_rate: metric("block_write_bytes_per_sec"),
+            }
+        })
+        .collect()
+}
+
 #[derive(Clone, Serialize)]
 pub(crate) struct DiskInfo {
     pub(crate) name: String,
@@ -184,6 +343,8 @@ pub(crate) struct DiskInfo {
     pub(crate) available_space: u64,
     pub(crate) usage_percentage: f32,
     pub(crate) file_system: String,
+    /// Kernel name of the backing disk (`nvme0n1`), for matching per-device I/O statistics.
+    pub(crate) block_device: Option<String>,
 }
 
 #[derive(Clone, Serialize)]
@@ -193,6 +354,11 @@ pub(crate) struct NetworkInfo {
     pub(crate) transmitted: u64,
     pub(crate) received_rate: f64,
     pub(crate) transmitted_rate: f64,
+    pub(crate) details: crate::network::InterfaceDetails,
+    /// Receive plus transmit errors per second.
+    pub(crate) error_rate: f64,
+    /// Receive plus transmit drops per second.
+    pub(crate) drop_rate: f64,
 }
 
 #[derive(Clone)]
@@ -279,6 +445,37 @@ pub(crate) struct SystemInfo {
     pub(crate) bios_version: Option<String>,
     pub(crate) gpu_driver: Option<String>,
     pub(crate) os_build: Option<String>,
+    /// Hypervisor this system runs under, with the detection source.
+    pub(crate) hypervisor: Option<String>,
+}
+
+impl SystemInfo {
+    /// Fill motherboard and BIOS fields the platform query left empty from the
+    /// `board.*` / `bios.*` labels of the WMI or Linux inventory providers, and
+    /// the hypervisor from the virtualization provider.
+    pub(crate) fn fill_from_labels(&mut self, labels: &std::collections::HashMap<String, String>) {
+        if self.motherboard.is_none() {
+            let board: Vec<&str> = ["board.manufacturer", "board.product"]
+                .iter()
+                .filter_map(|key| labels.get(*key).map(String::as_str))
+                .collect();
+            if !board.is_empty() {
+                self.motherboard = Some(board.join(" "));
+            }
+        }
+        if self.bios_version.is_none() {
+            self.bios_version = labels.get("bios.version").map(|version| match labels.get("bios.date") {
+                Some(date) => format!("{version} ({date})"),
+                None => version.clone(),
+            });
+        }
+        self.hypervisor = labels
+            .get("virt.hypervisor")
+            .map(|name| match labels.get("virt.source") {
+                Some(source) => format!("{name} (via {source})"),
+                None => name.clone(),
+            });
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -289,6 +486,18 @@ pub enum AppTheme {
     System,
 }
 
+/// Which memory figure the memory alert compares against its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MemoryAlertBasis {
+    /// Alert when used memory rises above `notification_memory_threshold`.
+    #[default]
+    Used,
+    /// Alert when available memory falls below `notification_memory_available_threshold`.
+    /// Page cache counts as used but is given back on demand, so this avoids
+    /// alerting on a machine that is merely caching files.
+    Available,
+}
+
 fn deserialize_app_theme<'de, D>(deserializer: D) -> Result<AppTheme, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -377,11 +586,18 @@ pub(crate) struct AppSettings {
     #[serde(default = "default_notification_disk_threshold")]
     pub(crate) notification_disk_threshold: f32,
     #[serde(default)]
+    pub(crate) memory_alert_basis: MemoryAlertBasis,
+    #[serde(default = "default_notification_memory_available_threshold")]
+    pub(crate) notification_memory_available_threshold: f32,
+    #[serde(default)]
     pub(crate) sidebar_collapsed: bool,
     #[serde(default)]
     pub(crate) timeline_enabled: bool,
     #[serde(default = "default_timeline_retention_days")]
     pub(crate) timeline_retention_days: u16,
+    /// Docker/Podman Engine API socket; empty means auto-detect.
+    #[serde(default)]
+    pub(crate) container_socket: String,
 }
 
 fn default_timeline_retention_days() -> u16 {
@@ -396,6 +612,10 @@ fn default_notification_disk_threshold() -> f32 {
     90.0
 }
 
+fn default_notification_memory_available_threshold() -> f32 {
+    10.0
+}
+
 fn default_auto_clean_interval() -> u64 {
     300
 }
@@ -452,7 +672,13 @@ pub struct SystemMonitor {
     pub(crate) last_network_update: Instant,
     pub(crate) last_disk_update: Instant,
     pub(crate) previous_network_totals: std::collections::HashMap<String, (u64, u64)>,
+    /// Cumulative (errors, drops) per interface from the previous refresh.
+    pub(crate) previous_network_faults: std::collections::HashMap<String, (u64, u64)>,
     pub(crate) previous_disk_totals: (u64, u64),
+    #[cfg(target_os = "linux")]
+    pub(crate) gpu_clients: crate::monitoring::gpu_clients::GpuClientSampler,
+    #[cfg(target_os = "linux")]
+    pub(crate) cgroups: crate::monitoring::cgroups::CgroupSampler,
 }
 
 impl Default for AppSettings {
@@ -489,9 +715,12 @@ impl Default for AppSettings {
             show_cpu_cores: true,
             show_widget: false,
             notification_disk_threshold: 90.0,
+            memory_alert_basis: MemoryAlertBasis::Used,
+            notification_memory_available_threshold: default_notification_memory_available_threshold(),
             sidebar_collapsed: false,
             timeline_enabled: false,
             timeline_retention_days: default_timeline_retention_days(),
+            container_socket: String::new(),
         }
     }
 }
@@ -565,10 +794,16 @@ pub(crate) struct SystemData {
     pub(crate) memory_total: u64,
     pub(crate) memory_used: u64,
     pub(crate) memory_percentage: f32,
+    /// Memory that can be handed to new allocations without swapping, in bytes.
+    pub(crate) memory_available: u64,
+    /// Linux `/proc/meminfo` composition; `None` on other platforms.
+    pub(crate) memory_breakdown: Option<crate::monitoring::snapshot::MemoryBreakdownSnapshot>,
     pub(crate) cpu_usage: f32,
     pub(crate) cpu_cores: Vec<CpuCoreInfo>,
+    pub(crate) cpu_topology: Option<crate::cpu_topology::CpuTopology>,
     pub(crate) gpu_info: Vec<GpuInfo>,
     pub(crate) top_processes: Vec<crate::processes::ProcessInfo>,
+    pub(crate) cgroups: Vec<crate::processes::CgroupInfo>,
     pub(crate) timeline_processes: Vec<crate::processes::ProcessInfo>,
     pub(crate) monitoring_paused: bool,
     pub(crate) selected_process_pid: Option<u32>,
@@ -596,12 +831,23 @@ pub(crate) struct SystemData {
     pub(crate) disk_write_rate: f64,
     pub(crate) disk_read_history: VecDeque<DataPoint>,
     pub(crate) disk_write_history: VecDeque<DataPoint>,
+    /// Busy percentage per block device, keyed by kernel device name.
+    pub(crate) disk_util_history:
+        std::collections::BTreeMap<String, crate::monitoring::history::BoundedHistory<DataPoint>>,
+    /// Clock speed in MHz per logical processor, indexed by core id.
+    pub(crate) cpu_freq_history: Vec<crate::monitoring::history::BoundedHistory<DataPoint>>,
     pub(crate) is_hidden: bool,
     pub(crate) selected_tab: crate::Tab,
     pub(crate) services: Vec<crate::services::ServiceInfo>,
     pub(crate) last_activity: Instant,
     pub(crate) telemetry_history_stats: std::collections::HashMap<String, crate::telemetry::HistoryStats>,
     pub(crate) provider_status: std::collections::HashMap<String, bool>,
+    pub(crate) pressure: Option<crate::monitoring::snapshot::PressureSnapshot>,
+    pub(crate) load: Option<crate::monitoring::snapshot::LoadSnapshot>,
+    pub(crate) throttle: Option<crate::monitoring::snapshot::ThrottleSnapshot>,
+    pub(crate) hardware: HardwareInventory,
+    pub(crate) containers: Vec<crate::containers::ContainerInfo>,
+    pub(crate) block_devices: Vec<crate::monitoring::snapshot::BlockDeviceSnapshot>,
     pub(crate) physical_disks: Vec<crate::storage::PhysicalDiskHealth>,
     pub(crate) socket_connections: Vec<crate::network::SocketConnection>,
     pub(crate) power_plans: Vec<crate::power::PowerPlan>,
@@ -614,10 +860,14 @@ impl Default for SystemData {
             memory_total: 0,
             memory_used: 0,
             memory_percentage: 0.0,
+            memory_available: 0,
+            memory_breakdown: None,
             cpu_usage: 0.0,
             cpu_cores: Vec::new(),
+            cpu_topology: None,
             gpu_info: Vec::new(),
             top_processes: Vec::new(),
+            cgroups: Vec::new(),
             timeline_processes: Vec::new(),
             monitoring_paused: false,
             selected_process_pid: None,
@@ -636,6 +886,7 @@ impl Default for SystemData {
                 bios_version: None,
                 gpu_driver: None,
                 os_build: None,
+                hypervisor: None,
             },
             cpu_temperature: None,
             last_update: String::new(),
@@ -661,12 +912,20 @@ impl Default for SystemData {
             disk_write_rate: 0.0,
             disk_read_history: VecDeque::new(),
             disk_write_history: VecDeque::new(),
+            disk_util_history: std::collections::BTreeMap::new(),
+            cpu_freq_history: Vec::new(),
             is_hidden: false,
             selected_tab: crate::Tab::Overview,
             last_activity: Instant::now(),
             services: Vec::new(),
             telemetry_history_stats: std::collections::HashMap::new(),
             provider_status: std::collections::HashMap::new(),
+            pressure: None,
+            load: None,
+            throttle: None,
+            hardware: HardwareInventory::default(),
+            containers: Vec::new(),
+            block_devices: Vec::new(),
             physical_disks: Vec::new(),
             socket_connections: Vec::new(),
             power_plans: Vec::new(),
@@ -679,6 +938,106 @@ impl Default for SystemData {
 mod tests {
     use super::*;
 
+    #[test]
+    fn gpus_from_telemetry_counts_devices_from_every_provider() {
+        // NVML last wrote a count of 1; the DRM provider added gpu.1 after it.
+        let mut snapshot = crate::telemetry::TelemetrySnapshot::default();
+        snapshot.metrics.insert("gpu.device_count".into(), 1.0);
+        snapshot
+            .labels
+            .insert("gpu.0.name".into(), "NVIDIA GeForce RTX 4060 Laptop GPU".into());
+        snapshot
+            .labels
+            .insert("gpu.1.name".into(), "Intel Graphics [8086:a7a0]".into());
+        snapshot.metrics.insert("gpu.1.clock_graphics".into(), 1300.0);
+
+        let gpus = gpus_from_telemetry(&snapshot);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(gpus[1].name, "Intel Graphics [8086:a7a0]");
+        assert_eq!(gpus[1].clock_mhz, Some(1300));
+    }
+
+    #[test]
+    fn inventory_and_board_labels_come_from_telemetry() {
+        let mut snapshot = crate::telemetry::TelemetrySnapshot::default();
+        snapshot.metrics.insert("pci.count".into(), 1.0);
+        snapshot.metrics.insert("usb.count".into(), 0.0);
+        snapshot.metrics.insert("memory.dimm_count".into(), 1.0);
+        snapshot
+            .metrics
+            .insert("memory.dimm.0.size_bytes".into(), (16u64 << 30) as f64);
+        for (key, value) in [
+            ("pci.0.address", "0000:00:02.0"),
+            ("pci.0.vendor_id", "8086"),
+            ("pci.0.device_id", "a7a0"),
+            ("pci.0.class", "Display controller"),
+            ("pci.0.driver", "i915"),
+            ("pci.0.vendor", ""),
+            // Stale entry from a device unplugged since the previous poll.
+            ("usb.0.address", "1-2"),
+            ("memory.dimm.0.locator", "DIMM_A1"),
+            ("board.manufacturer", "LENOVO"),
+            ("board.product", "21HM"),
+            ("bios.version", "N3XET45W"),
+            ("bios.date", "08/01/2024"),
+            ("virt.hypervisor", "KVM"),
+            ("virt.source", "CPUID"),
+        ] {
+            snapshot.labels.insert(key.into(), value.into());
+        }
+
+        let inventory = inventory_from_telemetry(&snapshot);
+        assert_eq!(inventory.pci_devices[0].driver.as_deref(), Some("i915"));
+        assert_eq!(inventory.pci_devices[0].name, None);
+        assert_eq!(inventory.pci_devices[0].vendor, None);
+        assert!(inventory.usb_devices.is_empty());
+        assert_eq!(inventory.memory_modules[0].size_bytes, 16 << 30);
+
+        let mut info = SystemInfo {
+            os_name: String::new(),
+            os_version: String::new(),
+            kernel_version: String::new(),
+            hostname: String::new(),
+            uptime: 0,
+            cpu_count: 0,
+            cpu_brand: String::new(),
+            motherboard: None,
+            bios_version: None,
+            gpu_driver: None,
+            os_build: None,
+            hypervisor: None,
+        };
+        info.fill_from_labels(&snapshot.labels);
+        assert_eq!(info.motherboard.as_deref(), Some("LENOVO 21HM"));
+        assert_eq!(info.bios_version.as_deref(), Some("N3XET45W (08/01/2024)"));
+        assert_eq!(info.hypervisor.as_deref(), Some("KVM (via CPUID)"));
+    }
+
+    #[test]
+    fn containers_come_from_telemetry_only_while_the_provider_is_up() {
+        let mut snapshot = crate::telemetry::TelemetrySnapshot::default();
+        snapshot.metrics.insert("container.count".into(), 1.0);
+        snapshot.metrics.insert("container.0.cpu_percent".into(), 12.5);
+        snapshot.metrics.insert("container.0.memory_limit_bytes".into(), 0.0);
+        for (key, value) in [
+            ("container.0.id", "0123456789abcdef"),
+            ("container.0.name", "web"),
+            ("container.0.state", "running"),
+            ("container.0.pid", "4242"),
+        ] {
+            snapshot.labels.insert(key.into(), value.into());
+        }
+        assert!(containers_from_telemetry(&snapshot).is_empty());
+
+        snapshot.provider_status.insert("containers".into(), true);
+        let containers = containers_from_telemetry(&snapshot);
+        assert_eq!(containers.len(), 1);
+        assert_eq!(containers[0].short_id(), "0123456789ab");
+        assert_eq!(containers[0].pid, Some(4242));
+        assert_eq!(containers[0].cpu_usage, 12.5);
+        assert_eq!(containers[0].memory_limit, None);
+    }
+
     #[test]
     fn app_theme_defaults_to_dark() {
         assert_eq!(AppTheme::default(), AppTheme::Dark);
@@ -5,15 +5,14 @@ use tracing::warn;
 
 use crate::app::models::SystemMonitor;
 use crate::app::{actions, commands, events};
-use crate::{persistence, power, processes, services, startup};
+use crate::{containers, persistence, power, processes, services, startup};
 
 #[derive(Debug, Clone)]
 pub(crate) enum ActionError {
     AccessDenied,
-    #[allow(dead_code)]
     NotFound,
-    #[allow(dead_code)]
     Unavailable,
+    InvalidInput(String),
     Failed(String),
 }
 
@@ -23,11 +22,28 @@ impl std::fmt::Display for ActionError {
             Self::AccessDenied => write!(f, "Access denied; administrator privileges may be required"),
             Self::NotFound => write!(f, "Process or service not found"),
             Self::Unavailable => write!(f, "Operation unavailable on this system"),
+            Self::InvalidInput(message) => write!(f, "Invalid request: {message}"),
             Self::Failed(message) => f.write_str(message),
         }
     }
 }
 
+impl From<std::io::Error> for ActionError {
+    fn from(error: std::io::Error) -> Self {
+        // ESRCH has no dedicated ErrorKind; it is how the kernel reports a PID that has exited.
+        #[cfg(target_os = "linux")]
+        if error.raw_os_error() == Some(libc::ESRCH) {
+            return Self::NotFound;
+        }
+        match error.kind() {
+            std::io::ErrorKind::PermissionDenied => Self::AccessDenied,
+            std::io::ErrorKind::NotFound => Self::NotFound,
+            std::io::ErrorKind::InvalidInput => Self::InvalidInput(error.to_string()),
+            _ => Self::Failed(error.to_string()),
+        }
+    }
+}
+
 /// Loop that consumes ActionCommands on a background thread and dispatches events back to the UI.
 pub(crate) fn run_action_worker(commands: Receiver<commands::ActionCommand>, events: Sender<events::AppEvent>) {
     let mut monitor = SystemMonitor::new();
@@ -39,29 +55,55 @@ pub(crate) fn run_action_worker(commands: Receiver<commands::ActionCommand>, eve
                 .kill_process(pid)
                 .then_some(format!("Process {pid} killed"))
                 .ok_or(ActionError::AccessDenied),
-            commands::ActionCommand::SuspendProcess(pid) => monitor
-                .suspend_process(pid)
-                .then_some(format!("Process {pid} suspended"))
-                .ok_or(ActionError::AccessDenied),
-            commands::ActionCommand::ResumeProcess(pid) => monitor
-                .resume_process(pid)
-                .then_some(format!("Process {pid} resumed"))
-                .ok_or(ActionError::AccessDenied),
+            commands::ActionCommand::SuspendProcess(pid) => {
+                monitor.suspend_process(pid).map(|_| format!("Process {pid} suspended"))
+            }
+            commands::ActionCommand::ResumeProcess(pid) => {
+                monitor.resume_process(pid).map(|_| format!("Process {pid} resumed"))
+            }
             commands::ActionCommand::SetPriority { pid, priority } => {
                 SystemMonitor::set_process_priority(pid, &priority)
-                    .then_some(format!("Process {pid} priority set to {priority}"))
-                    .ok_or(ActionError::AccessDenied)
+                    .map(|_| format!("Process {pid} priority set to {priority}"))
+            }
+            commands::ActionCommand::CleanRam => Ok(format!("Freed {} bytes", monitor.clean_ram(&[], false, u64::MAX))),
+            commands::ActionCommand::DropPageCache => {
+                #[cfg(target_os = "linux")]
+                let result = crate::monitoring::reclaim::drop_page_cache();
+                #[cfg(not(target_os = "linux"))]
+                let result: Result<u64, ActionError> = Err(ActionError::Unavailable);
+                result.map(|freed| format!("Freed {freed} bytes of page cache"))
             }
-            commands::ActionCommand::CleanRam => Ok(format!("Freed {} bytes", monitor.clean_ram(&[], false))),
             commands::ActionCommand::ControlService { name, action } => services::send_service_control(&name, action)
                 .map(|outcome| format!("Service {name}: {outcome}"))
                 .map_err(|error| ActionError::Failed(error.to_string())),
-            commands::ActionCommand::SetPowerPlan(guid) => power::set_active_power_plan(&guid)
-                .map(|_| "Power plan changed".into())
-                .map_err(ActionError::Failed),
-            commands::ActionCommand::SetAffinity { pid, mask } => processes::set_process_affinity(pid, mask)
-                .map(|_| format!("Process {pid} affinity set to {mask:#x}"))
-                .map_err(ActionError::Failed),
+            commands::ActionCommand::ControlContainer {
+                socket,
+                id,
+                name,
+                action,
+            } => containers::control_container(&socket, &id, action)
+                .map(|outcome| format!("Container {name}: {outcome}")),
+            commands::ActionCommand::SetPowerPlan(guid) => {
+                let previous = power::get_power_plans()
+                    .into_iter()
+                    .find(|plan| plan.is_active)
+                    .map(|plan| plan.guid);
+                power::set_active_power_plan(&guid)
+                    .map(|_| {
+                        if let Some(previous) = previous.filter(|previous| *previous != guid) {
+                            dynamic_undo = Some(commands::ActionCommand::SetPowerPlan(previous));
+                        }
+                        "Power plan changed".into()
+                    })
+                    .map_err(ActionError::Failed)
+            }
+            commands::ActionCommand::SetAffinity { pid, mask, group } => {
+                #[cfg(target_os = "linux")]
+                let result = processes::linux::set_affinity(pid, mask).map_err(ActionError::from);
+                #[cfg(not(target_os = "linux"))]
+                let result = processes::set_process_affinity(pid, mask).map_err(ActionError::Failed);
+                result.map(|_| format!("Process {pid} affinity set to {group} ({mask:#x})"))
+            }
             commands::ActionCommand::KillProcessTree(root) => {
                 monitor.sys.refresh_processes();
                 let tree = processes::build_process_tree(&monitor.sys);
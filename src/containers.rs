@@ -0,0 +1,588 @@
+//! Container inventory from a Docker-compatible Engine API.
+//!
+//! Docker and Podman both serve the Engine REST API on a local Unix socket.
+//! This speaks just enough HTTP/1.1 over that socket to list containers, read
+//! one-shot stats, look up each container's init PID and send lifecycle
+//! requests. Stats counters are cumulative; the container provider keeps the
+//! previous sample to turn them into rates.
+
+use crate::app::worker::ActionError;
+use crate::processes::ProcessInfo;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One container as shown on the Processes page.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ContainerInfo {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    /// Engine state such as `running`, `exited` or `paused`.
+    pub state: String,
+    /// Engine status text such as `Up 3 hours`.
+    pub status: String,
+    /// Host PID of the container's init process while it runs.
+    pub pid: Option<u32>,
+    /// Same scale as `ProcessInfo::cpu_usage`: percent of all logical CPUs.
+    pub cpu_usage: f32,
+    pub memory_usage: u64,
+    pub memory_limit: Option<u64>,
+    pub net_rx_rate: f64,
+    pub net_tx_rate: f64,
+    pub block_read_rate: f64,
+    pub block_write_rate: f64,
+}
+
+impl ContainerInfo {
+    pub fn is_running(&self) -> bool {
+        self.state == "running"
+    }
+
+    /// The 12-character prefix the Docker and Podman CLIs print.
+    pub fn short_id(&self) -> &str {
+        self.id.get(..12).unwrap_or(&self.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ContainerControlAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl fmt::Display for ContainerControlAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Restart => "restart",
+        })
+    }
+}
+
+/// Engine socket to talk to. A configured path wins (with or without a
+/// `unix://` scheme); otherwise the first existing socket among
+/// `$DOCKER_HOST`, the Docker default and the rootless and rootful Podman
+/// sockets.
+pub fn resolve_socket(configured: &str) -> Option<PathBuf> {
+    let configured = configured.trim();
+    if !configured.is_empty() {
+        return Some(PathBuf::from(configured.strip_prefix("unix://").unwrap_or(configured)));
+    }
+    let mut candidates = Vec::new();
+    if let Some(host) = std::env::var("DOCKER_HOST")
+        .ok()
+        .and_then(|host| host.strip_prefix("unix://").map(PathBuf::from))
+    {
+        candidates.push(host);
+    }
+    candidates.push(PathBuf::from("/var/run/docker.sock"));
+    if let Some(runtime) = std::env::var_os("XDG_RUNTIME_DIR") {
+        candidates.push(PathBuf::from(runtime).join("podman/podman.sock"));
+    }
+    candidates.push(PathBuf::from("/run/podman/podman.sock"));
+    candidates.into_iter().find(|path| path.exists())
+}
+
+/// One entry from `GET /containers/json`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListedContainer {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub state: String,
+    pub status: String,
+}
+
+/// Cumulative counters from one `GET /containers/{id}/stats` sample.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContainerCounters {
+    pub cpu_ns: u64,
+    /// Host CPU time across all CPUs; zero when the engine omits it.
+    pub system_ns: u64,
+    pub online_cpus: u32,
+    /// Usage minus reclaimable file cache, as `docker stats` reports it.
+    pub memory_usage: u64,
+    pub memory_limit: u64,
+    pub net_rx: u64,
+    pub net_tx: u64,
+    pub block_read: u64,
+    pub block_write: u64,
+}
+
+impl ContainerCounters {
+    /// CPU use since `previous` as a percentage of all logical CPUs. Prefers
+    /// the engine's host CPU clock and falls back to wall time when it is missing.
+    pub fn cpu_percent_since(&self, previous: &ContainerCounters, elapsed: Duration) -> f64 {
+        let used = self.cpu_ns.saturating_sub(previous.cpu_ns) as f64;
+        let system = self.system_ns.saturating_sub(previous.system_ns) as f64;
+        let percent = if system > 0.0 {
+            used / system * 100.0
+        } else {
+            let available = elapsed.as_nanos() as f64 * self.online_cpus.max(1) as f64;
+            if available <= 0.0 {
+                return 0.0;
+            }
+            used / available * 100.0
+        };
+        percent.clamp(0.0, 100.0)
+    }
+}
+
+pub fn parse_container_list(json: &str) -> Result<Vec<ListedContainer>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|error| format!("invalid container list: {error}"))?;
+    let entries = value.as_array().ok_or("container list is not an array")?;
+    let text = |entry: &Value, key: &str| entry.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+    Ok(entries
+        .iter()
+        .map(|entry| {
+            let name = entry
+                .get("Names")
+                .and_then(Value::as_array)
+                .and_then(|names| names.first())
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            ListedContainer {
+                id: text(entry, "Id"),
+                name: name.trim_start_matches('/').to_string(),
+                image: text(entry, "Image"),
+                state: text(entry, "State"),
+                status: text(entry, "Status"),
+            }
+        })
+        .collect())
+}
+
+pub fn parse_container_stats(json: &str) -> Result<ContainerCounters, String> {
+    let value: Value = serde_json::from_str(json).map_err(|error| format!("invalid container stats: {error}"))?;
+    let number = |pointer: &str| value.pointer(pointer).and_then(Value::as_u64).unwrap_or(0);
+
+    // cgroup v2 reports `inactive_file`; v1 has `total_inactive_file`.
+    let cache = [
+        "/memory_stats/stats/inactive_file",
+        "/memory_stats/stats/total_inactive_file",
+    ]
+    .into_iter()
+    .find_map(|pointer| value.pointer(pointer).and_then(Value::as_u64))
+    .unwrap_or(0);
+
+    let (net_rx, net_tx) = value
+        .get("networks")
+        .and_then(Value::as_object)
+        .map(|networks| {
+            networks.values().fold((0, 0), |(rx, tx), interface| {
+                let counter = |key: &str| interface.get(key).and_then(Value::as_u64).unwrap_or(0);
+                (rx + counter("rx_bytes"), tx + counter("tx_bytes"))
+            })
+        })
+        .unwrap_or_default();
+
+    let (mut block_read, mut block_write) = (0, 0);
+    if let Some(entries) = value
+        .pointer("/blkio_stats/io_service_bytes_recursive")
+        .and_then(Value::as_array)
+    {
+        for entry in entries {
+            let bytes = entry.get("value").and_then(Value::as_u64).unwrap_or(0);
+            match entry.get("op").and_then(Value::as_str) {
+                Some(op) if op.eq_ignore_ascii_case("read") => block_read += bytes,
+                Some(op) if op.eq_ignore_ascii_case("write") => block_write += bytes,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(ContainerCounters {
+        cpu_ns: number("/cpu_stats/cpu_usage/total_usage"),
+        system_ns: number("/cpu_stats/system_cpu_usage"),
+        online_cpus: number("/cpu_stats/online_cpus") as u32,
+        memory_usage: number("/memory_stats/usage").saturating_sub(cache),
+        memory_limit: number("/memory_stats/limit"),
+        net_rx,
+        net_tx,
+        block_read,
+        block_write,
+    })
+}
+
+/// `State.Pid` from `GET /containers/{id}/json`; the engine reports 0 for stopped containers.
+pub fn parse_inspect_pid(json: &str) -> Option<u32> {
+    let value: Value = serde_json::from_str(json).ok()?;
+    let pid = value.pointer("/State/Pid").and_then(Value::as_u64)?;
+    u32::try_from(pid).ok().filter(|pid| *pid != 0)
+}
+
+/// Tag each process with the container it runs in: by the container ID in its
+/// cgroup path (`docker-<id>.scope`, `libpod-<id>.scope`, `/docker/<id>`), or
+/// failing that by descent from the container's init PID.
+pub fn assign_containers(processes: &mut [ProcessInfo], containers: &[ContainerInfo]) {
+    if containers.is_empty() {
+        return;
+    }
+    let by_pid: HashMap<u32, &str> = containers
+        .iter()
+        .filter_map(|container| container.pid.map(|pid| (pid, container.name.as_str())))
+        .collect();
+    let parents: HashMap<u32, u32> = processes
+        .iter()
+        .filter_map(|process| process.parent_pid.map(|parent| (process.pid, parent)))
+        .collect();
+
+    for process in processes.iter_mut() {
+        let from_cgroup = process.cgroup.as_deref().and_then(|path| {
+            containers
+                .iter()
+                .find(|container| !container.id.is_empty() && path.contains(container.id.as_str()))
+                .map(|container| container.name.as_str())
+        });
+        let from_ancestry = || {
+            let mut pid = process.pid;
+            // Bounded in case a PID was reused into a parent loop between samples.
+            for _ in 0..64 {
+                if let Some(name) = by_pid.get(&pid) {
+                    return Some(*name);
+                }
+                pid = *parents.get(&pid)?;
+            }
+            None
+        };
+        process.container = from_cgroup.or_else(from_ancestry).map(str::to_string);
+    }
+}
+
+/// Long enough for a stop request, which waits out the container's grace period.
+const CONTROL_TIMEOUT: Duration = Duration::from_secs(30);
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn list_containers(socket: &Path) -> Result<Vec<ListedContainer>, String> {
+    parse_container_list(&get(socket, "/containers/json?all=true")?)
+}
+
+/// `one-shot` skips the engine's second sample; older engines ignore it.
+pub fn container_stats(socket: &Path, id: &str) -> Result<ContainerCounters, String> {
+    parse_container_stats(&get(
+        socket,
+        &format!("/containers/{id}/stats?stream=false&one-shot=true"),
+    )?)
+}
+
+pub fn container_pid(socket: &Path, id: &str) -> Option<u32> {
+    get(socket, &format!("/containers/{id}/json"))
+        .ok()
+        .and_then(|body| parse_inspect_pid(&body))
+}
+
+pub fn control_container(socket: &Path, id: &str, action: ContainerControlAction) -> Result<String, ActionError> {
+    let path = format!("/containers/{id}/{action}");
+    let (status, body) =
+        transport::request(socket, "POST", &path, CONTROL_TIMEOUT).map_err(|error| match error.kind() {
+            std::io::ErrorKind::PermissionDenied => ActionError::AccessDenied,
+            std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused | std::io::ErrorKind::Unsupported => {
+                ActionError::Unavailable
+            }
+            _ => ActionError::Failed(format!("container engine request failed: {error}")),
+        })?;
+    match status {
+        204 => Ok(format!("{action} requested")),
+        304 => Ok(format!("already in the requested state ({action})")),
+        404 => Err(ActionError::NotFound),
+        _ => Err(ActionError::Failed(engine_message(status, &body))),
+    }
+}
+
+fn get(socket: &Path, path: &str) -> Result<String, String> {
+    match transport::request(socket, "GET", path, QUERY_TIMEOUT) {
+        Ok((200, body)) => Ok(body),
+        Ok((status, body)) => Err(engine_message(status, &body)),
+        Err(error) => Err(format!("{}: {error}", socket.display())),
+    }
+}
+
+/// Engine errors carry a JSON `message`.
+fn engine_message(status: u16, body: &str) -> String {
+    let message = serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| value.get("message").and_then(Value::as_str).map(str::to_string))
+        .unwrap_or_else(|| body.trim().to_string());
+    format!("container engine returned HTTP {status}: {message}")
+}
+
+#[cfg(target_os = "linux")]
+mod transport {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::path::Path;
+    use std::time::Duration;
+
+    /// One request per connection; `Connection: close` lets the body run to EOF.
+    pub(super) fn request(
+        socket: &Path,
+        method: &str,
+        path: &str,
+        timeout: Duration,
+    ) -> std::io::Result<(u16, String)> {
+        let mut stream = UnixStream::connect(socket)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"
+        )?;
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        decode_response(&response)
+    }
+
+    fn decode_response(raw: &[u8]) -> std::io::Result<(u16, String)> {
+        let invalid = |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_string());
+        let split = raw
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .ok_or_else(|| invalid("truncated HTTP response"))?;
+        let head = String::from_utf8_lossy(&raw[..split]);
+        let body = &raw[split + 4..];
+        let mut lines = head.lines();
+        let status = lines
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| invalid("malformed HTTP status line"))?;
+        let chunked = lines.any(|line| {
+            line.split_once(':').is_some_and(|(name, value)| {
+                name.trim().eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+            })
+        });
+        let body = if chunked {
+            dechunk(body).ok_or_else(|| invalid("malformed chunked body"))?
+        } else {
+            body.to_vec()
+        };
+        Ok((status, String::from_utf8_lossy(&body).into_owned()))
+    }
+
+    fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+        let mut decoded = Vec::new();
+        loop {
+            let line_end = body.windows(2).position(|window| window == b"\r\n")?;
+            let size_field = std::str::from_utf8(&body[..line_end]).ok()?;
+            let size = usize::from_str_radix(size_field.split(';').next()?.trim(), 16).ok()?;
+            body = &body[line_end + 2..];
+            if size == 0 {
+                return Some(decoded);
+            }
+            decoded.extend_from_slice(body.get(..size)?);
+            body = body.get(size + 2..)?;
+        }
+    }
+}
+
+/// Docker Desktop on Windows listens on a named pipe, which this client does not speak.
+#[cfg(not(target_os = "linux"))]
+mod transport {
+    use std::path::Path;
+    use std::time::Duration;
+
+    pub(super) fn request(
+        _socket: &Path,
+        _method: &str,
+        _path: &str,
+        _timeout: Duration,
+    ) -> std::io::Result<(u16, String)> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "the container engine socket is only supported on Linux",
+        ))
+    }
+}
+
+/// A stand-in Engine API for tests: serves canned responses on a Unix socket.
+#[cfg(all(test, target_os = "linux"))]
+pub(crate) mod fake_engine {
+    use crate::providers::sysfs::TempTree;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::path::PathBuf;
+
+    /// One canned reply: method, path without query, status and JSON body.
+    pub(crate) type Route = (&'static str, String, u16, String);
+
+    /// A served socket; its directory is removed on drop.
+    pub(crate) struct FakeEngine {
+        pub(crate) socket: PathBuf,
+        _dir: TempTree,
+    }
+
+    /// Serve `routes` until the test process exits. Bodies go out chunked,
+    /// as the real engine sends them. Unknown paths get a 404.
+    pub(crate) fn serve(name: &str, routes: Vec<Route>) -> FakeEngine {
+        let dir = TempTree::new(&format!("engine-{name}"));
+        let socket = dir.join("engine.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&stream);
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                let mut header = String::new();
+                while reader.read_line(&mut header).is_ok_and(|read| read > 0) && header != "\r\n" {
+                    header.clear();
+                }
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default();
+                let path = parts.next().unwrap_or_default().split('?').next().unwrap_or_default();
+                let (status, body) = routes
+                    .iter()
+                    .find(|route| route.0 == method && route.1 == path)
+                    .map_or((404, r#"{"message":"No such container"}"#), |route| {
+                        (route.2, route.3.as_str())
+                    });
+                let response = if body.is_empty() {
+                    format!("HTTP/1.1 {status} Status\r\nConnection: close\r\n\r\n")
+                } else {
+                    format!(
+                        "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n{:x}\r\n{body}\r\n0\r\n\r\n",
+                        body.len()
+                    )
+                };
+                let _ = (&stream).write_all(response.as_bytes());
+            }
+        });
+        FakeEngine { socket, _dir: dir }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATS: &str = r#"{
+        "cpu_stats": {"cpu_usage": {"total_usage": 4000000000}, "system_cpu_usage": 100000000000, "online_cpus": 4},
+        "memory_stats": {"usage": 314572800, "limit": 1073741824, "stats": {"inactive_file": 104857600}},
+        "networks": {"eth0": {"rx_bytes": 1000, "tx_bytes": 200}, "eth1": {"rx_bytes": 24, "tx_bytes": 6}},
+        "blkio_stats": {"io_service_bytes_recursive": [
+            {"major": 8, "minor": 0, "op": "read", "value": 4096},
+            {"major": 8, "minor": 0, "op": "Write", "value": 8192},
+            {"major": 8, "minor": 0, "op": "total", "value": 12288}
+        ]}
+    }"#;
+
+    #[test]
+    fn stats_parse_into_counters_and_cpu_share() {
+        let counters = parse_container_stats(STATS).unwrap();
+        assert_eq!(counters.memory_usage, 200 << 20);
+        assert_eq!(counters.memory_limit, 1 << 30);
+        assert_eq!((counters.net_rx, counters.net_tx), (1024, 206));
+        assert_eq!((counters.block_read, counters.block_write), (4096, 8192));
+
+        // One full CPU out of four over the interval is 25% of the machine.
+        let later = ContainerCounters {
+            cpu_ns: counters.cpu_ns + 1_000_000_000,
+            system_ns: counters.system_ns + 4_000_000_000,
+            ..counters
+        };
+        assert_eq!(later.cpu_percent_since(&counters, Duration::from_secs(1)), 25.0);
+        let no_host_clock = ContainerCounters { system_ns: 0, ..later };
+        let previous = ContainerCounters {
+            system_ns: 0,
+            ..counters
+        };
+        assert_eq!(no_host_clock.cpu_percent_since(&previous, Duration::from_secs(1)), 25.0);
+    }
+
+    #[test]
+    fn processes_map_to_containers_by_cgroup_or_ancestry() {
+        let process = |pid: u32, parent: Option<u32>, cgroup: Option<&str>| ProcessInfo {
+            pid,
+            start_time: 0,
+            name: format!("p{pid}"),
+            parent_pid: parent,
+            cpu_usage: 0.0,
+            memory: 0,
+            status: "Run".into(),
+            disk_read_bytes: 0,
+            disk_written_bytes: 0,
+            gpu_usage: 0.0,
+            gpu_memory: 0,
+            cgroup: cgroup.map(str::to_string),
+            container: None,
+        };
+        let web_id = "a".repeat(64);
+        let mut processes = vec![
+            process(10, Some(1), Some(&format!("/system.slice/docker-{web_id}.scope"))),
+            process(20, Some(1), None),
+            process(21, Some(20), None),
+            process(30, Some(1), Some("/user.slice")),
+        ];
+        let containers = vec![
+            ContainerInfo {
+                id: web_id,
+                name: "web".into(),
+                state: "running".into(),
+                ..Default::default()
+            },
+            ContainerInfo {
+                id: "b".repeat(64),
+                name: "db".into(),
+                state: "running".into(),
+                pid: Some(20),
+                ..Default::default()
+            },
+        ];
+        assign_containers(&mut processes, &containers);
+        let assigned: Vec<_> = processes.iter().map(|process| process.container.as_deref()).collect();
+        assert_eq!(assigned, [Some("web"), Some("db"), Some("db"), None]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn client_talks_to_a_fake_engine_socket() {
+        let id = "c".repeat(64);
+        let engine = fake_engine::serve(
+            "client",
+            vec![
+                (
+                    "GET",
+                    "/containers/json".into(),
+                    200,
+                    format!(
+                        r#"[{{"Id":"{id}","Names":["/cache"],"Image":"redis:7","State":"running","Status":"Up 2 hours"}}]"#
+                    ),
+                ),
+                ("GET", format!("/containers/{id}/stats"), 200, STATS.into()),
+                (
+                    "GET",
+                    format!("/containers/{id}/json"),
+                    200,
+                    r#"{"State":{"Pid":4242}}"#.into(),
+                ),
+                ("POST", format!("/containers/{id}/restart"), 204, String::new()),
+            ],
+        );
+
+        let socket = &engine.socket;
+        let listed = list_containers(socket).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(
+            (listed[0].name.as_str(), listed[0].state.as_str()),
+            ("cache", "running")
+        );
+        assert_eq!(container_stats(socket, &id).unwrap().memory_usage, 200 << 20);
+        assert_eq!(container_pid(socket, &id), Some(4242));
+        assert!(control_container(socket, &id, ContainerControlAction::Restart).is_ok());
+        assert!(matches!(
+            control_container(socket, "missing", ContainerControlAction::Stop),
+            Err(ActionError::NotFound)
+        ));
+        assert!(matches!(
+            control_container(&socket.with_file_name("absent.sock"), &id, ContainerControlAction::Stop),
+            Err(ActionError::Unavailable)
+        ));
+    }
+}
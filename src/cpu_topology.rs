@@ -0,0 +1,561 @@
+//! CPU topology: packages, physical cores, SMT siblings, hybrid core types,
+//! NUMA nodes and caches, used to group the core grid and build affinity masks.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Core class on hybrid processors (Intel P/E cores, Arm big.LITTLE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
+pub enum CoreType {
+    Performance,
+    Efficient,
+    /// Not a hybrid part, or the platform does not say.
+    Unknown,
+}
+
+impl CoreType {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Performance => "P-cores",
+            Self::Efficient => "E-cores",
+            Self::Unknown => "Cores",
+        }
+    }
+
+    pub fn badge(self) -> Option<&'static str> {
+        match self {
+            Self::Performance => Some("P"),
+            Self::Efficient => Some("E"),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct LogicalCpu {
+    pub id: usize,
+    pub package: u32,
+    /// Physical core number within the package; SMT siblings share it.
+    pub core: u32,
+    /// Cores sharing an L2 (Intel E-core modules) or an Arm cluster.
+    pub cluster: Option<u32>,
+    pub node: Option<u32>,
+    pub core_type: CoreType,
+    /// Relative compute capacity where the kernel reports it, 1024 for the fastest core.
+    pub capacity: Option<u32>,
+    /// Other logical CPUs on the same physical core.
+    pub siblings: Vec<usize>,
+    pub max_mhz: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct CacheInfo {
+    pub level: u8,
+    /// `Data`, `Instruction` or `Unified`.
+    pub kind: String,
+    pub size_bytes: u64,
+    /// Logical CPUs sharing this cache instance.
+    pub cpus: Vec<usize>,
+}
+
+/// A named set of logical CPUs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuGroup {
+    pub label: String,
+    pub cpus: Vec<usize>,
+}
+
+impl CpuGroup {
+    pub fn affinity_mask(&self) -> usize {
+        affinity_mask(&self.cpus)
+    }
+
+    /// Whether every CPU fits in `affinity_mask`; groups that do not are not
+    /// offered, since the mask would quietly narrow them.
+    pub fn fits_affinity_mask(&self) -> bool {
+        self.cpus.iter().all(|cpu| *cpu < usize::BITS as usize)
+    }
+}
+
+/// Package, NUMA node, core type and shared cluster, in grid order.
+type GroupKey = (u32, Option<u32>, CoreType, Option<u32>);
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct CpuTopology {
+    /// Sorted by id.
+    pub cpus: Vec<LogicalCpu>,
+    pub caches: Vec<CacheInfo>,
+}
+
+impl CpuTopology {
+    pub fn cpu(&self, id: usize) -> Option<&LogicalCpu> {
+        self.cpus
+            .binary_search_by_key(&id, |cpu| cpu.id)
+            .ok()
+            .map(|index| &self.cpus[index])
+    }
+
+    pub fn packages(&self) -> BTreeSet<u32> {
+        self.cpus.iter().map(|cpu| cpu.package).collect()
+    }
+
+    pub fn numa_nodes(&self) -> BTreeSet<u32> {
+        self.cpus.iter().filter_map(|cpu| cpu.node).collect()
+    }
+
+    pub fn physical_cores(&self) -> usize {
+        self.cpus
+            .iter()
+            .map(|cpu| (cpu.package, cpu.core))
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    pub fn is_hybrid(&self) -> bool {
+        let types: BTreeSet<_> = self.cpus.iter().map(|cpu| cpu.core_type).collect();
+        types.contains(&CoreType::Performance) && types.contains(&CoreType::Efficient)
+    }
+
+    pub fn has_smt(&self) -> bool {
+        self.cpus.iter().any(|cpu| !cpu.siblings.is_empty())
+    }
+
+    /// Groups for the core grid: by package, then NUMA node, then core type.
+    /// Clusters only split a group when several physical cores share one, so
+    /// P-cores with a private L2 each stay together while E-core modules and
+    /// Arm clusters are shown apart.
+    pub fn groups(&self) -> Vec<CpuGroup> {
+        let mut cores_per_cluster: HashMap<(u32, u32), BTreeSet<u32>> = HashMap::new();
+        for cpu in &self.cpus {
+            if let Some(cluster) = cpu.cluster {
+                cores_per_cluster
+                    .entry((cpu.package, cluster))
+                    .or_default()
+                    .insert(cpu.core);
+            }
+        }
+        let shared_cluster = |cpu: &LogicalCpu| {
+            cpu.cluster
+                .filter(|cluster| cores_per_cluster.get(&(cpu.package, *cluster)).map_or(0, BTreeSet::len) > 1)
+        };
+
+        let mut grouped: BTreeMap<GroupKey, Vec<usize>> = BTreeMap::new();
+        for cpu in &self.cpus {
+            grouped
+                .entry((cpu.package, cpu.node, cpu.core_type, shared_cluster(cpu)))
+                .or_default()
+                .push(cpu.id);
+        }
+
+        let show_package = self.packages().len() > 1;
+        let show_node = self.numa_nodes().len() > 1;
+        grouped
+            .into_iter()
+            .map(|((package, node, core_type, cluster), cpus)| {
+                let mut parts = Vec::new();
+                if show_package {
+                    parts.push(format!("Package {package}"));
+                }
+                if let (true, Some(node)) = (show_node, node) {
+                    parts.push(format!("Node {node}"));
+                }
+                parts.push(core_type.label().to_string());
+                if let Some(cluster) = cluster {
+                    parts.push(format!("Cluster {cluster}"));
+                }
+                CpuGroup {
+                    label: parts.join(" · "),
+                    cpus,
+                }
+            })
+            .collect()
+    }
+
+    /// Useful affinity targets: core types, NUMA nodes, packages, one thread
+    /// per physical core, and any grid group not already covered. Groups with
+    /// CPUs beyond the affinity mask's width are left out.
+    pub fn affinity_groups(&self) -> Vec<CpuGroup> {
+        let mut groups = Vec::new();
+        let mut push = |label: String, cpus: Vec<usize>| {
+            let group = CpuGroup { label, cpus };
+            if !group.cpus.is_empty()
+                && group.cpus.len() < self.cpus.len()
+                && group.fits_affinity_mask()
+                && !groups.iter().any(|g: &CpuGroup| g.cpus == group.cpus)
+            {
+                groups.push(group);
+            }
+        };
+        let select = |keep: &dyn Fn(&LogicalCpu) -> bool| -> Vec<usize> {
+            self.cpus.iter().filter(|cpu| keep(cpu)).map(|cpu| cpu.id).collect()
+        };
+
+        if self.is_hybrid() {
+            for core_type in [CoreType::Performance, CoreType::Efficient] {
+                push(
+                    format!("All {}", core_type.label()),
+                    select(&|cpu| cpu.core_type == core_type),
+                );
+            }
+        }
+        for node in self.numa_nodes() {
+            push(format!("NUMA node {node}"), select(&|cpu| cpu.node == Some(node)));
+        }
+        for package in self.packages() {
+            push(format!("Package {package}"), select(&|cpu| cpu.package == package));
+        }
+        if self.has_smt() {
+            push(
+                "One thread per core".into(),
+                select(&|cpu| cpu.siblings.iter().all(|sibling| *sibling > cpu.id)),
+            );
+        }
+        for group in self.groups() {
+            push(group.label, group.cpus);
+        }
+        groups
+    }
+
+    /// Approximate topology from processor counts alone (Windows WMI):
+    /// one package, SMT siblings numbered next to each other as Windows does.
+    pub fn from_counts(logical: usize, physical: usize, max_mhz: Option<u64>, caches: Vec<CacheInfo>) -> Self {
+        let threads_per_core = if physical > 0 && logical % physical == 0 {
+            logical / physical
+        } else {
+            1
+        };
+        let cpus = (0..logical)
+            .map(|id| {
+                let first = id - id % threads_per_core;
+                LogicalCpu {
+                    id,
+                    package: 0,
+                    core: (id / threads_per_core) as u32,
+                    cluster: None,
+                    node: None,
+                    core_type: CoreType::Unknown,
+                    capacity: None,
+                    siblings: (first..first + threads_per_core)
+                        .filter(|sibling| *sibling != id)
+                        .collect(),
+                    max_mhz,
+                }
+            })
+            .collect();
+        Self { cpus, caches }
+    }
+}
+
+/// Bit mask of `cpus` for `ActionCommand::SetAffinity`. CPUs beyond the
+/// width of `usize` cannot be expressed and are left out; see
+/// `CpuGroup::fits_affinity_mask`.
+pub fn affinity_mask(cpus: &[usize]) -> usize {
+    cpus.iter()
+        .filter(|cpu| **cpu < usize::BITS as usize)
+        .fold(0, |mask, cpu| mask | (1 << cpu))
+}
+
+/// Read the topology from sysfs on Linux, or approximate it from the WMI
+/// processor counts in `metrics` elsewhere. `None` when neither is available.
+pub fn detect(metrics: &HashMap<String, f64>) -> Option<CpuTopology> {
+    #[cfg(target_os = "linux")]
+    if let Some(topology) = linux_impl::read_topology(std::path::Path::new("/sys")) {
+        return Some(topology);
+    }
+
+    let logical = *metrics.get("cpu.logical_processors")? as usize;
+    let physical = metrics
+        .get("cpu.physical_cores")
+        .map_or(logical, |cores| *cores as usize);
+    let all: Vec<usize> = (0..logical).collect();
+    let caches = [(2, "cpu.l2_cache_kb"), (3, "cpu.l3_cache_kb")]
+        .into_iter()
+        .filter_map(|(level, key)| {
+            let kib = *metrics.get(key)? as u64;
+            (kib > 0).then(|| CacheInfo {
+                level,
+                kind: "Unified".into(),
+                size_bytes: kib * 1024,
+                cpus: all.clone(),
+            })
+        })
+        .collect();
+    let max_mhz = metrics.get("cpu.max_clock_mhz").map(|mhz| *mhz as u64);
+    (logical > 0).then(|| CpuTopology::from_counts(logical, physical, max_mhz, caches))
+}
+
+#[cfg(target_os = "linux")]
+mod linux_impl {
+    use super::*;
+    use crate::providers::sysfs::{read_i64, read_parsed, read_trimmed, read_u64};
+    use std::path::Path;
+
+    /// Parse a kernel CPU list such as `0-3,8,10-11`.
+    fn parse_cpu_list(list: &str) -> Vec<usize> {
+        list.trim()
+            .split(',')
+            .filter(|part| !part.is_empty())
+            .flat_map(|part| {
+                let (start, end) = part.split_once('-').unwrap_or((part, part));
+                match (start.trim().parse::<usize>(), end.trim().parse::<usize>()) {
+                    (Ok(start), Ok(end)) if start <= end => start..end + 1,
+                    _ => 0..0,
+                }
+            })
+            .collect()
+    }
+
+    /// Parse cache sizes such as `48K` or `30M`.
+    fn parse_cache_size(size: &str) -> Option<u64> {
+        let size = size.trim();
+        let (digits, unit) = size.split_at(size.find(|c: char| !c.is_ascii_digit()).unwrap_or(size.len()));
+        let value: u64 = digits.parse().ok()?;
+        Some(match unit {
+            "K" => value * 1024,
+            "M" => value * 1024 * 1024,
+            "G" => value * 1024 * 1024 * 1024,
+            _ => value,
+        })
+    }
+
+    /// Read `devices/system/cpu/cpu*/` below `sys_root`. Offline CPUs have no
+    /// `topology/` directory and are skipped.
+    pub(super) fn read_topology(sys_root: &Path) -> Option<CpuTopology> {
+        let cpu_root = sys_root.join("devices/system/cpu");
+        let mut cpus = Vec::new();
+        let mut caches: Vec<CacheInfo> = Vec::new();
+        for entry in std::fs::read_dir(&cpu_root).ok()?.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some(id) = name.strip_prefix("cpu").and_then(|id| id.parse::<usize>().ok()) else {
+                continue;
+            };
+            let dir = entry.path();
+            let topology = dir.join("topology");
+            let Some(core) = read_parsed::<u32>(&topology.join("core_id")) else {
+                continue;
+            };
+            // Some Arm firmware reports -1 for the package and cluster.
+            let package = read_i64(&topology.join("physical_package_id")).unwrap_or(0).max(0) as u32;
+            let cluster = read_i64(&topology.join("cluster_id"))
+                .filter(|cluster| *cluster >= 0)
+                .map(|cluster| cluster as u32);
+            let siblings = read_trimmed(&topology.join("thread_siblings_list"))
+                .map(|list| parse_cpu_list(&list))
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|sibling| *sibling != id)
+                .collect();
+            let node = std::fs::read_dir(&dir).ok().and_then(|entries| {
+                entries.flatten().find_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| name.strip_prefix("node"))
+                        .and_then(|node| node.parse().ok())
+                })
+            });
+            cpus.push(LogicalCpu {
+                id,
+                package,
+                core,
+                cluster,
+                node,
+                core_type: CoreType::Unknown,
+                capacity: read_parsed(&dir.join("cpu_capacity")),
+                siblings,
+                max_mhz: read_u64(&dir.join("cpufreq/cpuinfo_max_freq")).map(|khz| khz / 1000),
+            });
+
+            for index in std::fs::read_dir(dir.join("cache")).into_iter().flatten().flatten() {
+                let path = index.path();
+                let (Some(level), Some(kind), Some(size), Some(shared)) = (
+                    read_parsed::<u8>(&path.join("level")),
+                    read_trimmed(&path.join("type")),
+                    read_trimmed(&path.join("size")).and_then(|size| parse_cache_size(&size)),
+                    read_trimmed(&path.join("shared_cpu_list")),
+                ) else {
+                    continue;
+                };
+                let cache = CacheInfo {
+                    level,
+                    kind,
+                    size_bytes: size,
+                    cpus: parse_cpu_list(&shared),
+                };
+                if !caches.contains(&cache) {
+                    caches.push(cache);
+                }
+            }
+        }
+        if cpus.is_empty() {
+            return None;
+        }
+        cpus.sort_by_key(|cpu| cpu.id);
+        caches.sort_by(|a, b| (a.level, &a.kind, &a.cpus).cmp(&(b.level, &b.kind, &b.cpus)));
+        classify_core_types(sys_root, &mut cpus);
+        Some(CpuTopology { cpus, caches })
+    }
+
+    /// Intel hybrid parts list their P- and E-cores under separate PMU devices;
+    /// Arm reports a lower `cpu_capacity` for its little cores.
+    fn classify_core_types(sys_root: &Path, cpus: &mut [LogicalCpu]) {
+        let list = |pmu: &str| {
+            read_trimmed(&sys_root.join("devices").join(pmu).join("cpus")).map(|list| parse_cpu_list(&list))
+        };
+        if let (Some(performance), Some(efficient)) = (list("cpu_core"), list("cpu_atom")) {
+            for cpu in cpus.iter_mut() {
+                if performance.contains(&cpu.id) {
+                    cpu.core_type = CoreType::Performance;
+                } else if efficient.contains(&cpu.id) {
+                    cpu.core_type = CoreType::Efficient;
+                }
+            }
+            return;
+        }
+        let capacities: BTreeSet<u32> = cpus.iter().filter_map(|cpu| cpu.capacity).collect();
+        if let (true, Some(fastest)) = (capacities.len() > 1, capacities.last().copied()) {
+            for cpu in cpus.iter_mut() {
+                if let Some(capacity) = cpu.capacity {
+                    cpu.core_type = if capacity == fastest {
+                        CoreType::Performance
+                    } else {
+                        CoreType::Efficient
+                    };
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::providers::sysfs::TempTree;
+
+        #[test]
+        fn reads_a_hybrid_sysfs_tree() {
+            let root = TempTree::new("topology");
+            // Two P-cores with SMT (cpu0/1 and cpu2/3) and two E-cores sharing cluster 8.
+            for (cpu, core, cluster, siblings) in [
+                (0, 0, 0, "0-1"),
+                (1, 0, 0, "0-1"),
+                (2, 4, 1, "2-3"),
+                (3, 4, 1, "2-3"),
+                (4, 8, 8, "4"),
+                (5, 9, 8, "5"),
+            ] {
+                let dir = format!("devices/system/cpu/cpu{cpu}");
+                root.write(format!("{dir}/topology/core_id"), &format!("{core}\n"));
+                root.write(format!("{dir}/topology/physical_package_id"), "0\n");
+                root.write(format!("{dir}/topology/cluster_id"), &format!("{cluster}\n"));
+                root.write(format!("{dir}/topology/thread_siblings_list"), &format!("{siblings}\n"));
+                let khz = if cpu < 4 { "5400000" } else { "4200000" };
+                root.write(format!("{dir}/cpufreq/cpuinfo_max_freq"), khz);
+                std::fs::create_dir_all(root.join(format!("{dir}/node0"))).unwrap();
+                root.write(format!("{dir}/cache/index3/level"), "3\n");
+                root.write(format!("{dir}/cache/index3/type"), "Unified\n");
+                root.write(format!("{dir}/cache/index3/size"), "30M\n");
+                root.write(format!("{dir}/cache/index3/shared_cpu_list"), "0-5\n");
+            }
+            std::fs::create_dir_all(root.join("devices/system/cpu/cpu6")).unwrap();
+            root.write("devices/cpu_core/cpus", "0-3\n");
+            root.write("devices/cpu_atom/cpus", "4-5\n");
+
+            let topology = read_topology(&root).unwrap();
+
+            assert_eq!(topology.cpus.len(), 6);
+            assert_eq!(topology.physical_cores(), 4);
+            assert!(topology.is_hybrid());
+            assert_eq!(topology.cpu(1).unwrap().siblings, vec![0]);
+            assert_eq!(topology.cpu(4).unwrap().max_mhz, Some(4200));
+            assert_eq!(topology.cpu(5).unwrap().node, Some(0));
+            assert_eq!(topology.caches.len(), 1);
+            assert_eq!(topology.caches[0].size_bytes, 30 * 1024 * 1024);
+
+            let groups = topology.groups();
+            assert_eq!(
+                groups
+                    .iter()
+                    .map(|group| (group.label.as_str(), group.cpus.clone()))
+                    .collect::<Vec<_>>(),
+                vec![("P-cores", vec![0, 1, 2, 3]), ("E-cores · Cluster 8", vec![4, 5])]
+            );
+            let affinity: Vec<_> = topology
+                .affinity_groups()
+                .into_iter()
+                .map(|group| (group.affinity_mask(), group.label))
+                .collect();
+            assert_eq!(
+                affinity,
+                vec![
+                    (0b1111, "All P-cores".to_string()),
+                    (0b11_0000, "All E-cores".to_string()),
+                    (0b11_0101, "One thread per core".to_string()),
+                ]
+            );
+        }
+
+        #[test]
+        fn arm_capacity_marks_little_cores() {
+            let root = TempTree::new("topology-arm");
+            for (cpu, capacity) in [(0, "446"), (1, "446"), (2, "1024")] {
+                let dir = format!("devices/system/cpu/cpu{cpu}");
+                root.write(format!("{dir}/topology/core_id"), &cpu.to_string());
+                root.write(format!("{dir}/topology/physical_package_id"), "-1");
+                root.write(format!("{dir}/cpu_capacity"), capacity);
+            }
+            let topology = read_topology(&root).unwrap();
+
+            assert_eq!(topology.cpu(0).unwrap().core_type, CoreType::Efficient);
+            assert_eq!(topology.cpu(2).unwrap().core_type, CoreType::Performance);
+            assert_eq!(topology.cpu(2).unwrap().package, 0);
+            assert_eq!(parse_cache_size("48K"), Some(48 * 1024));
+            assert_eq!(parse_cpu_list("0-3,8,10-11\n"), vec![0, 1, 2, 3, 8, 10, 11]);
+            assert_eq!(parse_cpu_list(""), Vec::<usize>::new());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_skip_cpus_beyond_usize() {
+        assert_eq!(affinity_mask(&[0, 2, 5]), 0b10_0101);
+        assert_eq!(affinity_mask(&[usize::BITS as usize]), 0);
+    }
+
+    #[test]
+    fn groups_beyond_the_mask_width_are_not_offered() {
+        // One thread per core would be 0, 2, .., 126.
+        let topology = CpuTopology::from_counts(128, 64, None, Vec::new());
+        assert!(topology.affinity_groups().is_empty());
+
+        let mut numa = CpuTopology::from_counts(128, 128, None, Vec::new());
+        for cpu in &mut numa.cpus {
+            cpu.node = Some((cpu.id / 32) as u32);
+        }
+        let offered: Vec<_> = numa
+            .affinity_groups()
+            .into_iter()
+            .map(|group| (group.affinity_mask(), group.label))
+            .collect();
+        assert_eq!(
+            offered,
+            vec![
+                (0xffff_ffff, "NUMA node 0".to_string()),
+                (0xffff_ffff << 32, "NUMA node 1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_give_adjacent_smt_siblings() {
+        let topology = CpuTopology::from_counts(8, 4, Some(3600), Vec::new());
+        assert_eq!(topology.physical_cores(), 4);
+        assert_eq!(topology.cpu(5).unwrap().siblings, vec![4]);
+        assert_eq!(topology.groups().len(), 1);
+        let affinity = topology.affinity_groups();
+        assert_eq!(affinity[0].label, "One thread per core");
+        assert_eq!(affinity[0].affinity_mask(), 0b0101_0101);
+    }
+}
@@ -36,6 +36,23 @@ pub struct DiagnosticReport {
     pub findings: Vec<Finding>,
 }
 
+/// One minute of 1 Hz samples before a five-minute average counts as sustained.
+const MIN_SUSTAINED_SAMPLES: u64 = 60;
+
+/// Five-minute average of a 1 Hz telemetry series, once enough history exists.
+fn sustained_average(histories: &std::collections::HashMap<String, HistoryStats>, key: &str) -> Option<f64> {
+    histories
+        .get(key)
+        .map(|history| &history.five_minutes)
+        .filter(|stats| stats.sample_count >= MIN_SUSTAINED_SAMPLES)
+        .map(|stats| stats.avg)
+}
+
+/// Five-minute average of a PSI `avg10` series.
+fn sustained_pressure(histories: &std::collections::HashMap<String, HistoryStats>, key: &str) -> Option<f64> {
+    sustained_average(histories, &format!("pressure.{key}.avg10"))
+}
+
 pub fn analyze(
     snapshot: &SystemSnapshot,
     histories: &std::collections::HashMap<String, HistoryStats>,
@@ -77,6 +94,52 @@ pub fn analyze(
         });
     }
 
+    if let Some(throttle) = snapshot.throttle.filter(|throttle| throttle.sustained) {
+        findings.push(Finding {
+            severity: if throttle.clock_ratio().is_some_and(|ratio| ratio < 0.5) {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            },
+            title: "CPU thermal throttling".into(),
+            evidence: format!(
+                "The CPU is running below its rated clock to shed heat: {}",
+                throttle.evidence()
+            ),
+            recommendation: "High CPU usage here means less work done per second. Check the cooler, fans, dust \
+                             and laptop vents, and the firmware power limits, before blaming the busiest process."
+                .into(),
+            confidence: if throttle.events_per_sec > 0.0 { 92 } else { 78 },
+        });
+    }
+
+    let steal = sustained_average(histories, "cpu.steal_percent").unwrap_or_default();
+    if steal >= 10.0 {
+        let platform = snapshot
+            .system
+            .hypervisor
+            .as_deref()
+            .map_or_else(String::new, |hypervisor| format!("under {hypervisor}, "));
+        findings.push(Finding {
+            severity: if steal >= 25.0 {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            },
+            title: "CPU steal time".into(),
+            evidence: format!(
+                "Over the last five minutes the hypervisor ran other work on this machine's CPUs {steal:.1}% of the \
+                 time ({platform}guest CPU usage now {:.1}%).",
+                snapshot.cpu_usage
+            ),
+            recommendation: "The physical host is overcommitted or other guests are busy; ask the provider or host \
+                             administrator for dedicated vCPUs, a larger instance or a less loaded host. Tuning \
+                             inside this guest will not help."
+                .into(),
+            confidence: 90,
+        });
+    }
+
     let sustained_memory = histories
         .get("memory.used")
         .and_then(|history| {
@@ -110,6 +173,89 @@ pub fn analyze(
         });
     }
 
+    let memory_some = sustained_pressure(histories, "memory.some").unwrap_or_default();
+    let memory_full = sustained_pressure(histories, "memory.full").unwrap_or_default();
+    if memory_some >= 10.0 || memory_full >= 5.0 {
+        findings.push(Finding {
+            severity: if memory_full >= 20.0 {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            },
+            title: "Sustained memory stalls".into(),
+            evidence: format!(
+                "Over the last five minutes tasks waited on memory {memory_some:.1}% of the time, and all tasks \
+                 were stalled {memory_full:.1}% of the time (RAM usage {:.1}%, swap {:.1}%).",
+                snapshot.memory_percentage, snapshot.swap.percentage
+            ),
+            recommendation: "The system is reclaiming or swapping memory; close large applications or add RAM \
+                             rather than relying on cache drops."
+                .into(),
+            confidence: 90,
+        });
+    }
+
+    let io_some = sustained_pressure(histories, "io.some").unwrap_or_default();
+    let io_full = sustained_pressure(histories, "io.full").unwrap_or_default();
+    if io_some >= 20.0 || io_full >= 10.0 {
+        let top = snapshot
+            .processes
+            .iter()
+            .max_by_key(|process| process.disk_read_bytes.saturating_add(process.disk_written_bytes));
+        let stall = format!(
+            "Over the last five minutes tasks waited on I/O {io_some:.1}% of the time, and all tasks were stalled \
+             {io_full:.1}% of the time"
+        );
+        findings.push(Finding {
+            severity: if io_full >= 30.0 {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            },
+            title: "Sustained I/O stalls".into(),
+            evidence: top.map_or_else(
+                || format!("{stall}."),
+                |process| {
+                    format!(
+                        "{stall}; {} (PID {}) is the heaviest disk user.",
+                        process.name, process.pid
+                    )
+                },
+            ),
+            recommendation: "Check the busiest process and the health of the underlying disk; heavy swapping also \
+                             shows up as I/O pressure."
+                .into(),
+            confidence: if top.is_some() { 85 } else { 75 },
+        });
+    }
+
+    for device in &snapshot.block_devices {
+        let util = sustained_average(histories, &format!("disk.{}.util_percent", device.name)).unwrap_or_default();
+        let await_ms = sustained_average(histories, &format!("disk.{}.await_ms", device.name)).unwrap_or_default();
+        if util < 90.0 || await_ms < 20.0 {
+            continue;
+        }
+        findings.push(Finding {
+            severity: if await_ms >= 100.0 {
+                Severity::Critical
+            } else {
+                Severity::Warning
+            },
+            title: format!("Saturated disk: {}", device.name),
+            evidence: format!(
+                "{} was busy {util:.0}% of the last five minutes with requests taking {await_ms:.1} ms on average \
+                 (now {:.0} IOPS, queue depth {:.1}).",
+                device.name,
+                device.read_iops + device.write_iops,
+                device.queue_depth
+            ),
+            recommendation: "The device cannot keep up with its workload; find the heaviest disk user, move hot data \
+                             to faster storage or spread it across devices."
+                .into(),
+            confidence: 88,
+        });
+    }
+
     for disk in snapshot.disks.iter().filter(|disk| disk.usage_percentage >= 90.0) {
         findings.push(Finding {
             severity: if disk.usage_percentage >= 97.0 {
@@ -187,6 +333,143 @@ mod tests {
         assert!(report.findings.iter().any(|finding| finding.title == "CPU saturation"));
     }
 
+    #[test]
+    fn sustained_memory_pressure_produces_finding() {
+        let mut histories = std::collections::HashMap::new();
+        let stalled = |avg, sample_count| HistoryStats {
+            five_minutes: crate::telemetry::ring_buffer::MetricStats {
+                avg,
+                sample_count,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        histories.insert("pressure.memory.some.avg10".to_string(), stalled(35.0, 120));
+        histories.insert("pressure.memory.full.avg10".to_string(), stalled(25.0, 120));
+        histories.insert("pressure.io.some.avg10".to_string(), stalled(80.0, 5));
+
+        let report = analyze(&SystemSnapshot::default(), &histories);
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.title == "Sustained memory stalls")
+            .expect("memory stall finding");
+        assert_eq!(finding.severity, Severity::Critical);
+        // A brief I/O spike is not enough history to call it sustained.
+        assert!(!report
+            .findings
+            .iter()
+            .any(|finding| finding.title == "Sustained I/O stalls"));
+    }
+
+    #[test]
+    fn busy_slow_disk_is_saturated() {
+        let sustained = |avg| HistoryStats {
+            five_minutes: crate::telemetry::ring_buffer::MetricStats {
+                avg,
+                sample_count: 300,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let histories = std::collections::HashMap::from([
+            ("disk.sda.util_percent".to_string(), sustained(97.0)),
+            ("disk.sda.await_ms".to_string(), sustained(45.0)),
+            ("disk.nvme0n1.util_percent".to_string(), sustained(99.0)),
+            ("disk.nvme0n1.await_ms".to_string(), sustained(0.4)),
+        ]);
+        let device = |name: &str| crate::monitoring::snapshot::BlockDeviceSnapshot {
+            name: name.into(),
+            ..Default::default()
+        };
+        let snapshot = SystemSnapshot {
+            block_devices: vec![device("nvme0n1"), device("sda")],
+            ..Default::default()
+        };
+
+        let report = analyze(&snapshot, &histories);
+        let saturated: Vec<_> = report
+            .findings
+            .iter()
+            .filter(|finding| finding.title.starts_with("Saturated disk"))
+            .map(|finding| (finding.title.as_str(), finding.severity))
+            .collect();
+        // A busy NVMe drive that still answers in under a millisecond is not saturated.
+        assert_eq!(saturated, vec![("Saturated disk: sda", Severity::Warning)]);
+    }
+
+    #[test]
+    fn sustained_throttling_produces_finding() {
+        let throttle = crate::monitoring::snapshot::ThrottleSnapshot {
+            average_mhz: 1800.0,
+            max_mhz: Some(4000.0),
+            temperature: Some(99.0),
+            throttled_samples: 45,
+            window_samples: 60,
+            sustained: true,
+            ..Default::default()
+        };
+        let snapshot = SystemSnapshot {
+            throttle: Some(throttle),
+            ..Default::default()
+        };
+        let report = analyze(&snapshot, &Default::default());
+        let finding = report
+            .findings
+            .iter()
+            .find(|finding| finding.title == "CPU thermal throttling")
+            .expect("throttling finding");
+        assert_eq!(finding.severity, Severity::Critical);
+        assert!(finding.evidence.contains("45% of the 4000 MHz maximum"));
+
+        let brief = SystemSnapshot {
+            throttle: Some(crate::monitoring::snapshot::ThrottleSnapshot {
+                sustained: false,
+                ..throttle
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            analyze(&brief, &Default::default()).findings[0].severity,
+            Severity::Healthy
+        ));
+    }
+
+    #[test]
+    fn sustained_steal_produces_finding() {
+        let stolen = |avg| {
+            std::collections::HashMap::from([(
+                "cpu.steal_percent".to_string(),
+                HistoryStats {
+                    five_minutes: crate::telemetry::ring_buffer::MetricStats {
+                        avg,
+                        sample_count: 300,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )])
+        };
+        let mut snapshot = SystemSnapshot::default();
+        snapshot.system.hypervisor = Some("KVM (via CPUID)".into());
+
+        let report = analyze(&snapshot, &stolen(30.0));
+        assert_eq!(report.findings[0].title, "CPU steal time");
+        assert_eq!(report.findings[0].severity, Severity::Critical);
+        assert!(report.findings[0]
+            .evidence
+            .contains("(under KVM (via CPUID), guest CPU usage"));
+
+        assert_eq!(
+            analyze(&snapshot, &stolen(12.0)).findings[0].severity,
+            Severity::Warning
+        );
+        assert!(matches!(
+            analyze(&snapshot, &stolen(2.0)).findings[0].severity,
+            Severity::Healthy
+        ));
+    }
+
     #[test]
     fn quiet_snapshot_is_healthy() {
         let report = analyze(&SystemSnapshot::default(), &Default::default());
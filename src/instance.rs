@@ -0,0 +1,730 @@
+//! Single-instance enforcement and hand-off to the running instance.
+//!
+//! The first launch takes a lock (a `flock`ed file on Linux, a named mutex on
+//! Windows) and listens on a local channel (a Unix socket next to the lock
+//! file, or a per-user named pipe). Later launches find the lock held, send
+//! their command-line requests over the channel and exit, so only one copy
+//! ever owns the timeline database. A launch with `--replace` (used by the
+//! elevated relaunch) instead waits for the closing copy to release the lock.
+
+use crate::monitoring::engine::Tab;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How long a `--replace` launch waits for the previous copy to exit before
+/// falling back to a normal hand-off.
+const REPLACE_WAIT: Duration = Duration::from_secs(10);
+
+/// How long the listener waits on a connected launch for its requests, so a
+/// client that connects and never writes cannot block later hand-offs.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Flag passed by `privilege::relaunch_as_admin` to the copy it starts.
+pub(crate) const REPLACE_FLAG: &str = "--replace";
+
+/// Something a launch asks the running instance to do.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum InstanceRequest {
+    Show,
+    SelectTab(Tab),
+    Run(InstanceCommand),
+}
+
+/// Commands accepted by `--command`, mirroring the tray menu.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum InstanceCommand {
+    CleanRam,
+    ProcessManager,
+    Pause,
+    Resume,
+}
+
+const TABS: [(&str, Tab); 14] = [
+    ("overview", Tab::Overview),
+    ("performance", Tab::Performance),
+    ("processes", Tab::Processes),
+    ("cpu-cores", Tab::CpuCores),
+    ("storage", Tab::Storage),
+    ("network", Tab::Network),
+    ("system-info", Tab::SystemInfo),
+    ("alerts", Tab::Alerts),
+    ("ram-cleaner", Tab::RamCleaner),
+    ("startup", Tab::StartupManager),
+    ("services", Tab::Services),
+    ("diagnostics", Tab::Diagnostics),
+    ("timeline", Tab::Timeline),
+    ("about", Tab::About),
+];
+
+const COMMANDS: [(&str, InstanceCommand); 4] = [
+    ("clean-ram", InstanceCommand::CleanRam),
+    ("process-manager", InstanceCommand::ProcessManager),
+    ("pause", InstanceCommand::Pause),
+    ("resume", InstanceCommand::Resume),
+];
+
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| *value)
+}
+
+fn name_of<T: PartialEq>(table: &[(&'static str, T)], value: &T) -> &'static str {
+    table
+        .iter()
+        .find(|(_, candidate)| candidate == value)
+        .map_or("", |(key, _)| key)
+}
+
+impl InstanceRequest {
+    /// One line of the hand-off protocol.
+    pub(crate) fn encode(&self) -> String {
+        match self {
+            Self::Show => "show".into(),
+            Self::SelectTab(tab) => format!("tab {}", name_of(&TABS, tab)),
+            Self::Run(command) => format!("command {}", name_of(&COMMANDS, command)),
+        }
+    }
+
+    pub(crate) fn decode(line: &str) -> Option<Self> {
+        let mut words = line.split_whitespace();
+        let request = match (words.next()?, words.next()) {
+            ("show", None) => Self::Show,
+            ("tab", Some(name)) => Self::SelectTab(lookup(&TABS, name)?),
+            ("command", Some(name)) => Self::Run(lookup(&COMMANDS, name)?),
+            _ => return None,
+        };
+        words.next().is_none().then_some(request)
+    }
+}
+
+/// A parsed command line.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct LaunchArgs {
+    pub(crate) requests: Vec<InstanceRequest>,
+    /// Take over from a running copy that is about to exit rather than handing off to it.
+    pub(crate) replace: bool,
+}
+
+/// Parse `--tab <name>`, `--command <name>`, `--show` and `--replace` from the
+/// command line (without the program name). Unknown arguments are reported, not ignored.
+pub(crate) fn parse_args(args: impl IntoIterator<Item = String>) -> Result<LaunchArgs, String> {
+    let mut launch = LaunchArgs::default();
+    let requests = &mut launch.requests;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or(format!("{flag} needs a value"))
+        };
+        match flag.as_str() {
+            "--show" => requests.push(InstanceRequest::Show),
+            REPLACE_FLAG => launch.replace = true,
+            "--tab" => {
+                let name = value()?;
+                let tab = lookup(&TABS, &name).ok_or(format!("Unknown tab '{name}'"))?;
+                requests.push(InstanceRequest::SelectTab(tab));
+            }
+            "--command" => {
+                let name = value()?;
+                let command = lookup(&COMMANDS, &name).ok_or(format!("Unknown command '{name}'"))?;
+                requests.push(InstanceRequest::Run(command));
+            }
+            other => return Err(format!("Unknown argument '{other}'")),
+        }
+    }
+    Ok(launch)
+}
+
+/// Names accepted by `--tab` and `--command`, for usage messages.
+pub(crate) fn usage() -> String {
+    let tabs: Vec<_> = TABS.iter().map(|(name, _)| *name).collect();
+    let commands: Vec<_> = COMMANDS.iter().map(|(name, _)| *name).collect();
+    format!(
+        "Usage: system-monitor [--show] [--tab <name>] [--command <name>]\n  tabs: {}\n  commands: {}",
+        tabs.join(", "),
+        commands.join(", ")
+    )
+}
+
+/// Held by the running instance for its whole lifetime.
+pub(crate) struct PrimaryInstance {
+    requests: Receiver<InstanceRequest>,
+    sender: Sender<InstanceRequest>,
+    repaint: Arc<OnceLock<eframe::egui::Context>>,
+}
+
+impl PrimaryInstance {
+    /// Queue requests for this instance itself, e.g. those from its own command line.
+    pub(crate) fn submit(&self, requests: &[InstanceRequest]) {
+        for request in requests {
+            let _ = self.sender.send(*request);
+        }
+    }
+
+    /// Hand over the receiving end once the UI exists; later requests wake it up.
+    pub(crate) fn attach(self, ctx: &eframe::egui::Context) -> Receiver<InstanceRequest> {
+        let _ = self.repaint.set(ctx.clone());
+        self.requests
+    }
+}
+
+pub(crate) enum Launch {
+    Primary(PrimaryInstance),
+    /// Another instance accepted the requests; this process should exit.
+    HandedOff,
+    /// Another instance holds the lock but could not be reached.
+    Unreachable(String),
+}
+
+/// Take the single-instance lock, or pass the requests to whoever holds it.
+pub(crate) fn acquire(launch: &LaunchArgs) -> Launch {
+    let requests = if launch.requests.is_empty() {
+        vec![InstanceRequest::Show]
+    } else {
+        launch.requests.clone()
+    };
+    platform::acquire(&requests, launch.replace)
+}
+
+fn spawn_listener<S: std::io::Read + Send + 'static>(
+    incoming: impl Iterator<Item = std::io::Result<S>> + Send + 'static,
+    sender: Sender<InstanceRequest>,
+    repaint: Arc<OnceLock<eframe::egui::Context>>,
+) {
+    let spawned = std::thread::Builder::new()
+        .name("instance_listener".to_string())
+        .spawn(move || {
+            for stream in incoming {
+                let Ok(stream) = stream else {
+                    continue;
+                };
+                for line in BufReader::new(stream).lines().map_while(Result::ok).take(16) {
+                    match InstanceRequest::decode(&line) {
+                        Some(request) => {
+                            info!(request = %line, "Request from another launch");
+                            if sender.send(request).is_err() {
+                                return;
+                            }
+                        }
+                        None => warn!(request = %line, "Ignoring malformed instance request"),
+                    }
+                }
+                if let Some(ctx) = repaint.get() {
+                    ctx.request_repaint();
+                }
+            }
+        });
+    if let Err(error) = spawned {
+        warn!("Could not start instance listener: {error}");
+    }
+}
+
+fn send_requests(mut stream: impl Write, requests: &[InstanceRequest]) -> std::io::Result<()> {
+    for request in requests {
+        writeln!(stream, "{}", request.encode())?;
+    }
+    stream.flush()
+}
+
+fn primary() -> (
+    PrimaryInstance,
+    Sender<InstanceRequest>,
+    Arc<OnceLock<eframe::egui::Context>>,
+) {
+    let (sender, requests) = mpsc::channel();
+    let repaint = Arc::new(OnceLock::new());
+    let instance = PrimaryInstance {
+        requests,
+        sender: sender.clone(),
+        repaint: repaint.clone(),
+    };
+    (instance, sender, repaint)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::*;
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::{Path, PathBuf};
+    use std::time::Instant;
+
+    /// Kept open for the life of the process; closing it releases the lock.
+    static LOCK: OnceLock<std::fs::File> = OnceLock::new();
+
+    /// Candidate directories in order of preference. `$XDG_RUNTIME_DIR` is
+    /// per-user and cleared at logout, which is exactly the lifetime we want;
+    /// the data dir is the fallback without a session. The shared temp dir is
+    /// the last resort, so its name carries the uid.
+    fn instance_dirs() -> Vec<PathBuf> {
+        let runtime =
+            directories::BaseDirs::new().and_then(|dirs| dirs.runtime_dir().map(|dir| dir.join("system-monitor")));
+        let data = directories::ProjectDirs::from("com", "Xenonesis", "SystemMonitor")
+            .map(|dirs| dirs.data_local_dir().to_path_buf());
+        // SAFETY: geteuid has no preconditions and cannot fail.
+        let temp = std::env::temp_dir().join(format!("SystemMonitor-{}", unsafe { libc::geteuid() }));
+        runtime.into_iter().chain(data).chain([temp]).collect()
+    }
+
+    pub(super) fn acquire(requests: &[InstanceRequest], replace: bool) -> Launch {
+        let dirs = instance_dirs();
+        let dirs: Vec<&Path> = dirs.iter().map(PathBuf::as_path).collect();
+        let (launch, lock) = acquire_in(&dirs, requests, replace);
+        if let Some(lock) = lock {
+            let _ = LOCK.set(lock);
+        }
+        launch
+    }
+
+    /// Take the lock in the first usable directory. Only when none of them
+    /// can hold a lock does this launch run unlocked, since two primaries
+    /// would then share the timeline database and settings.
+    ///
+    /// Also returns the lock file when this launch took the lock; it must stay
+    /// open for as long as the instance runs.
+    pub(super) fn acquire_in(
+        dirs: &[&Path],
+        requests: &[InstanceRequest],
+        replace: bool,
+    ) -> (Launch, Option<std::fs::File>) {
+        for dir in dirs {
+            match acquire_at(dir, requests, replace) {
+                Ok(acquired) => return acquired,
+                Err(error) => warn!("Not using instance directory {}: {error}", dir.display()),
+            }
+        }
+        warn!("No usable instance directory; running without the single-instance lock");
+        (Launch::Primary(primary().0), None)
+    }
+
+    /// Fails only when `dir` cannot hold the lock, so the caller can move on
+    /// to the next candidate.
+    fn acquire_at(
+        dir: &Path,
+        requests: &[InstanceRequest],
+        replace: bool,
+    ) -> std::io::Result<(Launch, Option<std::fs::File>)> {
+        private_dir(dir)?;
+        let socket_path = dir.join("instance.sock");
+        let lock = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(dir.join("instance.lock"))?;
+
+        let locked = try_lock(&lock) || (replace && wait_for_lock(&lock));
+        if !locked {
+            return Ok((hand_off(&socket_path, requests), None));
+        }
+
+        // We hold the lock, so any socket left behind belongs to a dead instance.
+        let _ = std::fs::remove_file(&socket_path);
+        let (instance, sender, repaint) = primary();
+        match UnixListener::bind(&socket_path) {
+            Ok(listener) => {
+                let incoming = std::iter::from_fn(move || {
+                    Some(listener.accept().and_then(|(stream, _)| {
+                        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+                        Ok(stream)
+                    }))
+                });
+                spawn_listener(incoming, sender, repaint);
+            }
+            Err(error) => warn!("Could not listen on {}: {error}", socket_path.display()),
+        }
+        Ok((Launch::Primary(instance), Some(lock)))
+    }
+
+    /// Create `dir` with mode 0700, or refuse an existing one that
+    /// another user owns or can write to: whoever can write there can replace
+    /// the socket and receive every later launch's requests.
+    fn private_dir(dir: &Path) -> std::io::Result<()> {
+        use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+        std::fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)?;
+        let metadata = std::fs::symlink_metadata(dir)?;
+        // SAFETY: geteuid has no preconditions and cannot fail.
+        let owned = metadata.uid() == unsafe { libc::geteuid() };
+        if !metadata.is_dir() || !owned || metadata.mode() & 0o022 != 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "not a directory private to the current user",
+            ));
+        }
+        Ok(())
+    }
+
+    fn try_lock(lock: &std::fs::File) -> bool {
+        // SAFETY: flock on a descriptor we own; the lock is released when it closes.
+        unsafe { libc::flock(lock.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) == 0 }
+    }
+
+    /// Poll until the previous owner exits and releases the lock.
+    fn wait_for_lock(lock: &std::fs::File) -> bool {
+        let deadline = Instant::now() + REPLACE_WAIT;
+        while Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(100));
+            if try_lock(lock) {
+                return true;
+            }
+        }
+        warn!("Previous instance did not exit within {REPLACE_WAIT:?}; handing off instead");
+        false
+    }
+
+    /// The lock holder may still be starting up and not listening yet.
+    fn hand_off(socket_path: &Path, requests: &[InstanceRequest]) -> Launch {
+        let mut last_error = None;
+        for _ in 0..20 {
+            match UnixStream::connect(socket_path).and_then(|stream| send_requests(stream, requests)) {
+                Ok(()) => return Launch::HandedOff,
+                Err(error) => last_error = Some(error),
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        Launch::Unreachable(last_error.map_or_else(String::new, |error| error.to_string()))
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use super::*;
+    use std::io::Read;
+    use std::os::windows::io::{AsRawHandle, FromRawHandle};
+    use std::time::Instant;
+    use windows_sys::Win32::Foundation::{
+        GetLastError, ERROR_ALREADY_EXISTS, ERROR_PIPE_CONNECTED, INVALID_HANDLE_VALUE, WAIT_ABANDONED, WAIT_OBJECT_0,
+    };
+    use windows_sys::Win32::Storage::FileSystem::{FILE_FLAG_FIRST_PIPE_INSTANCE, PIPE_ACCESS_INBOUND};
+    use windows_sys::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PeekNamedPipe, PIPE_READMODE_BYTE, PIPE_REJECT_REMOTE_CLIENTS,
+        PIPE_TYPE_BYTE, PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+    };
+    use windows_sys::Win32::System::Threading::{CreateMutexW, WaitForSingleObject};
+
+    fn wide(text: &str) -> Vec<u16> {
+        text.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    /// Pipes are per machine, so the user name keeps sessions apart.
+    fn pipe_name() -> String {
+        let user = std::env::var("USERNAME").unwrap_or_default();
+        format!(r"\\.\pipe\SystemMonitor-{user}")
+    }
+
+    pub(super) fn acquire(requests: &[InstanceRequest], replace: bool) -> Launch {
+        let name = wide("Global\\SystemMonitorSingleInstance");
+        // The handle is deliberately never closed: the mutex is the lock and
+        // lives until the process exits.
+        let handle = unsafe { CreateMutexW(std::ptr::null(), 1, name.as_ptr()) };
+        if unsafe { GetLastError() } == ERROR_ALREADY_EXISTS && !(replace && wait_for_mutex(handle)) {
+            return hand_off(requests);
+        }
+
+        let (instance, sender, repaint) = primary();
+        match PipeConnections::claim(wide(&pipe_name())) {
+            Ok(connections) => spawn_listener(connections, sender, repaint),
+            Err(error) => warn!("Could not create instance pipe: {error}"),
+        }
+        Launch::Primary(instance)
+    }
+
+    /// Wait for the previous owner to exit; its mutex is then abandoned and
+    /// ownership passes to this process.
+    fn wait_for_mutex(handle: windows_sys::Win32::Foundation::HANDLE) -> bool {
+        if handle.is_null() {
+            return false;
+        }
+        let result = unsafe { WaitForSingleObject(handle, REPLACE_WAIT.as_millis() as u32) };
+        let acquired = result == WAIT_OBJECT_0 || result == WAIT_ABANDONED;
+        if !acquired {
+            warn!("Previous instance did not exit within {REPLACE_WAIT:?}; handing off instead");
+        }
+        acquired
+    }
+
+    fn hand_off(requests: &[InstanceRequest]) -> Launch {
+        let mut last_error = None;
+        for _ in 0..20 {
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .open(pipe_name())
+                .and_then(|pipe| send_requests(pipe, requests))
+            {
+                Ok(()) => return Launch::HandedOff,
+                Err(error) => last_error = Some(error),
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        Launch::Unreachable(last_error.map_or_else(String::new, |error| error.to_string()))
+    }
+
+    /// Each accepted client gets a fresh pipe instance, read as a file. The
+    /// next instance is created before a client is served, so the name is
+    /// never free for another process to claim.
+    struct PipeConnections {
+        name: Vec<u16>,
+        next: Option<std::fs::File>,
+    }
+
+    impl PipeConnections {
+        /// Create the first instance, failing if another process already owns the name.
+        fn claim(name: Vec<u16>) -> std::io::Result<Self> {
+            let first = create_pipe(&name, FILE_FLAG_FIRST_PIPE_INSTANCE)?;
+            Ok(Self {
+                name,
+                next: Some(first),
+            })
+        }
+    }
+
+    fn create_pipe(name: &[u16], flags: u32) -> std::io::Result<std::fs::File> {
+        unsafe {
+            let pipe = CreateNamedPipeW(
+                name.as_ptr(),
+                PIPE_ACCESS_INBOUND | flags,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT | PIPE_REJECT_REMOTE_CLIENTS,
+                PIPE_UNLIMITED_INSTANCES,
+                0,
+                4096,
+                0,
+                std::ptr::null(),
+            );
+            if pipe == INVALID_HANDLE_VALUE {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(std::fs::File::from_raw_handle(pipe))
+        }
+    }
+
+    impl Iterator for PipeConnections {
+        type Item = std::io::Result<PipeClient>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            // Give up rather than spin once pipe instances cannot be created at all.
+            let file = self.next.take()?;
+            self.next = create_pipe(&self.name, 0)
+                .map_err(|error| warn!("Could not create instance pipe: {error}"))
+                .ok();
+            unsafe {
+                if ConnectNamedPipe(file.as_raw_handle(), std::ptr::null_mut()) == 0
+                    && GetLastError() != ERROR_PIPE_CONNECTED
+                {
+                    return Some(Err(std::io::Error::last_os_error()));
+                }
+            }
+            Some(Ok(PipeClient {
+                file,
+                deadline: Instant::now() + CLIENT_TIMEOUT,
+            }))
+        }
+    }
+
+    /// A connected launch. Synchronous pipe reads cannot time out, so reads
+    /// wait for data with `PeekNamedPipe` until the deadline passes.
+    struct PipeClient {
+        file: std::fs::File,
+        deadline: Instant,
+    }
+
+    impl Read for PipeClient {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            loop {
+                let mut available = 0;
+                let peeked = unsafe {
+                    PeekNamedPipe(
+                        self.file.as_raw_handle(),
+                        std::ptr::null_mut(),
+                        0,
+                        std::ptr::null_mut(),
+                        &mut available,
+                        std::ptr::null_mut(),
+                    )
+                };
+                // A failed peek means the client hung up; the read reports that.
+                if peeked == 0 || available > 0 {
+                    return self.file.read(buf);
+                }
+                if Instant::now() >= self.deadline {
+                    return Err(std::io::ErrorKind::TimedOut.into());
+                }
+                std::thread::sleep(Duration::from_millis(20));
+            }
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+mod platform {
+    use super::*;
+
+    pub(super) fn acquire(_requests: &[InstanceRequest], _replace: bool) -> Launch {
+        Launch::Primary(primary().0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(target_os = "linux")]
+    use crate::providers::sysfs::TempTree;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn command_line_requests_parse_in_order() {
+        assert_eq!(parse_args(args(&[])).unwrap(), LaunchArgs::default());
+        assert_eq!(
+            parse_args(args(&["--tab", "Processes", "--command=clean-ram", "--show"])).unwrap(),
+            LaunchArgs {
+                requests: vec![
+                    InstanceRequest::SelectTab(Tab::Processes),
+                    InstanceRequest::Run(InstanceCommand::CleanRam),
+                    InstanceRequest::Show,
+                ],
+                replace: false,
+            }
+        );
+        assert!(parse_args(args(&[REPLACE_FLAG])).unwrap().replace);
+        assert!(parse_args(args(&["--tab"])).is_err());
+        assert!(parse_args(args(&["--tab", "nowhere"])).is_err());
+        assert!(parse_args(args(&["--verbose"])).is_err());
+    }
+
+    #[test]
+    fn every_request_round_trips_through_the_wire_format() {
+        let requests = TABS
+            .iter()
+            .map(|(_, tab)| InstanceRequest::SelectTab(*tab))
+            .chain(COMMANDS.iter().map(|(_, command)| InstanceRequest::Run(*command)))
+            .chain([InstanceRequest::Show]);
+        for request in requests {
+            assert_eq!(InstanceRequest::decode(&request.encode()), Some(request));
+        }
+        assert_eq!(InstanceRequest::decode("tab processes extra"), None);
+        assert_eq!(InstanceRequest::decode("rm -rf /"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn second_launch_hands_its_requests_to_the_lock_holder() {
+        let dir = TempTree::new("instance-handoff");
+
+        let (Launch::Primary(primary), Some(_lock)) =
+            platform::acquire_in(&[dir.path()], &[InstanceRequest::Show], false)
+        else {
+            panic!("first launch should take the lock");
+        };
+        let requests = [
+            InstanceRequest::SelectTab(Tab::Timeline),
+            InstanceRequest::Run(InstanceCommand::Pause),
+        ];
+        assert!(matches!(
+            platform::acquire_in(&[dir.path()], &requests, false),
+            (Launch::HandedOff, None)
+        ));
+
+        let timeout = std::time::Duration::from_secs(5);
+        let received: Vec<_> = (0..2)
+            .map(|_| primary.requests.recv_timeout(timeout).unwrap())
+            .collect();
+        assert_eq!(received, requests);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn replace_launch_waits_for_the_closing_instance_instead_of_handing_off() {
+        use std::os::unix::io::AsRawFd;
+
+        let dir = TempTree::new("instance-replace");
+
+        // Stand-in for a copy that is still closing: it holds the lock and listens.
+        let old_lock = std::fs::File::create(dir.join("instance.lock")).unwrap();
+        assert_eq!(
+            unsafe { libc::flock(old_lock.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) },
+            0
+        );
+        let old_listener = std::os::unix::net::UnixListener::bind(dir.join("instance.sock")).unwrap();
+        old_listener.set_nonblocking(true).unwrap();
+        let exiting = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(300));
+            drop(old_lock);
+        });
+
+        let launch = platform::acquire_in(&[dir.path()], &[InstanceRequest::Show], true);
+        assert!(matches!(launch, (Launch::Primary(_), Some(_))));
+        assert_eq!(
+            old_listener.accept().map(|_| ()).unwrap_err().kind(),
+            std::io::ErrorKind::WouldBlock,
+            "the closing instance must not receive a hand-off"
+        );
+        exiting.join().unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn silent_client_does_not_block_later_launches() {
+        let dir = TempTree::new("instance-silent");
+        let (Launch::Primary(primary), Some(_lock)) =
+            platform::acquire_in(&[dir.path()], &[InstanceRequest::Show], false)
+        else {
+            panic!("first launch should take the lock");
+        };
+
+        // Connects first and never writes; the listener must give up on it.
+        let _silent = std::os::unix::net::UnixStream::connect(dir.join("instance.sock")).unwrap();
+        let requests = [InstanceRequest::Run(InstanceCommand::Pause)];
+        assert!(matches!(
+            platform::acquire_in(&[dir.path()], &requests, false),
+            (Launch::HandedOff, None)
+        ));
+        let received = primary.requests.recv_timeout(CLIENT_TIMEOUT * 3).unwrap();
+        assert_eq!(received, requests[0]);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn rejected_directory_falls_back_to_the_next_candidate() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let shared = TempTree::new("instance-shared");
+        std::fs::set_permissions(&*shared, std::fs::Permissions::from_mode(0o777)).unwrap();
+        let private = TempTree::new("instance-private");
+        let dirs = [shared.path(), private.path()];
+
+        let (Launch::Primary(primary), Some(_lock)) = platform::acquire_in(&dirs, &[InstanceRequest::Show], false)
+        else {
+            panic!("first launch should take the lock in the next directory");
+        };
+        assert!(!shared.join("instance.lock").exists());
+        assert!(private.join("instance.lock").exists());
+
+        // A later launch skips the same directory and still finds the primary.
+        let requests = [InstanceRequest::Run(InstanceCommand::Resume)];
+        assert!(matches!(
+            platform::acquire_in(&dirs, &requests, false),
+            (Launch::HandedOff, None)
+        ));
+        let received = primary.requests.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(received, requests[0]);
+
+        // Only with no usable directory at all does a launch run unlocked.
+        assert!(matches!(
+            platform::acquire_in(&[shared.path()], &[InstanceRequest::Show], false),
+            (Launch::Primary(_), None)
+        ));
+    }
+}
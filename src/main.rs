@@ -4,7 +4,10 @@ pub(crate) mod ui;
 use crate::ui::components::*;
 use crate::ui::theme::ThemePalette;
 use chrono::Local;
+mod containers;
+mod cpu_topology;
 mod diagnostics;
+mod instance;
 mod monitoring;
 mod network;
 mod persistence;
@@ -58,7 +61,10 @@ impl eframe::App for SystemMonitorApp {
                         .record_event(crate::timeline::TimelineEvent::from_audit(&record));
                     self.action_pending = false;
                     self.action_status = Some(record.message.clone());
-                    if matches!(&command, app::commands::ActionCommand::CleanRam) {
+                    if matches!(
+                        &command,
+                        app::commands::ActionCommand::CleanRam | app::commands::ActionCommand::DropPageCache
+                    ) {
                         self.ram_cleaner_state.is_cleaning = false;
                         self.ram_cleaner_state.last_cleaned = Some(Instant::now());
                         self.ram_cleaner_state.last_cleaned_display = Local::now().format("%H:%M:%S").to_string();
@@ -124,7 +130,10 @@ impl eframe::App for SystemMonitorApp {
                         .record_event(crate::timeline::TimelineEvent::from_audit(&record));
                     self.action_pending = false;
                     self.action_status = Some(record.message.clone());
-                    if matches!(&command, app::commands::ActionCommand::CleanRam) {
+                    if matches!(
+                        &command,
+                        app::commands::ActionCommand::CleanRam | app::commands::ActionCommand::DropPageCache
+                    ) {
                         self.ram_cleaner_state.is_cleaning = false;
                     }
                     self.action_history
@@ -296,6 +305,15 @@ impl eframe::App for SystemMonitorApp {
             }
         }
 
+        let instance_requests: Vec<_> = self
+            .instance_requests
+            .as_ref()
+            .map(|requests| requests.try_iter().collect())
+            .unwrap_or_default();
+        for request in instance_requests {
+            self.apply_instance_request(ctx, request);
+        }
+
         #[cfg(target_os = "windows")]
         if let Ok(event) = MenuEvent::receiver().try_recv() {
             if Some(&event.id) == self.tray_menu_quit_id.as_ref() {
@@ -593,8 +611,12 @@ impl eframe::App for SystemMonitorApp {
         }
 
         // Handle process CPU affinity changes
-        if let Some((pid, mask)) = self.affinity_change.take() {
-            self.queue_action(app::commands::ActionCommand::SetAffinity { pid, mask });
+        if let Some((pid, group)) = self.affinity_change.take() {
+            self.queue_action(app::commands::ActionCommand::SetAffinity {
+                pid,
+                mask: group.affinity_mask(),
+                group: group.label,
+            });
         }
 
         // Auto RAM cleaning
@@ -633,12 +655,12 @@ impl eframe::App for SystemMonitorApp {
                         let mut monitor = SystemMonitor::new();
                         let mut freed_total = 0u64;
                         for _pass in 0..5 {
-                            let freed = monitor.clean_ram(&exclusions, smart_only);
                             let budget_left = if max_mb == 0 {
                                 u64::MAX
                             } else {
                                 (max_mb * 1024 * 1024).saturating_sub(freed_total)
                             };
+                            let freed = monitor.clean_ram(&exclusions, smart_only, budget_left);
                             freed_total = freed_total.saturating_add(freed);
                             monitor.sys.refresh_memory();
                             let usage_pct = if total_ram > 0 {
@@ -1719,42 +1741,42 @@ mod tests {
 }
 fn main() {
     // ── 1. Single-Instance Enforcement ──────────────────────────────────
-    // Prevent multiple copies from running simultaneously using a Windows named mutex.
-    #[cfg(target_os = "windows")]
-    {
-        extern "system" {
-            fn CreateMutexW(
-                lp_mutex_attributes: *const std::ffi::c_void,
-                b_initial_owner: i32,
-                lp_name: *const u16,
-            ) -> *mut std::ffi::c_void;
-            fn GetLastError() -> u32;
+    // A later launch hands its command line to the running instance and exits.
+    let launch = match instance::parse_args(std::env::args().skip(1)) {
+        Ok(launch) => launch,
+        Err(error) => {
+            eprintln!("{error}\n{}", instance::usage());
+            std::process::exit(2);
         }
+    };
+    let primary_instance = match instance::acquire(&launch) {
+        instance::Launch::Primary(primary) => primary,
+        instance::Launch::HandedOff => std::process::exit(0),
+        instance::Launch::Unreachable(error) => {
+            eprintln!("System Monitor is already running but did not respond: {error}");
 
-        let mutex_name: Vec<u16> = "Global\\SystemMonitorSingleInstance\0".encode_utf16().collect();
-        let _handle = unsafe { CreateMutexW(std::ptr::null(), 1, mutex_name.as_ptr()) };
-        let last_error = unsafe { GetLastError() };
-
-        const ERROR_ALREADY_EXISTS: u32 = 183;
-        if last_error == ERROR_ALREADY_EXISTS {
-            use windows::core::PCWSTR;
-            use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, MB_ICONINFORMATION, MB_OK};
+            #[cfg(target_os = "windows")]
+            {
+                use windows::core::PCWSTR;
+                use windows::Win32::UI::WindowsAndMessaging::{MessageBoxW, MB_ICONINFORMATION, MB_OK};
 
-            let title: Vec<u16> = "System Monitor\0".encode_utf16().collect();
-            let msg: Vec<u16> = "System Monitor is already running.\n\nCheck your system tray or taskbar.\0"
-                .encode_utf16()
-                .collect();
-            unsafe {
-                let _ = MessageBoxW(
-                    None,
-                    PCWSTR(msg.as_ptr()),
-                    PCWSTR(title.as_ptr()),
-                    MB_OK | MB_ICONINFORMATION,
-                );
+                let title: Vec<u16> = "System Monitor\0".encode_utf16().collect();
+                let msg: Vec<u16> = "System Monitor is already running.\n\nCheck your system tray or taskbar.\0"
+                    .encode_utf16()
+                    .collect();
+                unsafe {
+                    let _ = MessageBoxW(
+                        None,
+                        PCWSTR(msg.as_ptr()),
+                        PCWSTR(title.as_ptr()),
+                        MB_OK | MB_ICONINFORMATION,
+                    );
+                }
             }
             std::process::exit(0);
         }
-    }
+    };
+    primary_instance.submit(&launch.requests);
 
     // ── 2. Crash Report Directory ───────────────────────────────────────
     let log_dir = directories::ProjectDirs::from("com", "Xenonesis", "SystemMonitor")
@@ -1867,7 +1889,8 @@ fn main() {
         "System Monitor",
         options,
         Box::new(|cc| {
-            let app = SystemMonitorApp::new(cc);
+            let mut app = SystemMonitorApp::new(cc);
+            app.instance_requests = Some(primary_instance.attach(&cc.egui_ctx));
             Ok(Box::new(app))
         }),
     );
@@ -0,0 +1,293 @@
+//! cgroup v2 resource accounting on Linux.
+//!
+//! Walks the unified hierarchy (normally `/sys/fs/cgroup`) and reads every
+//! group's `cpu.stat`, `cpu.max`, `memory.current`/`memory.max`, `io.stat`
+//! and `pids.current`/`pids.max`, plus `cgroup.procs` to map processes onto
+//! the group they live in. CPU time and I/O bytes are cumulative, so rates
+//! need two samples of the same group.
+
+use crate::monitoring::rates::counter_rate;
+use crate::processes::CgroupInfo;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Counters {
+    cpu_usec: u64,
+    read_bytes: u64,
+    write_bytes: u64,
+}
+
+/// Limits inherited from the nearest ancestor that sets them.
+#[derive(Debug, Default, Clone, Copy)]
+struct Limits {
+    cpu: Option<f32>,
+    memory: Option<u64>,
+    pids: Option<u64>,
+}
+
+impl Limits {
+    fn tighten(self, own: Limits) -> Self {
+        fn min<T: PartialOrd>(a: Option<T>, b: Option<T>) -> Option<T> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(if b < a { b } else { a }),
+                (a, b) => a.or(b),
+            }
+        }
+        Self {
+            cpu: min(self.cpu, own.cpu),
+            memory: min(self.memory, own.memory),
+            pids: min(self.pids, own.pids),
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct CgroupSampler {
+    previous: HashMap<String, Counters>,
+    sampled_at: Option<Instant>,
+    groups: Vec<CgroupInfo>,
+    members: HashMap<u32, String>,
+}
+
+impl CgroupSampler {
+    pub(crate) fn refresh(&mut self, cpu_count: usize) {
+        self.refresh_from(Path::new(CGROUP_ROOT), Instant::now(), cpu_count);
+    }
+
+    pub(crate) fn groups(&self) -> &[CgroupInfo] {
+        &self.groups
+    }
+
+    pub(crate) fn cgroup_of(&self, pid: u32) -> Option<&str> {
+        self.members.get(&pid).map(String::as_str)
+    }
+
+    fn refresh_from(&mut self, root: &Path, now: Instant, cpu_count: usize) {
+        // Without `cgroup.controllers` at the root this is a v1 or hybrid mount.
+        if !root.join("cgroup.controllers").is_file() {
+            *self = Self::default();
+            return;
+        }
+        let mut walk = Walk {
+            previous: &self.previous,
+            elapsed: self
+                .sampled_at
+                .map_or(Duration::ZERO, |then| now.saturating_duration_since(then)),
+            cpu_count: cpu_count.max(1) as f64,
+            counters: HashMap::new(),
+            groups: Vec::new(),
+            members: HashMap::new(),
+        };
+        walk.visit(root, "/".to_string(), Limits::default());
+
+        let Walk {
+            counters,
+            groups,
+            members,
+            ..
+        } = walk;
+        self.previous = counters;
+        self.groups = groups;
+        self.members = members;
+        self.sampled_at = Some(now);
+    }
+}
+
+struct Walk<'a> {
+    previous: &'a HashMap<String, Counters>,
+    elapsed: Duration,
+    cpu_count: f64,
+    counters: HashMap<String, Counters>,
+    groups: Vec<CgroupInfo>,
+    members: HashMap<u32, String>,
+}
+
+impl Walk<'_> {
+    fn visit(&mut self, dir: &Path, path: String, inherited: Limits) {
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).ok();
+        let own = Limits {
+            cpu: read("cpu.max")
+                .as_deref()
+                .and_then(parse_cpu_max)
+                .map(|cpus| (cpus / self.cpu_count * 100.0) as f32),
+            memory: read("memory.max").as_deref().and_then(parse_limit),
+            pids: read("pids.max").as_deref().and_then(parse_limit),
+        };
+        let limits = inherited.tighten(own);
+
+        let (read_bytes, write_bytes) = read("io.stat").as_deref().map(parse_io_stat).unwrap_or_default();
+        let counters = Counters {
+            cpu_usec: read("cpu.stat")
+                .as_deref()
+                .and_then(|stat| keyed_value(stat, "usage_usec"))
+                .unwrap_or_default(),
+            read_bytes,
+            write_bytes,
+        };
+        let mut group = CgroupInfo {
+            path: path.clone(),
+            cpu_limit: limits.cpu,
+            memory_current: read("memory.current")
+                .as_deref()
+                .and_then(|value| value.trim().parse().ok())
+                .unwrap_or_default(),
+            memory_max: limits.memory,
+            pids_current: read("pids.current")
+                .as_deref()
+                .and_then(|value| value.trim().parse().ok())
+                .unwrap_or_default(),
+            pids_max: limits.pids,
+            ..Default::default()
+        };
+        if let Some(previous) = self.previous.get(&path).filter(|_| !self.elapsed.is_zero()) {
+            let rate = |before, current| counter_rate(Some(before), current, self.elapsed).value_per_second;
+            let cpu_secs_per_sec = rate(previous.cpu_usec, counters.cpu_usec) / 1_000_000.0;
+            group.cpu_usage = (cpu_secs_per_sec / self.cpu_count * 100.0) as f32;
+            group.io_read_rate = rate(previous.read_bytes, counters.read_bytes);
+            group.io_write_rate = rate(previous.write_bytes, counters.write_bytes);
+        }
+
+        for pid in read("cgroup.procs")
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| line.trim().parse::<u32>().ok())
+        {
+            self.members.insert(pid, path.clone());
+        }
+        self.counters.insert(path.clone(), counters);
+        self.groups.push(group);
+
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut children: Vec<_> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        children.sort();
+        for child in children {
+            let child_path = if path == "/" {
+                format!("/{child}")
+            } else {
+                format!("{path}/{child}")
+            };
+            self.visit(&dir.join(&child), child_path, limits);
+        }
+    }
+}
+
+/// `memory.max` / `pids.max`: a number, or `max` for no limit.
+fn parse_limit(contents: &str) -> Option<u64> {
+    contents.trim().parse().ok()
+}
+
+/// `cpu.max` is `<quota> <period>` in microseconds, or `max <period>`.
+/// Returns the quota as a number of CPUs.
+fn parse_cpu_max(contents: &str) -> Option<f64> {
+    let mut fields = contents.split_whitespace();
+    let quota: f64 = fields.next()?.parse().ok()?;
+    let period: f64 = fields.next()?.parse().ok()?;
+    (period > 0.0).then(|| quota / period)
+}
+
+/// Value of a `key value` line in flat-keyed files such as `cpu.stat`.
+fn keyed_value(contents: &str, key: &str) -> Option<u64> {
+    contents.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        (name == key).then(|| value.trim().parse().ok()).flatten()
+    })
+}
+
+/// Sum `rbytes` and `wbytes` over every device line of `io.stat`.
+fn parse_io_stat(contents: &str) -> (u64, u64) {
+    let mut totals = (0u64, 0u64);
+    for field in contents.split_whitespace() {
+        let Some((name, value)) = field.split_once('=') else {
+            continue;
+        };
+        let Ok(value) = value.parse::<u64>() else {
+            continue;
+        };
+        match name {
+            "rbytes" => totals.0 = totals.0.saturating_add(value),
+            "wbytes" => totals.1 = totals.1.saturating_add(value),
+            _ => {}
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    #[test]
+    fn parses_limits_and_counters() {
+        assert_eq!(parse_limit("max\n"), None);
+        assert_eq!(parse_limit("536870912\n"), Some(536_870_912));
+        assert_eq!(parse_cpu_max("max 100000\n"), None);
+        assert_eq!(parse_cpu_max("150000 100000\n"), Some(1.5));
+        assert_eq!(
+            keyed_value("usage_usec 123456\nuser_usec 100000\n", "usage_usec"),
+            Some(123_456)
+        );
+        assert_eq!(
+            parse_io_stat(
+                "8:0 rbytes=1000 wbytes=2000 rios=1 wios=2 dbytes=0 dios=0\n\
+                 259:0 rbytes=500 wbytes=10 rios=1 wios=1 dbytes=0 dios=0\n"
+            ),
+            (1500, 2010)
+        );
+    }
+
+    #[test]
+    fn samples_a_fake_hierarchy() {
+        let root = TempTree::new("cgroup");
+        let slice = Path::new("app.slice");
+        let service = slice.join("web.service");
+        let write = |dir: &Path, name: &str, contents: &str| root.write(dir.join(name), contents);
+        root.write("cgroup.controllers", "cpu io memory pids\n");
+        root.write("cgroup.procs", "1\n");
+        write(slice, "memory.max", "1073741824\n");
+        write(slice, "cpu.max", "200000 100000\n");
+        write(&service, "memory.max", "max\n");
+        write(&service, "pids.max", "64\n");
+        write(&service, "pids.current", "8\n");
+        write(&service, "memory.current", "268435456\n");
+        write(&service, "cgroup.procs", "42\n43\n");
+        write(&service, "cpu.stat", "usage_usec 1000000\n");
+        write(&service, "io.stat", "8:0 rbytes=0 wbytes=0\n");
+
+        let mut sampler = CgroupSampler::default();
+        let start = Instant::now();
+        sampler.refresh_from(&root, start, 4);
+        write(&service, "cpu.stat", "usage_usec 3000000\n");
+        write(&service, "io.stat", "8:0 rbytes=4096 wbytes=8192\n");
+        sampler.refresh_from(&root, start + Duration::from_secs(2), 4);
+
+        assert_eq!(sampler.cgroup_of(42), Some("/app.slice/web.service"));
+        assert_eq!(sampler.cgroup_of(1), Some("/"));
+        let web = sampler
+            .groups()
+            .iter()
+            .find(|group| group.path == "/app.slice/web.service")
+            .unwrap();
+        // 2 s of CPU over 2 s on 4 CPUs, limited to 2 CPUs by the parent slice.
+        assert_eq!(web.cpu_usage, 25.0);
+        assert_eq!(web.cpu_limit, Some(50.0));
+        assert_eq!(web.memory_max, Some(1_073_741_824));
+        assert_eq!(web.pids_max, Some(64));
+        assert_eq!((web.io_read_rate, web.io_write_rate), (2048.0, 4096.0));
+
+        std::fs::remove_dir_all(root.path()).unwrap();
+        sampler.refresh_from(&root, start + Duration::from_secs(4), 4);
+        assert!(sampler.groups().is_empty());
+        assert_eq!(sampler.cgroup_of(42), None);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::app::models::*;
+use crate::app::worker::ActionError;
 use crate::{monitoring, power, processes, services};
 use chrono::Local;
 use parking_lot::{Mutex, RwLock};
@@ -90,7 +91,12 @@ impl SystemMonitor {
             last_network_update: Instant::now(),
             last_disk_update: Instant::now(),
             previous_network_totals: std::collections::HashMap::new(),
+            previous_network_faults: std::collections::HashMap::new(),
             previous_disk_totals: (0, 0),
+            #[cfg(target_os = "linux")]
+            gpu_clients: Default::default(),
+            #[cfg(target_os = "linux")]
+            cgroups: Default::default(),
         }
     }
 
@@ -126,6 +132,7 @@ impl SystemMonitor {
                     }
                 }
 
+                let (gpu_usage, gpu_memory) = self.process_gpu_usage(pid.as_u32());
                 crate::processes::ProcessInfo {
                     pid: pid.as_u32(),
                     start_time: process.start_time(),
@@ -136,6 +143,10 @@ impl SystemMonitor {
                     status: format!("{:?}", process.status()),
                     disk_read_bytes: process.disk_usage().read_bytes,
                     disk_written_bytes: process.disk_usage().written_bytes,
+                    gpu_usage,
+                    gpu_memory,
+                    cgroup: self.process_cgroup(pid.as_u32()),
+                    container: None,
                 }
             })
             .collect();
@@ -145,22 +156,47 @@ impl SystemMonitor {
         processes
     }
 
+    #[cfg(target_os = "linux")]
+    fn process_gpu_usage(&self, pid: u32) -> (f32, u64) {
+        let usage = self.gpu_clients.usage(pid);
+        (usage.usage, usage.memory)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn process_gpu_usage(&self, _pid: u32) -> (f32, u64) {
+        (0.0, 0)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn process_cgroup(&self, pid: u32) -> Option<String> {
+        self.cgroups.cgroup_of(pid).map(str::to_string)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn process_cgroup(&self, _pid: u32) -> Option<String> {
+        None
+    }
+
     fn get_timeline_processes(&self, per_metric: usize) -> Vec<crate::processes::ProcessInfo> {
         let processes = self.get_top_processes(usize::MAX);
         let mut selected = std::collections::BTreeMap::new();
         let mut by_cpu = processes.iter().collect::<Vec<_>>();
         let mut by_memory = by_cpu.clone();
         let mut by_disk = by_cpu.clone();
+        let mut by_gpu = by_cpu.clone();
         by_cpu.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
         by_memory.sort_by_key(|process| std::cmp::Reverse(process.memory));
         by_disk.sort_by_key(|process| {
             std::cmp::Reverse(process.disk_read_bytes.saturating_add(process.disk_written_bytes))
         });
+        by_gpu.retain(|process| process.gpu_usage > 0.0 || process.gpu_memory > 0);
+        by_gpu.sort_by(|a, b| b.gpu_usage.total_cmp(&a.gpu_usage));
         for process in by_cpu
             .into_iter()
             .take(per_metric)
             .chain(by_memory.into_iter().take(per_metric))
             .chain(by_disk.into_iter().take(per_metric))
+            .chain(by_gpu.into_iter().take(per_metric))
         {
             selected.insert((process.pid, process.start_time), process.clone());
         }
@@ -176,6 +212,7 @@ impl SystemMonitor {
                 core_id: id,
                 usage: cpu.cpu_usage(),
                 name: cpu.name().to_string(),
+                frequency_mhz: cpu.frequency(),
             })
             .collect()
     }
@@ -197,7 +234,7 @@ impl SystemMonitor {
     }
 
     #[cfg(target_os = "windows")]
-    pub(crate) fn suspend_process(&mut self, pid: u32) -> bool {
+    pub(crate) fn suspend_process(&mut self, pid: u32) -> Result<(), ActionError> {
         use ntapi::ntpsapi::NtSuspendProcess;
         use windows::Win32::Foundation::CloseHandle;
         use windows::Win32::System::Threading::{OpenProcess, PROCESS_SUSPEND_RESUME};
@@ -207,18 +244,22 @@ impl SystemMonitor {
                 if !h.is_invalid() {
                     let result = NtSuspendProcess(h.0 as *mut _);
                     let _ = CloseHandle(h);
-                    result == 0
+                    if result == 0 {
+                        Ok(())
+                    } else {
+                        Err(ActionError::Failed(format!("NTSTATUS {result:#x}")))
+                    }
                 } else {
-                    false
+                    Err(ActionError::AccessDenied)
                 }
             } else {
-                false
+                Err(ActionError::AccessDenied)
             }
         }
     }
 
     #[cfg(target_os = "windows")]
-    pub(crate) fn resume_process(&mut self, pid: u32) -> bool {
+    pub(crate) fn resume_process(&mut self, pid: u32) -> Result<(), ActionError> {
         use ntapi::ntpsapi::NtResumeProcess;
         use windows::Win32::Foundation::CloseHandle;
         use windows::Win32::System::Threading::{OpenProcess, PROCESS_SUSPEND_RESUME};
@@ -228,24 +269,38 @@ impl SystemMonitor {
                 if !h.is_invalid() {
                     let result = NtResumeProcess(h.0 as *mut _);
                     let _ = CloseHandle(h);
-                    result == 0
+                    if result == 0 {
+                        Ok(())
+                    } else {
+                        Err(ActionError::Failed(format!("NTSTATUS {result:#x}")))
+                    }
                 } else {
-                    false
+                    Err(ActionError::AccessDenied)
                 }
             } else {
-                false
+                Err(ActionError::AccessDenied)
             }
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
-    pub(crate) fn suspend_process(&mut self, _pid: u32) -> bool {
-        false
+    #[cfg(target_os = "linux")]
+    pub(crate) fn suspend_process(&mut self, pid: u32) -> Result<(), ActionError> {
+        processes::linux::signal_process(pid, libc::SIGSTOP).map_err(ActionError::from)
     }
 
-    #[cfg(not(target_os = "windows"))]
-    pub(crate) fn resume_process(&mut self, _pid: u32) -> bool {
-        false
+    #[cfg(target_os = "linux")]
+    pub(crate) fn resume_process(&mut self, pid: u32) -> Result<(), ActionError> {
+        processes::linux::signal_process(pid, libc::SIGCONT).map_err(ActionError::from)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    pub(crate) fn suspend_process(&mut self, _pid: u32) -> Result<(), ActionError> {
+        Err(ActionError::Unavailable)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    pub(crate) fn resume_process(&mut self, _pid: u32) -> Result<(), ActionError> {
+        Err(ActionError::Unavailable)
     }
 
     fn get_swap_info(&self) -> SwapInfo {
@@ -263,8 +318,12 @@ impl SystemMonitor {
         }
     }
 
+    /// Trim process memory, skipping excluded names (and the foreground app in
+    /// smart mode). Returns the bytes actually freed. `budget` caps a single
+    /// pass on Linux; Windows trims whole passes and relies on the caller's
+    /// `should_stop_cleaning` check between them.
     #[cfg(target_os = "windows")]
-    pub fn clean_ram(&mut self, exclusions: &[String], smart_only: bool) -> u64 {
+    pub fn clean_ram(&mut self, exclusions: &[String], smart_only: bool, _budget: u64) -> u64 {
         use windows::Win32::Foundation::{CloseHandle, E_ACCESSDENIED};
         use windows::Win32::System::ProcessStatus::EmptyWorkingSet;
         use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_SET_QUOTA};
@@ -326,15 +385,57 @@ impl SystemMonitor {
         freed
     }
 
-    #[cfg(not(target_os = "windows"))]
-    pub(crate) fn clean_ram(&mut self, _exclusions: &[String], _smart_only: bool) -> u64 {
+    #[cfg(target_os = "linux")]
+    pub(crate) fn clean_ram(&mut self, exclusions: &[String], smart_only: bool, budget: u64) -> u64 {
+        use monitoring::reclaim::{self, ReclaimTarget};
+
+        info!(
+            excluded = exclusions.len(),
+            "RAM clean operation initiated (process_madvise / memory.reclaim)"
+        );
+        self.sys.refresh_processes();
+        let own_pid = std::process::id();
+        let mut targets: Vec<ReclaimTarget> = self
+            .sys
+            .processes()
+            .iter()
+            .filter(|(pid, process)| {
+                pid.as_u32() != own_pid
+                    && process.thread_kind().is_none()
+                    && process.memory() > 0
+                    && !is_excluded(process.name(), exclusions)
+                    // There is no portable notion of a focused window on Linux, so
+                    // smart mode leaves anything currently on a CPU alone.
+                    && !(smart_only && process.status() == sysinfo::ProcessStatus::Run)
+            })
+            .map(|(pid, process)| ReclaimTarget {
+                pid: pid.as_u32(),
+                resident: process.memory(),
+            })
+            .collect();
+        targets.sort_by_key(|target| std::cmp::Reverse(target.resident));
+
+        let outcome = reclaim::reclaim(&targets, budget);
+        info!(
+            freed_mb = outcome.freed / 1024 / 1024,
+            paged_out = outcome.reclaimed,
+            access_denied = outcome.denied,
+            errored = outcome.failed,
+            cgroups = outcome.cgroups,
+            "RAM clean complete"
+        );
+        outcome.freed
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    pub(crate) fn clean_ram(&mut self, _exclusions: &[String], _smart_only: bool, _budget: u64) -> u64 {
         0
     }
 
     // Startup item collection and actions are now in startup.rs module
 
     #[cfg(target_os = "windows")]
-    pub(crate) fn set_process_priority(pid: u32, priority: &str) -> bool {
+    pub(crate) fn set_process_priority(pid: u32, priority: &str) -> Result<(), ActionError> {
         use windows::Win32::Foundation::CloseHandle;
         use windows::Win32::System::Threading::{OpenProcess, SetPriorityClass, PROCESS_CREATION_FLAGS};
 
@@ -345,7 +446,7 @@ impl SystemMonitor {
             "Normal" => windows::Win32::System::Threading::NORMAL_PRIORITY_CLASS,
             "BelowNormal" => windows::Win32::System::Threading::BELOW_NORMAL_PRIORITY_CLASS,
             "Idle" => windows::Win32::System::Threading::IDLE_PRIORITY_CLASS,
-            _ => return false,
+            _ => return Err(ActionError::InvalidInput(format!("unknown priority {priority}"))),
         };
 
         unsafe {
@@ -353,19 +454,24 @@ impl SystemMonitor {
                 if !h.is_invalid() {
                     let result = SetPriorityClass(h, priority_class);
                     let _ = CloseHandle(h);
-                    result.is_ok()
+                    result.map_err(|_| ActionError::AccessDenied)
                 } else {
-                    false
+                    Err(ActionError::AccessDenied)
                 }
             } else {
-                false
+                Err(ActionError::AccessDenied)
             }
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
-    pub(crate) fn set_process_priority(_pid: u32, _priority: &str) -> bool {
-        false
+    #[cfg(target_os = "linux")]
+    pub(crate) fn set_process_priority(pid: u32, priority: &str) -> Result<(), ActionError> {
+        processes::linux::set_priority(pid, priority).map_err(ActionError::from)
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    pub(crate) fn set_process_priority(_pid: u32, _priority: &str) -> Result<(), ActionError> {
+        Err(ActionError::Unavailable)
     }
 
     #[cfg(target_os = "windows")]
@@ -637,8 +743,10 @@ impl SystemMonitor {
                     0.0
                 };
 
+                let name = disk.name().to_string_lossy().to_string();
                 DiskInfo {
-                    name: disk.name().to_string_lossy().to_string(),
+                    block_device: crate::storage::block_device_of(&name),
+                    name,
                     mount_point: disk.mount_point().to_string_lossy().to_string(),
                     total_space: total,
                     available_space: available,
@@ -672,6 +780,35 @@ impl SystemMonitor {
         )
     }
 
+    /// Message and value for the memory alert when memory is past its threshold,
+    /// judged on used or available memory according to `memory_alert_basis`.
+    fn memory_alert(settings: &AppSettings, data: &SystemData) -> Option<(String, f32)> {
+        match settings.memory_alert_basis {
+            MemoryAlertBasis::Used => (data.memory_percentage > settings.notification_memory_threshold).then(|| {
+                (
+                    format!("Memory usage is high: {:.1}%", data.memory_percentage),
+                    data.memory_percentage,
+                )
+            }),
+            MemoryAlertBasis::Available => {
+                if data.memory_total == 0 {
+                    return None;
+                }
+                let available = (data.memory_available as f64 / data.memory_total as f64 * 100.0) as f32;
+                (available < settings.notification_memory_available_threshold).then(|| {
+                    (
+                        format!(
+                            "Available memory is low: {:.1}% ({:.1} GB) left",
+                            available,
+                            crate::ui::components::bytes_to_gb(data.memory_available)
+                        ),
+                        available,
+                    )
+                })
+            }
+        }
+    }
+
     fn check_alerts(settings: &AppSettings, data: &SystemData) -> Vec<AlertInfo> {
         let mut alerts = Vec::new();
         let timestamp = Local::now().format("%H:%M:%S").to_string();
@@ -688,13 +825,13 @@ impl SystemMonitor {
         }
 
         // Memory alert
-        if data.memory_percentage > settings.notification_memory_threshold {
+        if let Some((message, value)) = Self::memory_alert(settings, data) {
             alerts.push(AlertInfo {
                 timestamp: timestamp.clone(),
                 alert_type: AlertType::MemoryHigh,
                 source: AlertSource::Memory,
-                message: format!("Memory usage is high: {:.1}%", data.memory_percentage),
-                value: data.memory_percentage,
+                message,
+                value,
             });
         }
 
@@ -751,7 +888,9 @@ impl SystemMonitor {
 
     fn get_network_info(&mut self) -> Vec<NetworkInfo> {
         let elapsed = self.last_network_update.elapsed();
+        let mut interface_details = crate::network::get_interface_details();
         let mut current_totals = std::collections::HashMap::new();
+        let mut current_faults = std::collections::HashMap::new();
         let network_info = self
             .networks
             .iter()
@@ -761,16 +900,42 @@ impl SystemMonitor {
                 current_totals.insert(interface.clone(), current);
                 let received_rate = monitoring::rates::counter_rate(previous.map(|p| p.0), current.0, elapsed);
                 let transmitted_rate = monitoring::rates::counter_rate(previous.map(|p| p.1), current.1, elapsed);
+
+                let details = interface_details.remove(interface).unwrap_or_else(|| {
+                    let mac = data.mac_address();
+                    crate::network::InterfaceDetails {
+                        kind: crate::network::InterfaceKind::from_name(interface),
+                        mac: (!mac.is_unspecified()).then(|| mac.to_string()),
+                        counters: crate::network::InterfaceCounters {
+                            rx_packets: data.total_packets_received(),
+                            tx_packets: data.total_packets_transmitted(),
+                            rx_errors: data.total_errors_on_received(),
+                            tx_errors: data.total_errors_on_transmitted(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }
+                });
+                let faults = (details.counters.errors(), details.counters.dropped());
+                let previous_faults = self.previous_network_faults.get(interface).copied();
+                current_faults.insert(interface.clone(), faults);
+                let error_rate = monitoring::rates::counter_rate(previous_faults.map(|p| p.0), faults.0, elapsed);
+                let drop_rate = monitoring::rates::counter_rate(previous_faults.map(|p| p.1), faults.1, elapsed);
+
                 NetworkInfo {
                     interface: interface.clone(),
                     received: current.0,
                     transmitted: current.1,
                     received_rate: received_rate.value_per_second / 1024.0 / 1024.0,
                     transmitted_rate: transmitted_rate.value_per_second / 1024.0 / 1024.0,
+                    details,
+                    error_rate: error_rate.value_per_second,
+                    drop_rate: drop_rate.value_per_second,
                 }
             })
             .collect();
         self.previous_network_totals = current_totals;
+        self.previous_network_faults = current_faults;
         self.last_network_update = Instant::now();
         network_info
     }
@@ -794,6 +959,7 @@ impl SystemMonitor {
             bios_version,
             gpu_driver,
             os_build,
+            hypervisor: None,
         }
     }
 }
@@ -863,8 +1029,16 @@ pub(crate) struct SystemMonitorApp {
     pub(crate) suspended_pids: std::collections::HashSet<u32>,
     pub(crate) priority_change: Option<(u32, String)>,
     pub(crate) process_tree_view: bool,
-    pub(crate) affinity_change: Option<(u32, usize)>,
+    /// Processes page shows cgroup v2 groups instead of a flat list (Linux).
+    pub(crate) process_group_by_cgroup: bool,
+    pub(crate) expanded_cgroups: std::collections::HashSet<String>,
+    /// Processes page shows Docker/Podman containers instead of a flat list.
+    pub(crate) process_group_by_container: bool,
+    /// Container IDs whose member processes are listed.
+    pub(crate) expanded_containers: std::collections::HashSet<String>,
+    pub(crate) affinity_change: Option<(u32, crate::cpu_topology::CpuGroup)>,
     pub(crate) network_socket_search: String,
+    pub(crate) network_socket_family: Option<crate::network::AddressFamily>,
     #[allow(dead_code)]
     #[cfg(target_os = "windows")]
     pub(crate) tray_icon: Option<tray_icon::TrayIcon>,
@@ -901,9 +1075,11 @@ pub(crate) struct SystemMonitorApp {
     pub(crate) widget_open: bool,
     /// Whether we have already applied the start_minimized setting on the first frame.
     pub(crate) start_minimized_applied: bool,
+    /// Requests handed over by later launches of the app.
+    pub(crate) instance_requests: Option<std::sync::mpsc::Receiver<crate::instance::InstanceRequest>>,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum Tab {
     Overview,
     Performance,
@@ -1130,11 +1306,29 @@ impl SystemMonitorApp {
 
         let (mut telemetry_hub, mut telemetry_reader, telemetry_commands) = crate::telemetry::TelemetryHub::new();
         telemetry_hub.add_provider(Box::new(crate::providers::sysinfo_provider::SysinfoProvider::new()));
-        telemetry_hub.add_provider(Box::new(crate::providers::nvml_provider::NvmlProvider::new()));
+        let nvml_provider = crate::providers::nvml_provider::NvmlProvider::new();
+        let nvml_devices = nvml_provider.device_count();
+        telemetry_hub.add_provider(Box::new(nvml_provider));
         telemetry_hub.add_provider(Box::new(crate::providers::wmi_provider::WmiProvider::new()));
         telemetry_hub.add_provider(Box::new(
             crate::providers::windows_gpu_provider::WindowsGpuProvider::new(),
         ));
+        telemetry_hub.add_provider(Box::new(crate::providers::hwmon_provider::HwmonProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::inventory_provider::InventoryProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::drm_provider::DrmGpuProvider::new(
+            nvml_devices,
+        )));
+        telemetry_hub.add_provider(Box::new(crate::providers::psi_provider::PsiProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::diskstats_provider::DiskStatsProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::meminfo_provider::MeminfoProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::loadavg_provider::LoadProvider::new()));
+        telemetry_hub.add_provider(Box::new(crate::providers::throttle_provider::ThrottleProvider::new()));
+        telemetry_hub.add_provider(Box::new(
+            crate::providers::virtualization_provider::VirtualizationProvider::new(),
+        ));
+        telemetry_hub.add_provider(Box::new(crate::providers::container_provider::ContainerProvider::new(
+            Arc::clone(&shared_settings),
+        )));
         thread::Builder::new()
             .name("telemetry_hub".to_string())
             .spawn(move || telemetry_hub.run())
@@ -1149,13 +1343,14 @@ impl SystemMonitorApp {
                 let mut monitor = SystemMonitor::new();
 
                 // Get system info once (doesn't change)
-                let system_info = monitor.get_system_info();
+                let mut system_info = monitor.get_system_info();
                 let mut battery_check_counter: u32 = 0;
                 let mut temperature_check_counter: u32 = 0;
                 let mut service_check_counter: u32 = 0;
                 let mut disk_smart_check_counter: u32 = 0;
                 let mut sockets_check_counter: u32 = 0;
                 let mut power_plans_check_counter: u32 = 0;
+                let mut throttle_detector = crate::monitoring::throttle::ThrottleDetector::new();
                 let mut last_alert_time: std::collections::HashMap<AlertType, Instant> =
                     std::collections::HashMap::new();
                 let mut last_hidden_tick = Instant::now();
@@ -1225,6 +1420,8 @@ impl SystemMonitorApp {
                     // native structures; core CPU/RAM/GPU values come from the hub.
                     if !is_hidden {
                         monitor.sys.refresh_processes();
+                        #[cfg(target_os = "linux")]
+                        monitor.gpu_clients.refresh();
                         monitor.disks.refresh();
                         monitor.networks.refresh();
                     }
@@ -1242,6 +1439,12 @@ impl SystemMonitorApp {
                         .copied()
                         .map(|value| value as u64)
                         .unwrap_or(fallback_memory.1);
+                    let available_mem = latest_telemetry
+                        .metrics
+                        .get("memory.available")
+                        .copied()
+                        .map(|value| value as u64)
+                        .unwrap_or_else(|| monitor.sys.available_memory());
                     let mem_percentage = if total_mem == 0 {
                         0.0
                     } else {
@@ -1256,7 +1459,8 @@ impl SystemMonitorApp {
 
                     // Optimized queries
                     let need_cpu_cores = !is_hidden && (selected_tab == Tab::Overview || selected_tab == Tab::CpuCores);
-                    let need_cpu_temp = !is_hidden && selected_tab == Tab::Overview;
+                    // CPU Cores needs a temperature to tell throttling apart from power saving.
+                    let need_cpu_temp = !is_hidden && matches!(selected_tab, Tab::Overview | Tab::CpuCores);
                     let need_gpu_wmi =
                         !is_hidden && (selected_tab == Tab::Overview || selected_tab == Tab::Performance);
                     let need_gpu_info = need_gpu_wmi
@@ -1287,11 +1491,18 @@ impl SystemMonitorApp {
                         Vec::new()
                     };
 
-                    let cpu_temperature = if need_cpu_temp && temperature_check_counter % 10 == 0 {
-                        monitor.get_cpu_temperature_wmi()
-                    } else {
-                        None
-                    };
+                    // hwmon publishes every second; WMI thermal zones are slow, so only poll them periodically.
+                    let cpu_temperature = latest_telemetry
+                        .metrics
+                        .get("cpu.temperature")
+                        .map(|&value| value as f32)
+                        .or_else(|| {
+                            if need_cpu_temp && temperature_check_counter % 10 == 0 {
+                                monitor.get_cpu_temperature_wmi()
+                            } else {
+                                None
+                            }
+                        });
                     temperature_check_counter = temperature_check_counter.wrapping_add(1);
 
                     let gpu_info = if need_gpu_info {
@@ -1305,6 +1516,7 @@ impl SystemMonitorApp {
                         Vec::new()
                     };
 
+                    let containers = containers_from_telemetry(&latest_telemetry);
                     let top_processes = if need_processes {
                         // On Processes tab, fetch ALL processes so search/sort works on the full list.
                         // On Overview tab, only fetch the top N by memory for the summary panel.
@@ -1313,7 +1525,13 @@ impl SystemMonitorApp {
                         } else {
                             process_count
                         };
-                        monitor.get_top_processes(fetch_count)
+                        #[cfg(target_os = "linux")]
+                        if selected_tab == Tab::Processes {
+                            monitor.cgroups.refresh(monitor.sys.cpus().len());
+                        }
+                        let mut processes = monitor.get_top_processes(fetch_count);
+                        crate::containers::assign_containers(&mut processes, &containers);
+                        processes
                     } else {
                         Vec::new()
                     };
@@ -1366,7 +1584,9 @@ impl SystemMonitorApp {
 
                     let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-                    // Get battery info every 15 ticks (~7.5s) — retain previous value if unavailable
+                    // Get battery info every 15 ticks (~7.5s) — retain previous value if unavailable.
+                    // Other platforms get capacity data from `power::get_battery_health` instead.
+                    #[cfg(target_os = "windows")]
                     if battery_check_counter % 15 == 0 {
                         let mut bi = None;
                         if let Some(wmi_con) = monitor.get_battery_wmi() {
@@ -1379,17 +1599,21 @@ impl SystemMonitorApp {
                     }
                     battery_check_counter = battery_check_counter.wrapping_add(1);
 
-                    // Poll services every 60 ticks (~30s) — WMI queries are expensive
+                    // Poll services every 60 ticks (~30s) — WMI queries and systemctl spawns are expensive
                     if !is_hidden && selected_tab == Tab::Services {
                         let services_list = if last_selected_tab != Tab::Services
                             || data_clone.read().services.is_empty()
                             || service_check_counter % 60 == 0
                         {
-                            if let Some(ref com) = monitor.wmi_com {
+                            #[cfg(target_os = "windows")]
+                            let list = if let Some(ref com) = monitor.wmi_com {
                                 services::get_services_with_com(Some(com))
                             } else {
                                 services::get_services()
-                            }
+                            };
+                            #[cfg(not(target_os = "windows"))]
+                            let list = services::get_services();
+                            list
                         } else {
                             Vec::new()
                         };
@@ -1435,8 +1659,8 @@ impl SystemMonitorApp {
                         && (selected_tab == Tab::SystemInfo || selected_tab == Tab::Overview)
                         && (power_plans_check_counter % 20 == 0 || data_clone.read().power_plans.is_empty())
                     {
-                        let plans = crate::power::get_power_plans();
-                        let bat_health = crate::power::get_battery_health();
+                        let plans = power::get_power_plans();
+                        let bat_health = power::get_battery_health();
                         let mut data = data_clone.write();
                         data.power_plans = plans;
                         data.battery_health = bat_health;
@@ -1456,11 +1680,12 @@ impl SystemMonitorApp {
                         data.memory_total = total_mem;
                         data.memory_used = used_mem;
                         data.memory_percentage = mem_percentage;
+                        data.memory_available = available_mem;
                         data.cpu_usage = cpu_usage;
                         if need_cpu_cores {
                             data.cpu_cores = cpu_cores;
                         }
-                        if need_cpu_temp {
+                        if need_cpu_temp || cpu_temperature.is_some() {
                             data.cpu_temperature = cpu_temperature;
                         }
                         if need_gpu_info {
@@ -1468,6 +1693,10 @@ impl SystemMonitorApp {
                         }
                         if need_processes {
                             data.top_processes = top_processes;
+                            #[cfg(target_os = "linux")]
+                            {
+                                data.cgroups = monitor.cgroups.groups().to_vec();
+                            }
                         }
                         if settings_snapshot.timeline_enabled {
                             data.timeline_processes = timeline_processes;
@@ -1480,7 +1709,12 @@ impl SystemMonitorApp {
                         if need_network {
                             data.network_info = network_info;
                         }
+                        system_info.fill_from_labels(&latest_telemetry.labels);
                         data.system_info = system_info.clone();
+                        if selected_tab == Tab::SystemInfo || data.hardware.pci_devices.is_empty() {
+                            data.hardware = inventory_from_telemetry(&latest_telemetry);
+                        }
+                        data.containers = containers;
                         data.last_update = timestamp;
                         data.swap_info = swap_info;
                         if !is_hidden || settings_snapshot.timeline_enabled {
@@ -1490,6 +1724,24 @@ impl SystemMonitorApp {
                         data.network_sample_count += 1;
                         data.telemetry_history_stats = latest_telemetry.history_stats.clone();
                         data.provider_status = latest_telemetry.provider_status.clone();
+                        data.pressure =
+                            crate::monitoring::snapshot::PressureSnapshot::from_metrics(&latest_telemetry.metrics);
+                        data.load = crate::monitoring::snapshot::LoadSnapshot::from_metrics(&latest_telemetry.metrics);
+                        // Topology is static; on Windows it waits for the first WMI sample.
+                        if data.cpu_topology.is_none() {
+                            data.cpu_topology = crate::cpu_topology::detect(&latest_telemetry.metrics);
+                        }
+                        data.throttle = throttle_detector.observe(
+                            &latest_telemetry.metrics,
+                            cpu_usage,
+                            cpu_temperature,
+                            data.cpu_topology.as_ref(),
+                        );
+                        data.block_devices =
+                            crate::monitoring::snapshot::BlockDeviceSnapshot::from_metrics(&latest_telemetry.metrics);
+                        data.memory_breakdown = crate::monitoring::snapshot::MemoryBreakdownSnapshot::from_metrics(
+                            &latest_telemetry.metrics,
+                        );
 
                         // Check for alerts
                         let mut new_alerts = SystemMonitor::check_alerts(&settings_snapshot, &data);
@@ -1530,11 +1782,10 @@ impl SystemMonitorApp {
                             let temp_gpu_info = data.gpu_info.clone();
                             let disk_info = data.disk_info.clone();
                             let high_impact_count = data.high_impact_startup_count;
+                            let memory_alert_active = SystemMonitor::memory_alert(&settings_snapshot, &data).is_some();
                             data.alerts.retain(|alert| match alert.alert_type {
                                 AlertType::CpuHigh => cpu_usage > settings_snapshot.notification_cpu_threshold,
-                                AlertType::MemoryHigh => {
-                                    mem_percentage > settings_snapshot.notification_memory_threshold
-                                }
+                                AlertType::MemoryHigh => memory_alert_active,
                                 AlertType::GpuTempHigh => match &alert.source {
                                     AlertSource::Gpu { index, name } => temp_gpu_info.get(*index).is_some_and(|gpu| {
                                         gpu.name == *name
@@ -1569,6 +1820,26 @@ impl SystemMonitorApp {
                             value: mem_percentage as f64,
                         });
 
+                        if let Some(temperature) = cpu_temperature {
+                            data.cpu_temp_history.push(DataPoint {
+                                time: elapsed,
+                                value: temperature as f64,
+                            });
+                        }
+
+                        if !is_hidden {
+                            let frequencies = crate::monitoring::throttle::core_frequencies(&latest_telemetry.metrics);
+                            let cores = frequencies.last().map_or(0, |(id, _)| id + 1);
+                            data.cpu_freq_history
+                                .resize_with(cores, || crate::monitoring::history::BoundedHistory::new(60));
+                            for (id, mhz) in frequencies {
+                                data.cpu_freq_history[id].push(DataPoint {
+                                    time: elapsed,
+                                    value: mhz,
+                                });
+                            }
+                        }
+
                         if need_gpu_info {
                             let gpu_util = data.gpu_info.first().map(|gpu| gpu.utilization as f64);
                             if let Some(val) = gpu_util {
@@ -1599,6 +1870,19 @@ impl SystemMonitorApp {
                                 time: elapsed,
                                 value: disk_write_rate,
                             });
+                            let devices = std::mem::take(&mut data.block_devices);
+                            data.disk_util_history
+                                .retain(|name, _| devices.iter().any(|device| &device.name == name));
+                            for device in &devices {
+                                data.disk_util_history
+                                    .entry(device.name.clone())
+                                    .or_insert_with(|| crate::monitoring::history::BoundedHistory::new(60))
+                                    .push(DataPoint {
+                                        time: elapsed,
+                                        value: device.util_percent,
+                                    });
+                            }
+                            data.block_devices = devices;
                         }
 
                         // cpu_history capped at 60 by BoundedHistory; trim the rest
@@ -1837,8 +2121,13 @@ impl SystemMonitorApp {
             suspended_pids: std::collections::HashSet::new(),
             priority_change: None,
             process_tree_view: false,
+            process_group_by_cgroup: false,
+            expanded_cgroups: std::collections::HashSet::new(),
+            process_group_by_container: false,
+            expanded_containers: std::collections::HashSet::new(),
             affinity_change: None,
             network_socket_search: String::new(),
+            network_socket_family: None,
             service_search: String::new(),
             service_state_filter: None,
             service_sort_column: services::ServiceSortColumn::DisplayName,
@@ -1874,6 +2163,7 @@ impl SystemMonitorApp {
             is_hidden: false,
             widget_open: settings.show_widget,
             start_minimized_applied: false,
+            instance_requests: None,
         }
     }
     #[cfg(test)]
@@ -1956,8 +2246,13 @@ impl SystemMonitorApp {
             suspended_pids: std::collections::HashSet::new(),
             priority_change: None,
             process_tree_view: false,
+            process_group_by_cgroup: false,
+            expanded_cgroups: std::collections::HashSet::new(),
+            process_group_by_container: false,
+            expanded_containers: std::collections::HashSet::new(),
             affinity_change: None,
             network_socket_search: String::new(),
+            network_socket_family: None,
             service_search: String::new(),
             service_state_filter: None,
             service_sort_column: services::ServiceSortColumn::DisplayName,
@@ -1993,6 +2288,7 @@ impl SystemMonitorApp {
             is_hidden: false,
             widget_open: false,
             start_minimized_applied: true,
+            instance_requests: None,
         }
     }
 
@@ -2077,6 +2373,8 @@ impl SystemMonitorApp {
             disk_info: Vec<DiskInfo>,
             network_info: Vec<NetworkInfo>,
             system_info: SystemInfo,
+            hardware: HardwareInventory,
+            containers: Vec<crate::containers::ContainerInfo>,
             startup_item_count: usize,
             high_impact_startup_count: usize,
             boot_diagnostics: Option<crate::startup::BootDiagnostics>,
@@ -2099,6 +2397,8 @@ impl SystemMonitorApp {
             disk_info: data.disk_info.clone(),
             network_info: data.network_info.clone(),
             system_info: data.system_info.clone(),
+            hardware: data.hardware.clone(),
+            containers: data.containers.clone(),
             startup_item_count: self.startup_items.len(),
             high_impact_startup_count: crate::startup::high_impact_count(&self.startup_items),
             boot_diagnostics: self.boot_diagnostics.clone(),
@@ -2124,6 +2424,47 @@ impl SystemMonitorApp {
     pub fn start_ram_clean(&mut self, _ctx: &egui::Context) {
         self.queue_action(crate::app::commands::ActionCommand::CleanRam);
     }
+
+    /// Carry out a request from another launch. Everything except pause and
+    /// resume brings the window forward, like the matching tray menu items.
+    pub(crate) fn apply_instance_request(&mut self, ctx: &egui::Context, request: crate::instance::InstanceRequest) {
+        use crate::instance::{InstanceCommand, InstanceRequest};
+
+        let paused = match request {
+            InstanceRequest::Run(InstanceCommand::Pause) => Some(true),
+            InstanceRequest::Run(InstanceCommand::Resume) => Some(false),
+            _ => None,
+        };
+        if let Some(paused) = paused {
+            self.data.write().monitoring_paused = paused;
+            let _ = self
+                .app_channels
+                .monitoring_sender
+                .send(crate::app::commands::MonitoringCommand::SetPaused(paused));
+            #[cfg(target_os = "windows")]
+            if let Some(item) = &self.tray_menu_pause_item {
+                item.set_checked(paused);
+            }
+            return;
+        }
+
+        ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        self.is_hidden = false;
+        let _ = self
+            .app_channels
+            .monitoring_sender
+            .send(crate::app::commands::MonitoringCommand::SetHidden(false));
+        match request {
+            InstanceRequest::SelectTab(tab) => self.selected_tab = tab,
+            InstanceRequest::Run(InstanceCommand::CleanRam) => {
+                self.queue_action(crate::app::commands::ActionCommand::CleanRam)
+            }
+            InstanceRequest::Run(InstanceCommand::ProcessManager) => self.show_process_manager = true,
+            _ => {}
+        }
+    }
 }
 
 impl Drop for SystemMonitorApp {
@@ -2174,11 +2515,17 @@ pub(crate) fn snapshot_from_data(data: &SystemData) -> crate::monitoring::System
         memory_total: data.memory_total,
         memory_used: data.memory_used,
         memory_percentage: data.memory_percentage,
+        memory_available: data.memory_available,
+        memory_breakdown: data.memory_breakdown,
         swap: crate::monitoring::snapshot::SwapSnapshot {
             total: data.swap_info.total,
             used: data.swap_info.used,
             percentage: data.swap_info.percentage,
         },
+        pressure: data.pressure,
+        load: data.load,
+        throttle: data.throttle,
+        block_devices: data.block_devices.clone(),
         gpus: data
             .gpu_info
             .iter()
@@ -2205,6 +2552,10 @@ pub(crate) fn snapshot_from_data(data: &SystemData) -> crate::monitoring::System
                 file_system: disk.file_system.clone(),
                 read_bytes_per_second: data.disk_read_rate,
                 written_bytes_per_second: data.disk_write_rate,
+                io: disk
+                    .block_device
+                    .as_deref()
+                    .and_then(|name| data.block_devices.iter().find(|device| device.name == name).cloned()),
             })
             .collect(),
         networks: data
@@ -2216,6 +2567,16 @@ pub(crate) fn snapshot_from_data(data: &SystemData) -> crate::monitoring::System
                 transmitted: network.transmitted,
                 received_bytes_per_second: network.received_rate,
                 transmitted_bytes_per_second: network.transmitted_rate,
+                kind: network.details.kind.label().to_string(),
+                operstate: network.details.operstate.clone(),
+                speed_mbps: network.details.speed_mbps,
+                mtu: network.details.mtu,
+                rx_packets: network.details.counters.rx_packets,
+                tx_packets: network.details.counters.tx_packets,
+                errors: network.details.counters.errors(),
+                dropped: network.details.counters.dropped(),
+                errors_per_second: network.error_rate,
+                drops_per_second: network.drop_rate,
             })
             .collect(),
         processes: (if data.timeline_processes.is_empty() {
@@ -2233,6 +2594,8 @@ pub(crate) fn snapshot_from_data(data: &SystemData) -> crate::monitoring::System
             status: process.status.clone(),
             disk_read_bytes: process.disk_read_bytes,
             disk_written_bytes: process.disk_written_bytes,
+            gpu_usage: process.gpu_usage,
+            gpu_memory: process.gpu_memory,
         })
         .collect(),
         battery: data
@@ -2257,6 +2620,7 @@ pub(crate) fn snapshot_from_data(data: &SystemData) -> crate::monitoring::System
             bios_version: data.system_info.bios_version.clone(),
             gpu_driver: data.system_info.gpu_driver.clone(),
             os_build: data.system_info.os_build.clone(),
+            hypervisor: data.system_info.hypervisor.clone(),
         },
         provider_status,
         paused: data.monitoring_paused,
@@ -2317,6 +2681,7 @@ mod alert_tests {
                 available_space: 20,
                 usage_percentage: 80.0,
                 file_system: "NTFS".into(),
+                block_device: None,
             }],
             ..Default::default()
         };
@@ -2331,4 +2696,36 @@ mod alert_tests {
         );
         assert_eq!(alerts[0].key(), "disk:D:\\");
     }
+
+    #[test]
+    fn memory_alert_can_follow_available_memory() {
+        let gib = 1 << 30;
+        // 92% used, but most of it is page cache: 4 GiB of 16 GiB is still available.
+        let data = SystemData {
+            memory_total: 16 * gib,
+            memory_used: 14 * gib + gib / 2,
+            memory_percentage: 92.0,
+            memory_available: 4 * gib,
+            ..Default::default()
+        };
+        let used = AppSettings::default();
+        assert_eq!(SystemMonitor::check_alerts(&used, &data).len(), 1);
+
+        let available = AppSettings {
+            memory_alert_basis: MemoryAlertBasis::Available,
+            notification_memory_available_threshold: 10.0,
+            ..Default::default()
+        };
+        assert!(SystemMonitor::check_alerts(&available, &data).is_empty());
+
+        let starved = SystemData {
+            memory_available: gib,
+            ..data
+        };
+        let alerts = SystemMonitor::check_alerts(&available, &starved);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].source, AlertSource::Memory);
+        assert_eq!(alerts[0].value, 6.25);
+        assert!(alerts[0].message.contains("(1.0 GB) left"));
+    }
 }
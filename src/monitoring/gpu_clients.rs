@@ -0,0 +1,340 @@
+//! Per-process GPU usage from DRM client `fdinfo` on Linux.
+//!
+//! Every open DRM file description is a GPU client, and the kernel publishes
+//! its accounting in `/proc/<pid>/fdinfo/<fd>` (see the kernel's
+//! `drm-usage-stats` document): `drm-engine-<engine>` busy time in
+//! nanoseconds (amdgpu, i915, msm, panfrost, v3d), or `drm-cycles-<engine>`
+//! against `drm-total-cycles-<engine>` (xe), plus per-region memory. Counters
+//! are cumulative, so utilization needs two samples of the same client.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Instant;
+
+const PROC_ROOT: &str = "/proc";
+
+/// Busy counter for one engine class of one DRM client.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct EngineCounter {
+    /// Busy nanoseconds, or busy cycles when `total_cycles` is set.
+    pub busy: u64,
+    pub total_cycles: Option<u64>,
+    /// Number of engines of this class the counter is spread over.
+    pub capacity: u64,
+}
+
+/// One DRM client as described by a single `fdinfo` file.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct DrmClient {
+    pub pdev: String,
+    pub client_id: u64,
+    pub engines: HashMap<String, EngineCounter>,
+    /// Resident bytes in device-local regions, or in every region for GPUs
+    /// that have none (integrated GPUs carve their memory out of system RAM).
+    pub memory: u64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct ProcessGpuUsage {
+    /// Busiest engine as a percentage of its capacity.
+    pub usage: f32,
+    pub memory: u64,
+}
+
+/// Parse one `fdinfo` file. Returns `None` for anything that is not a DRM
+/// client (no `drm-client-id`), which covers every non-GPU descriptor.
+pub(crate) fn parse_fdinfo(contents: &str) -> Option<DrmClient> {
+    let mut client_id = None;
+    let mut pdev = String::new();
+    let mut ns = HashMap::new();
+    let mut cycles = HashMap::new();
+    let mut total_cycles = HashMap::new();
+    let mut capacity = HashMap::new();
+    let mut resident = HashMap::new();
+    let mut legacy = HashMap::new();
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let number = || first_number(value);
+        if key == "drm-client-id" {
+            client_id = value.parse::<u64>().ok();
+        } else if key == "drm-pdev" {
+            pdev = value.to_string();
+        } else if let Some(engine) = key.strip_prefix("drm-engine-capacity-") {
+            capacity.extend(number().map(|n| (engine.to_string(), n)));
+        } else if let Some(engine) = key.strip_prefix("drm-engine-") {
+            ns.extend(number().map(|n| (engine.to_string(), n)));
+        } else if let Some(engine) = key.strip_prefix("drm-total-cycles-") {
+            total_cycles.extend(number().map(|n| (engine.to_string(), n)));
+        } else if let Some(engine) = key.strip_prefix("drm-cycles-") {
+            cycles.extend(number().map(|n| (engine.to_string(), n)));
+        } else if let Some(region) = key.strip_prefix("drm-resident-") {
+            resident.extend(parse_memory(value).map(|bytes| (region.to_string(), bytes)));
+        } else if let Some(region) = key.strip_prefix("drm-memory-") {
+            legacy.extend(parse_memory(value).map(|bytes| (region.to_string(), bytes)));
+        }
+    }
+
+    let client_id = client_id?;
+    let capacity_of = |engine: &str| capacity.get(engine).copied().unwrap_or(1).max(1);
+    let mut engines: HashMap<String, EngineCounter> = ns
+        .into_iter()
+        .map(|(engine, busy)| {
+            let counter = EngineCounter {
+                busy,
+                total_cycles: None,
+                capacity: capacity_of(&engine),
+            };
+            (engine, counter)
+        })
+        .collect();
+    for (engine, busy) in cycles {
+        if let Some(&total) = total_cycles.get(&engine) {
+            let counter = EngineCounter {
+                busy,
+                total_cycles: Some(total),
+                capacity: capacity_of(&engine),
+            };
+            engines.insert(engine, counter);
+        }
+    }
+
+    // `drm-memory-*` is the pre-6.4 spelling of `drm-resident-*`.
+    let regions = if resident.is_empty() { legacy } else { resident };
+    let is_local = |region: &String| region.starts_with("vram") || region.starts_with("local");
+    let memory = if regions.keys().any(is_local) {
+        regions
+            .iter()
+            .filter(|(region, _)| is_local(region))
+            .map(|(_, bytes)| bytes)
+            .sum()
+    } else {
+        regions.values().sum()
+    };
+
+    Some(DrmClient {
+        pdev,
+        client_id,
+        engines,
+        memory,
+    })
+}
+
+/// Busy fraction (0.0..=1.0) of each engine between two samples of a client.
+/// Nanosecond counters are measured against wall time; cycle counters carry
+/// their own reference clock.
+pub(crate) fn engine_utilization<'a>(
+    previous: &DrmClient,
+    current: &'a DrmClient,
+    elapsed_ns: u64,
+) -> HashMap<&'a str, f64> {
+    current
+        .engines
+        .iter()
+        .filter_map(|(engine, now)| {
+            let before = previous.engines.get(engine)?;
+            let busy = now.busy.checked_sub(before.busy)? as f64;
+            let reference = match (before.total_cycles, now.total_cycles) {
+                (Some(before_total), Some(now_total)) => now_total.checked_sub(before_total)?,
+                (None, None) => elapsed_ns,
+                _ => return None,
+            };
+            if reference == 0 {
+                return None;
+            }
+            let fraction = busy / reference as f64 / now.capacity as f64;
+            Some((engine.as_str(), fraction.clamp(0.0, 1.0)))
+        })
+        .collect()
+}
+
+/// Keeps the previous counters of every client so each refresh can turn
+/// cumulative busy time into a rate.
+#[derive(Default)]
+pub(crate) struct GpuClientSampler {
+    previous: HashMap<(u32, String, u64), DrmClient>,
+    sampled_at: Option<Instant>,
+    latest: HashMap<u32, ProcessGpuUsage>,
+}
+
+impl GpuClientSampler {
+    pub(crate) fn refresh(&mut self) {
+        self.refresh_from(Path::new(PROC_ROOT), Instant::now());
+    }
+
+    pub(crate) fn usage(&self, pid: u32) -> ProcessGpuUsage {
+        self.latest.get(&pid).copied().unwrap_or_default()
+    }
+
+    fn refresh_from(&mut self, proc_root: &Path, now: Instant) {
+        let elapsed_ns = self
+            .sampled_at
+            .map(|then| now.saturating_duration_since(then).as_nanos().min(u64::MAX as u128) as u64)
+            .unwrap_or(0);
+        let mut current = HashMap::new();
+        let mut latest = HashMap::new();
+
+        let Ok(entries) = std::fs::read_dir(proc_root) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            let clients = clients_of(&entry.path());
+            if clients.is_empty() {
+                continue;
+            }
+
+            // Busy fraction per (device, engine), summed over the process's clients.
+            let mut busy: HashMap<(&str, &str), f64> = HashMap::new();
+            let mut memory = 0u64;
+            for client in clients.values() {
+                memory = memory.saturating_add(client.memory);
+                let key = (pid, client.pdev.clone(), client.client_id);
+                if let Some(previous) = self.previous.get(&key) {
+                    for (engine, fraction) in engine_utilization(previous, client, elapsed_ns) {
+                        *busy.entry((client.pdev.as_str(), engine)).or_default() += fraction;
+                    }
+                }
+            }
+            let usage = busy.values().copied().fold(0.0, f64::max).min(1.0) * 100.0;
+            latest.insert(
+                pid,
+                ProcessGpuUsage {
+                    usage: usage as f32,
+                    memory,
+                },
+            );
+            for ((pdev, client_id), client) in clients {
+                current.insert((pid, pdev, client_id), client);
+            }
+        }
+
+        self.previous = current;
+        self.latest = latest;
+        self.sampled_at = Some(now);
+    }
+}
+
+/// DRM clients held open by one process, keyed by device and client id so
+/// duplicated descriptors of the same client are only counted once.
+fn clients_of(process_dir: &Path) -> HashMap<(String, u64), DrmClient> {
+    let mut clients = HashMap::new();
+    let Ok(fds) = std::fs::read_dir(process_dir.join("fd")) else {
+        return clients;
+    };
+    for fd in fds.flatten() {
+        // Only render and card nodes can carry DRM accounting; checking the
+        // link target first avoids reading fdinfo for every socket and file.
+        let is_drm = std::fs::read_link(fd.path()).is_ok_and(|target| target.starts_with("/dev/dri"));
+        if !is_drm {
+            continue;
+        }
+        let Ok(contents) = std::fs::read_to_string(process_dir.join("fdinfo").join(fd.file_name())) else {
+            continue;
+        };
+        if let Some(client) = parse_fdinfo(&contents) {
+            clients.insert((client.pdev.clone(), client.client_id), client);
+        }
+    }
+    clients
+}
+
+fn first_number(value: &str) -> Option<u64> {
+    value.split_whitespace().next()?.parse().ok()
+}
+
+fn parse_memory(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let amount: u64 = parts.next()?.parse().ok()?;
+    let scale = match parts.next() {
+        None => 1,
+        Some("KiB") => 1024,
+        Some("MiB") => 1024 * 1024,
+        Some("GiB") => 1024 * 1024 * 1024,
+        Some(_) => return None,
+    };
+    Some(amount.saturating_mul(scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+    use std::time::Duration;
+
+    const AMDGPU: &str = "pos:\t0\nflags:\t02100002\nmnt_id:\t24\ndrm-driver:\tamdgpu\ndrm-client-id:\t17\n\
+        drm-pdev:\t0000:03:00.0\npasid:\t32781\ndrm-memory-vram:\t15468 KiB\ndrm-memory-gtt: \t2048 KiB\n\
+        drm-memory-cpu: \t0 KiB\ndrm-engine-gfx:\t1000000000 ns\ndrm-engine-compute:\t0 ns\n";
+    const XE: &str = "drm-driver:\txe\ndrm-client-id:\t4\ndrm-pdev:\t0000:03:00.0\n\
+        drm-total-vram0:\t24 MiB\ndrm-resident-vram0:\t20 MiB\ndrm-total-system:\t8 MiB\n\
+        drm-resident-system:\t8 MiB\ndrm-cycles-rcs:\t1000\ndrm-total-cycles-rcs:\t10000\n\
+        drm-cycles-vcs:\t0\ndrm-total-cycles-vcs:\t10000\ndrm-engine-capacity-vcs:\t2\n";
+
+    fn add_fd(root: &TempTree, pid: u32, fd: u32, target: &str, fdinfo: &str) {
+        root.symlink(format!("{pid}/fd/{fd}"), target);
+        root.write(format!("{pid}/fdinfo/{fd}"), fdinfo);
+    }
+
+    #[test]
+    fn parses_engine_time_and_device_local_memory() {
+        let client = parse_fdinfo(AMDGPU).unwrap();
+        assert_eq!(client.client_id, 17);
+        assert_eq!(client.pdev, "0000:03:00.0");
+        assert_eq!(client.engines["gfx"].busy, 1_000_000_000);
+        assert_eq!(client.engines["gfx"].capacity, 1);
+        assert_eq!(client.memory, 15468 * 1024);
+        assert_eq!(parse_fdinfo("pos:\t0\nflags:\t02\n"), None);
+
+        let igpu = parse_fdinfo("drm-client-id: 3\ndrm-resident-system0: 512 KiB\ndrm-engine-render: 5 ns\n").unwrap();
+        assert_eq!(igpu.memory, 512 * 1024);
+    }
+
+    #[test]
+    fn utilization_uses_wall_time_or_cycle_reference_and_capacity() {
+        let before = parse_fdinfo(AMDGPU).unwrap();
+        let after = parse_fdinfo(&AMDGPU.replace("1000000000 ns", "1500000000 ns")).unwrap();
+        let busy = engine_utilization(&before, &after, 1_000_000_000);
+        assert!((busy["gfx"] - 0.5).abs() < 1e-9);
+        assert_eq!(busy["compute"], 0.0);
+
+        let before = parse_fdinfo(XE).unwrap();
+        let after = parse_fdinfo(
+            &XE.replace("drm-cycles-rcs:\t1000", "drm-cycles-rcs:\t3500")
+                .replace("drm-total-cycles-rcs:\t10000", "drm-total-cycles-rcs:\t20000")
+                .replace("drm-cycles-vcs:\t0", "drm-cycles-vcs:\t10000")
+                .replace("drm-total-cycles-vcs:\t10000", "drm-total-cycles-vcs:\t20000"),
+        )
+        .unwrap();
+        assert_eq!(after.memory, 20 * 1024 * 1024);
+        let busy = engine_utilization(&before, &after, 0);
+        assert!((busy["rcs"] - 0.25).abs() < 1e-9);
+        assert!((busy["vcs"] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn sampler_dedupes_shared_clients_and_skips_non_drm_descriptors() {
+        let root = TempTree::new("gpu-clients");
+        add_fd(&root, 100, 3, "/dev/dri/renderD128", AMDGPU);
+        add_fd(&root, 100, 4, "/dev/dri/renderD128", AMDGPU);
+        add_fd(&root, 200, 3, "/tmp/not-a-gpu", AMDGPU);
+
+        let mut sampler = GpuClientSampler::default();
+        let start = Instant::now();
+        sampler.refresh_from(&root, start);
+        assert_eq!(sampler.usage(100).usage, 0.0);
+        assert_eq!(sampler.usage(100).memory, 15468 * 1024);
+        assert_eq!(sampler.usage(200), ProcessGpuUsage::default());
+
+        let busier = AMDGPU.replace("1000000000 ns", "1250000000 ns");
+        for fd in ["3", "4"] {
+            root.write(format!("100/fdinfo/{fd}"), &busier);
+        }
+        sampler.refresh_from(&root, start + Duration::from_secs(1));
+        assert!((sampler.usage(100).usage - 25.0).abs() < 0.01);
+    }
+}
@@ -1,6 +1,13 @@
+#[cfg(target_os = "linux")]
+pub mod cgroups;
+#[cfg(target_os = "linux")]
+pub mod gpu_clients;
 pub mod history;
 pub mod rates;
+#[cfg(target_os = "linux")]
+pub mod reclaim;
 pub mod snapshot;
+pub mod throttle;
 
 pub use snapshot::SystemSnapshot;
 pub mod engine;
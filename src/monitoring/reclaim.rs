@@ -0,0 +1,305 @@
+//! Linux memory reclaim backend for the RAM cleaner.
+//!
+//! Linux has no working-set trim. The closest equivalents are asking the kernel
+//! to page a process out with `process_madvise(MADV_PAGEOUT)` (5.10+, needs
+//! `CAP_SYS_NICE` for other processes) and asking a cgroup v2 group to shrink
+//! through `memory.reclaim` (5.19+, writable by the owner of a delegated group,
+//! e.g. everything under `user@.service`). Freed bytes are always measured:
+//! resident set size per process, or `memory.current` per cgroup.
+
+use crate::app::worker::ActionError;
+use crate::providers::sysfs::{parse_meminfo, read_trimmed, read_u64};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use tracing::debug;
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// Ranges per `process_madvise` call; the kernel rejects more than UIO_MAXIOV (1024).
+const IOV_BATCH: usize = 512;
+
+/// A process the cleaner may touch, already filtered for exclusions and smart mode.
+pub(crate) struct ReclaimTarget {
+    pub pid: u32,
+    pub resident: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ReclaimOutcome {
+    pub freed: u64,
+    pub reclaimed: u32,
+    pub denied: u32,
+    pub failed: u32,
+    pub cgroups: u32,
+}
+
+/// Page out `targets` (largest first) until `budget` bytes have been freed.
+/// Processes the kernel will not let us advise fall back to `memory.reclaim`
+/// on their cgroup, provided every member of that cgroup is itself a target.
+pub(crate) fn reclaim(targets: &[ReclaimTarget], budget: u64) -> ReclaimOutcome {
+    let mut outcome = ReclaimOutcome::default();
+    let mut fallback = Vec::new();
+    for target in targets {
+        if outcome.freed >= budget {
+            return outcome;
+        }
+        let Some(before) = resident_bytes(target.pid) else {
+            continue;
+        };
+        match page_out(target.pid) {
+            Ok(()) => {
+                outcome.reclaimed += 1;
+                let after = resident_bytes(target.pid).unwrap_or(before);
+                outcome.freed += before.saturating_sub(after);
+            }
+            Err(error) if matches!(error.raw_os_error(), Some(libc::EPERM | libc::ENOSYS)) => {
+                outcome.denied += 1;
+                fallback.push(target);
+            }
+            Err(error) if error.raw_os_error() == Some(libc::ESRCH) => {}
+            Err(error) => {
+                debug!(pid = target.pid, %error, "process_madvise failed");
+                outcome.failed += 1;
+            }
+        }
+    }
+    if !fallback.is_empty() && outcome.freed < budget {
+        let eligible: HashSet<u32> = targets.iter().map(|target| target.pid).collect();
+        reclaim_cgroups(&fallback, &eligible, budget, &mut outcome);
+    }
+    outcome
+}
+
+fn reclaim_cgroups(targets: &[&ReclaimTarget], eligible: &HashSet<u32>, budget: u64, outcome: &mut ReclaimOutcome) {
+    let mut groups: BTreeMap<String, u64> = BTreeMap::new();
+    for target in targets {
+        let Some(path) = std::fs::read_to_string(format!("/proc/{}/cgroup", target.pid))
+            .ok()
+            .and_then(|contents| parse_cgroup_v2_path(&contents))
+        else {
+            continue;
+        };
+        *groups.entry(path).or_default() += target.resident;
+    }
+
+    for (path, resident) in groups {
+        if outcome.freed >= budget {
+            break;
+        }
+        let dir = Path::new(CGROUP_ROOT).join(path.trim_start_matches('/'));
+        // Reclaim is group-wide, so a group holding an excluded (or foreground)
+        // process is left alone entirely.
+        let all_eligible = read_trimmed(&dir.join("cgroup.procs")).is_some_and(|procs| {
+            procs
+                .lines()
+                .filter_map(|pid| pid.parse().ok())
+                .all(|pid| eligible.contains(&pid))
+        });
+        if !all_eligible {
+            continue;
+        }
+        let Some(before) = read_u64(&dir.join("memory.current")) else {
+            continue;
+        };
+        let request = resident.min(budget - outcome.freed);
+        // EAGAIN only means the kernel reclaimed less than requested.
+        match std::fs::write(dir.join("memory.reclaim"), request.to_string()) {
+            Err(error) if error.raw_os_error() != Some(libc::EAGAIN) => {
+                debug!(cgroup = %dir.display(), %error, "memory.reclaim failed");
+                continue;
+            }
+            _ => {}
+        }
+        let after = read_u64(&dir.join("memory.current")).unwrap_or(before);
+        outcome.freed += before.saturating_sub(after);
+        outcome.cgroups += 1;
+    }
+}
+
+/// Flush dirty pages and drop the clean page cache (`vm.drop_caches = 1`).
+/// Requires root; returns the measured growth of MemFree.
+pub(crate) fn drop_page_cache() -> Result<u64, ActionError> {
+    let mem_free = || {
+        std::fs::read_to_string("/proc/meminfo")
+            .ok()
+            .and_then(|contents| parse_meminfo(&contents).get("MemFree").copied())
+    };
+    let before = mem_free().ok_or(ActionError::Unavailable)?;
+    // SAFETY: sync has no arguments and cannot fail.
+    unsafe { libc::sync() };
+    std::fs::write("/proc/sys/vm/drop_caches", "1")?;
+    let after = mem_free().unwrap_or(before);
+    Ok(after.saturating_sub(before))
+}
+
+fn page_size() -> u64 {
+    // SAFETY: sysconf only reads a configuration value.
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    u64::try_from(size).unwrap_or(4096)
+}
+
+fn resident_bytes(pid: u32) -> Option<u64> {
+    let statm = std::fs::read_to_string(format!("/proc/{pid}/statm")).ok()?;
+    Some(parse_statm_resident(&statm)? * page_size())
+}
+
+/// Advise every reclaimable mapping of `pid` with MADV_PAGEOUT.
+fn page_out(pid: u32) -> std::io::Result<()> {
+    let maps = std::fs::read_to_string(format!("/proc/{pid}/maps"))?;
+    let ranges = parse_reclaimable_ranges(&maps);
+
+    // SAFETY: pidfd_open takes a pid and flags and returns a new descriptor or -1.
+    let pidfd = unsafe { libc::syscall(libc::SYS_pidfd_open, pid as libc::pid_t, 0) };
+    if pidfd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    let pidfd = pidfd as libc::c_int;
+
+    let advise = |ranges: &[(usize, usize)]| {
+        let iov: Vec<libc::iovec> = ranges
+            .iter()
+            .map(|(start, end)| libc::iovec {
+                iov_base: *start as *mut libc::c_void,
+                iov_len: end - start,
+            })
+            .collect();
+        // SAFETY: the iovecs describe addresses in the target process and are
+        // only read by the kernel; `iov` outlives the call.
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_process_madvise,
+                pidfd,
+                iov.as_ptr(),
+                iov.len(),
+                libc::MADV_PAGEOUT,
+                0u32,
+            )
+        };
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut result = Ok(());
+    for batch in ranges.chunks(IOV_BATCH) {
+        result = match advise(batch) {
+            // One locked or huge-page mapping fails the whole batch; retry the
+            // ranges one by one and ignore the ones the kernel refuses.
+            Err(error) if error.raw_os_error() == Some(libc::EINVAL) => {
+                batch.iter().for_each(|range| {
+                    let _ = advise(std::slice::from_ref(range));
+                });
+                Ok(())
+            }
+            other => other,
+        };
+        if result.is_err() {
+            break;
+        }
+    }
+    // SAFETY: pidfd was returned by pidfd_open above and is closed exactly once.
+    unsafe { libc::close(pidfd) };
+    result
+}
+
+/// Address ranges from `/proc/<pid>/maps` worth paging out: readable mappings
+/// other than kernel-provided special regions and huge pages.
+pub(crate) fn parse_reclaimable_ranges(maps: &str) -> Vec<(usize, usize)> {
+    maps.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (start, end) = fields.next()?.split_once('-')?;
+            let perms = fields.next()?;
+            let path = fields.nth(3).unwrap_or("");
+            if !perms.starts_with('r') || path.contains("anon_hugepage") || path.starts_with("/dev/hugepages") {
+                return None;
+            }
+            if path.starts_with('[') && path != "[heap]" && !path.starts_with("[stack") && !path.starts_with("[anon:") {
+                return None;
+            }
+            let start = usize::from_str_radix(start, 16).ok()?;
+            let end = usize::from_str_radix(end, 16).ok()?;
+            (end > start).then_some((start, end))
+        })
+        .collect()
+}
+
+/// Resident pages (second field) from `/proc/<pid>/statm`.
+pub(crate) fn parse_statm_resident(statm: &str) -> Option<u64> {
+    statm.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// The unified-hierarchy path (`0::/...`) from `/proc/<pid>/cgroup`.
+pub(crate) fn parse_cgroup_v2_path(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .find_map(|line| line.strip_prefix("0::"))
+        .filter(|path| *path != "/")
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_skip_special_and_unreadable_regions() {
+        let maps = "\
+55d0c0a00000-55d0c0a21000 r--p 00000000 103:02 1835219                   /usr/bin/cat
+55d0c0c4f000-55d0c0c70000 rw-p 00000000 00:00 0                          [heap]
+7f1e2c000000-7f1e2c021000 rw-p 00000000 00:00 0
+7f1e2c021000-7f1e30000000 ---p 00000000 00:00 0
+7f1e30000000-7f1e30200000 rw-s 00000000 00:0f 4096                       /anon_hugepage (deleted)
+7ffd4a1c0000-7ffd4a1e1000 rw-p 00000000 00:00 0                          [stack]
+7ffd4a1f8000-7ffd4a1fc000 r--p 00000000 00:00 0                          [vvar]
+7ffd4a1fc000-7ffd4a1fe000 r-xp 00000000 00:00 0                          [vdso]
+ffffffffff600000-ffffffffff601000 --xp 00000000 00:00 0                  [vsyscall]
+";
+        assert_eq!(
+            parse_reclaimable_ranges(maps),
+            vec![
+                (0x55d0c0a00000, 0x55d0c0a21000),
+                (0x55d0c0c4f000, 0x55d0c0c70000),
+                (0x7f1e2c000000, 0x7f1e2c021000),
+                (0x7ffd4a1c0000, 0x7ffd4a1e1000),
+            ]
+        );
+    }
+
+    #[test]
+    fn statm_and_meminfo_fields_parse() {
+        assert_eq!(parse_statm_resident("6210 1422 1101 5 0 187 0\n"), Some(1422));
+        assert_eq!(parse_statm_resident(""), None);
+
+        let meminfo = "MemTotal:       32594308 kB\nMemFree:         1048576 kB\nMemAvailable:   20971520 kB\n";
+        let fields = parse_meminfo(meminfo);
+        assert_eq!(fields.get("MemFree"), Some(&(1024 * 1024 * 1024)));
+        assert_eq!(fields.get("Mem"), None);
+        assert_eq!(fields.get("SwapFree"), None);
+    }
+
+    #[test]
+    fn cgroup_path_comes_from_unified_hierarchy() {
+        let hybrid =
+            "12:memory:/user.slice\n0::/user.slice/user-1000.slice/user@1000.service/app.slice/firefox.scope\n";
+        assert_eq!(
+            parse_cgroup_v2_path(hybrid).as_deref(),
+            Some("/user.slice/user-1000.slice/user@1000.service/app.slice/firefox.scope")
+        );
+        assert_eq!(parse_cgroup_v2_path("0::/\n"), None);
+        assert_eq!(parse_cgroup_v2_path("4:memory:/docker/abc\n"), None);
+    }
+
+    #[test]
+    fn reclaim_respects_an_exhausted_budget() {
+        let targets = [ReclaimTarget {
+            pid: std::process::id(),
+            resident: 1 << 20,
+        }];
+        let outcome = reclaim(&targets, 0);
+        assert_eq!(outcome.freed, 0);
+        assert_eq!(outcome.reclaimed + outcome.denied + outcome.failed, 0);
+    }
+}
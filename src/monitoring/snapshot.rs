@@ -32,6 +32,56 @@ pub struct DiskSnapshot {
     pub file_system: String,
     pub read_bytes_per_second: f64,
     pub written_bytes_per_second: f64,
+    /// Statistics of the block device backing this mount, when it can be resolved.
+    #[serde(default)]
+    pub io: Option<BlockDeviceSnapshot>,
+}
+
+/// Per-device block I/O over the last poll interval, from `/proc/diskstats`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockDeviceSnapshot {
+    pub name: String,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub read_bytes_per_second: f64,
+    pub written_bytes_per_second: f64,
+    /// Average milliseconds a request spent queued and in service.
+    pub await_ms: f64,
+    /// Average number of requests in flight.
+    pub queue_depth: f64,
+    /// Percent of wall time with at least one request in flight.
+    pub util_percent: f64,
+}
+
+impl BlockDeviceSnapshot {
+    /// Build one entry per device from `disk.<device>.*` telemetry keys,
+    /// sorted by name. Devices without rates yet are left out.
+    pub fn from_metrics(metrics: &HashMap<String, f64>) -> Vec<Self> {
+        let mut devices: Vec<Self> = metrics
+            .keys()
+            .filter_map(|key| key.strip_prefix("disk.")?.strip_suffix(".util_percent"))
+            .map(|name| {
+                let value = |field: &str| {
+                    metrics
+                        .get(&format!("disk.{name}.{field}"))
+                        .copied()
+                        .unwrap_or_default()
+                };
+                Self {
+                    name: name.to_string(),
+                    read_iops: value("read_iops"),
+                    write_iops: value("write_iops"),
+                    read_bytes_per_second: value("read_bytes_per_sec"),
+                    written_bytes_per_second: value("write_bytes_per_sec"),
+                    await_ms: value("await_ms"),
+                    queue_depth: value("queue_depth"),
+                    util_percent: value("util_percent"),
+                }
+            })
+            .collect();
+        devices.sort_by(|a, b| a.name.cmp(&b.name));
+        devices
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -41,6 +91,28 @@ pub struct NetworkSnapshot {
     pub transmitted: u64,
     pub received_bytes_per_second: f64,
     pub transmitted_bytes_per_second: f64,
+    #[serde(default)]
+    pub kind: String,
+    #[serde(default)]
+    pub operstate: Option<String>,
+    #[serde(default)]
+    pub speed_mbps: Option<u32>,
+    #[serde(default)]
+    pub mtu: Option<u32>,
+    #[serde(default)]
+    pub rx_packets: u64,
+    #[serde(default)]
+    pub tx_packets: u64,
+    /// Cumulative receive plus transmit errors.
+    #[serde(default)]
+    pub errors: u64,
+    /// Cumulative receive plus transmit drops.
+    #[serde(default)]
+    pub dropped: u64,
+    #[serde(default)]
+    pub errors_per_second: f64,
+    #[serde(default)]
+    pub drops_per_second: f64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -53,6 +125,10 @@ pub struct ProcessSnapshot {
     pub status: String,
     pub disk_read_bytes: u64,
     pub disk_written_bytes: u64,
+    #[serde(default)]
+    pub gpu_usage: f32,
+    #[serde(default)]
+    pub gpu_memory: u64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -62,6 +138,192 @@ pub struct SwapSnapshot {
     pub percentage: f32,
 }
 
+/// Linux pressure stall information as 10-second averages: the percent of
+/// wall time in which some (or, for `full`, all) runnable tasks waited on
+/// the resource.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PressureSnapshot {
+    pub cpu_some: f64,
+    pub memory_some: f64,
+    pub memory_full: f64,
+    pub io_some: f64,
+    pub io_full: f64,
+}
+
+impl PressureSnapshot {
+    /// Build from `pressure.*` telemetry keys; `None` when PSI is unavailable.
+    pub fn from_metrics(metrics: &HashMap<String, f64>) -> Option<Self> {
+        let value = |key: &str| metrics.get(&format!("pressure.{key}.avg10")).copied();
+        let cpu_some = value("cpu.some");
+        let memory_some = value("memory.some");
+        let io_some = value("io.some");
+        if cpu_some.is_none() && memory_some.is_none() && io_some.is_none() {
+            return None;
+        }
+        Some(Self {
+            cpu_some: cpu_some.unwrap_or_default(),
+            memory_some: memory_some.unwrap_or_default(),
+            memory_full: value("memory.full").unwrap_or_default(),
+            io_some: io_some.unwrap_or_default(),
+            io_full: value("io.full").unwrap_or_default(),
+        })
+    }
+}
+
+/// Linux run-queue length and scheduler churn: load averages, task counts and
+/// per-second rates of context switches, interrupts, softirqs and forks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LoadSnapshot {
+    pub load_1m: f64,
+    pub load_5m: f64,
+    pub load_15m: f64,
+    pub procs_running: u32,
+    /// Tasks in uninterruptible sleep, usually waiting on disk or network I/O.
+    pub procs_blocked: u32,
+    pub context_switches_per_sec: f64,
+    pub interrupts_per_sec: f64,
+    pub softirqs_per_sec: f64,
+    pub forks_per_sec: f64,
+}
+
+impl LoadSnapshot {
+    /// Build from `load.*` telemetry keys; `None` when `/proc/loadavg` is unavailable.
+    pub fn from_metrics(metrics: &HashMap<String, f64>) -> Option<Self> {
+        let load_1m = *metrics.get("load.avg_1m")?;
+        let value = |key: &str| metrics.get(&format!("load.{key}")).copied().unwrap_or_default();
+        Some(Self {
+            load_1m,
+            load_5m: value("avg_5m"),
+            load_15m: value("avg_15m"),
+            procs_running: value("procs_running") as u32,
+            procs_blocked: value("procs_blocked") as u32,
+            context_switches_per_sec: value("context_switches_per_sec"),
+            interrupts_per_sec: value("interrupts_per_sec"),
+            softirqs_per_sec: value("softirqs_per_sec"),
+            forks_per_sec: value("forks_per_sec"),
+        })
+    }
+}
+
+/// CPU clock and thermal state as judged by `monitoring::throttle::ThrottleDetector`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThrottleSnapshot {
+    /// Mean current clock across logical processors.
+    pub average_mhz: f64,
+    /// Mean rated maximum clock, when the platform reports one.
+    pub max_mhz: Option<f64>,
+    pub temperature: Option<f32>,
+    /// Kernel thermal throttle events (core plus package) per second; Linux on Intel only.
+    pub events_per_sec: f64,
+    /// Samples in the detection window that looked throttled.
+    pub throttled_samples: u32,
+    pub window_samples: u32,
+    pub sustained: bool,
+}
+
+impl ThrottleSnapshot {
+    /// Current clock as a fraction of the rated maximum.
+    pub fn clock_ratio(&self) -> Option<f64> {
+        self.max_mhz.filter(|max| *max > 0.0).map(|max| self.average_mhz / max)
+    }
+
+    /// One-line description of the signals behind the verdict.
+    pub fn evidence(&self) -> String {
+        let mut parts = vec![match (self.max_mhz, self.clock_ratio()) {
+            (Some(max), Some(ratio)) => format!(
+                "cores average {:.0} MHz, {:.0}% of the {max:.0} MHz maximum",
+                self.average_mhz,
+                ratio * 100.0
+            ),
+            _ => format!("cores average {:.0} MHz", self.average_mhz),
+        }];
+        if let Some(temperature) = self.temperature {
+            parts.push(format!("package at {temperature:.0} C"));
+        }
+        if self.events_per_sec > 0.0 {
+            parts.push(format!("{:.1} kernel throttle events/s", self.events_per_sec));
+        }
+        format!(
+            "{}; {} of the last {} samples were throttled.",
+            parts.join(", "),
+            self.throttled_samples,
+            self.window_samples
+        )
+    }
+}
+
+/// Linux memory composition from `/proc/meminfo` (bytes) and paging activity
+/// from `/proc/vmstat` (pages or faults per second).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryBreakdownSnapshot {
+    pub free: u64,
+    /// Page cache, including `shared`.
+    pub cached: u64,
+    pub buffers: u64,
+    /// tmpfs and shared memory segments.
+    pub shared: u64,
+    pub slab_reclaimable: u64,
+    pub slab_unreclaimable: u64,
+    pub dirty: u64,
+    pub writeback: u64,
+    pub hugepages_total: u64,
+    pub hugepages_free: u64,
+    /// Commit charge: memory promised to processes, whether or not touched yet.
+    pub committed: u64,
+    pub commit_limit: u64,
+    pub swap_in_per_sec: f64,
+    pub swap_out_per_sec: f64,
+    pub major_faults_per_sec: f64,
+}
+
+impl MemoryBreakdownSnapshot {
+    /// Build from the `memory.*` keys of the meminfo provider; `None` off Linux.
+    pub fn from_metrics(metrics: &HashMap<String, f64>) -> Option<Self> {
+        metrics.get("memory.cached")?;
+        let bytes = |key: &str| metrics.get(&format!("memory.{key}")).map_or(0, |value| *value as u64);
+        let rate = |key: &str| metrics.get(&format!("memory.{key}")).copied().unwrap_or_default();
+        Some(Self {
+            free: bytes("free"),
+            cached: bytes("cached"),
+            buffers: bytes("buffers"),
+            shared: bytes("shared"),
+            slab_reclaimable: bytes("slab_reclaimable"),
+            slab_unreclaimable: bytes("slab_unreclaimable"),
+            dirty: bytes("dirty"),
+            writeback: bytes("writeback"),
+            hugepages_total: bytes("hugepages_total"),
+            hugepages_free: bytes("hugepages_free"),
+            committed: bytes("committed"),
+            commit_limit: bytes("commit_limit"),
+            swap_in_per_sec: rate("swap_in_per_sec"),
+            swap_out_per_sec: rate("swap_out_per_sec"),
+            major_faults_per_sec: rate("major_faults_per_sec"),
+        })
+    }
+
+    /// Split `total` into non-overlapping parts, from hardest to easiest to
+    /// reclaim. Whatever no other counter explains is attributed to applications.
+    pub fn composition(&self, total: u64) -> [(&'static str, u64); 8] {
+        let slab = self.slab_reclaimable.saturating_add(self.slab_unreclaimable);
+        let applications = total
+            .saturating_sub(self.free)
+            .saturating_sub(self.buffers)
+            .saturating_sub(self.cached)
+            .saturating_sub(slab)
+            .saturating_sub(self.hugepages_total);
+        [
+            ("Applications", applications),
+            ("Huge pages", self.hugepages_total),
+            ("Kernel slab", self.slab_unreclaimable),
+            ("Shared", self.shared),
+            ("Reclaimable slab", self.slab_reclaimable),
+            ("Buffers", self.buffers),
+            ("Page cache", self.cached.saturating_sub(self.shared)),
+            ("Free", self.free),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BatterySnapshot {
     pub design_capacity: u32,
@@ -84,6 +346,8 @@ pub struct SystemInfoSnapshot {
     pub bios_version: Option<String>,
     pub gpu_driver: Option<String>,
     pub os_build: Option<String>,
+    #[serde(default)]
+    pub hypervisor: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,7 +359,19 @@ pub struct SystemSnapshot {
     pub memory_total: u64,
     pub memory_used: u64,
     pub memory_percentage: f32,
+    #[serde(default)]
+    pub memory_available: u64,
+    #[serde(default)]
+    pub memory_breakdown: Option<MemoryBreakdownSnapshot>,
     pub swap: SwapSnapshot,
+    #[serde(default)]
+    pub pressure: Option<PressureSnapshot>,
+    #[serde(default)]
+    pub load: Option<LoadSnapshot>,
+    #[serde(default)]
+    pub throttle: Option<ThrottleSnapshot>,
+    #[serde(default)]
+    pub block_devices: Vec<BlockDeviceSnapshot>,
     pub gpus: Vec<GpuSnapshot>,
     pub disks: Vec<DiskSnapshot>,
     pub networks: Vec<NetworkSnapshot>,
@@ -116,7 +392,13 @@ impl Default for SystemSnapshot {
             memory_total: 0,
             memory_used: 0,
             memory_percentage: 0.0,
+            memory_available: 0,
+            memory_breakdown: None,
             swap: SwapSnapshot::default(),
+            pressure: None,
+            load: None,
+            throttle: None,
+            block_devices: Vec::new(),
             gpus: Vec::new(),
             disks: Vec::new(),
             networks: Vec::new(),
@@ -128,3 +410,32 @@ impl Default for SystemSnapshot {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_composition_accounts_for_every_byte() {
+        let gib = 1 << 30;
+        let metrics: HashMap<String, f64> = [
+            ("memory.free", 2 * gib),
+            ("memory.cached", 5 * gib),
+            ("memory.shared", gib),
+            ("memory.buffers", gib / 2),
+            ("memory.slab_reclaimable", gib / 2),
+            ("memory.slab_unreclaimable", gib / 4),
+            ("memory.hugepages_total", gib),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value as f64))
+        .collect();
+        let breakdown = MemoryBreakdownSnapshot::from_metrics(&metrics).unwrap();
+
+        let parts = breakdown.composition(16 * gib);
+        assert_eq!(parts.iter().map(|(_, bytes)| bytes).sum::<u64>(), 16 * gib);
+        assert_eq!(parts[0], ("Applications", 6 * gib + 3 * gib / 4));
+        assert_eq!(parts[6], ("Page cache", 4 * gib));
+        assert_eq!(MemoryBreakdownSnapshot::from_metrics(&HashMap::new()), None);
+    }
+}
@@ -0,0 +1,204 @@
+//! Sustained CPU throttling detection.
+//!
+//! A sample counts as throttled when the kernel reported thermal throttle
+//! events since the previous poll, or when the CPU is busy, hot and clocked
+//! well below its rated maximum. Low clocks alone are not enough: power-saving
+//! plans and idle cores run slow on purpose.
+//!
+//! Throttling becomes sustained once half of the recent window is throttled and
+//! clears again only when few samples are, so a borderline workload does not
+//! flap between the two states.
+
+use super::snapshot::ThrottleSnapshot;
+use crate::cpu_topology::CpuTopology;
+use std::collections::{HashMap, VecDeque};
+
+/// Samples kept for the sustained verdict; one minute at the default refresh rate.
+const WINDOW_SAMPLES: usize = 60;
+const SUSTAINED_SAMPLES: usize = 30;
+const CLEARED_SAMPLES: usize = 10;
+const BUSY_CPU_PERCENT: f32 = 50.0;
+/// All-core turbo commonly sits at 75-85% of the single-core maximum, so only
+/// clocks well below that count.
+const LOW_CLOCK_RATIO: f64 = 0.7;
+const HOT_CPU_CELSIUS: f32 = 90.0;
+/// Windows reads WMI thermal zones only every tenth refresh, so a reading
+/// stands in for the samples after it until it is this many samples old.
+const STALE_TEMPERATURE_SAMPLES: u32 = 15;
+
+#[derive(Debug, Default)]
+pub struct ThrottleDetector {
+    window: VecDeque<bool>,
+    sustained: bool,
+    /// Last temperature seen and how many samples ago.
+    last_temperature: Option<(f32, u32)>,
+}
+
+impl ThrottleDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Judge one sample. Returns `None` while no per-core clock is published.
+    pub fn observe(
+        &mut self,
+        metrics: &HashMap<String, f64>,
+        cpu_usage: f32,
+        temperature: Option<f32>,
+        topology: Option<&CpuTopology>,
+    ) -> Option<ThrottleSnapshot> {
+        let frequencies = core_frequencies(metrics);
+        if frequencies.is_empty() {
+            return None;
+        }
+        let average_mhz = frequencies.iter().map(|(_, mhz)| mhz).sum::<f64>() / frequencies.len() as f64;
+        let rated: Vec<f64> = frequencies
+            .iter()
+            .filter_map(|(id, _)| topology.and_then(|topology| topology.cpu(*id))?.max_mhz)
+            .map(|mhz| mhz as f64)
+            .collect();
+        let max_mhz = if rated.is_empty() {
+            metrics.get("cpu.max_clock_mhz").copied().filter(|mhz| *mhz > 0.0)
+        } else {
+            Some(rated.iter().sum::<f64>() / rated.len() as f64)
+        };
+        let events_per_sec = ["core", "package"]
+            .iter()
+            .filter_map(|kind| metrics.get(&format!("cpu.throttle.{kind}_events_per_sec")))
+            .sum::<f64>();
+
+        let temperature = self.temperature(temperature);
+        let mut snapshot = ThrottleSnapshot {
+            average_mhz,
+            max_mhz,
+            temperature,
+            events_per_sec,
+            ..ThrottleSnapshot::default()
+        };
+        let hot_and_slow = cpu_usage >= BUSY_CPU_PERCENT
+            && temperature.is_some_and(|temperature| temperature >= HOT_CPU_CELSIUS)
+            && snapshot.clock_ratio().is_some_and(|ratio| ratio < LOW_CLOCK_RATIO);
+        if self.window.len() == WINDOW_SAMPLES {
+            self.window.pop_front();
+        }
+        self.window.push_back(events_per_sec > 0.0 || hot_and_slow);
+
+        let throttled = self.window.iter().filter(|throttled| **throttled).count();
+        if throttled >= SUSTAINED_SAMPLES {
+            self.sustained = true;
+        } else if throttled <= CLEARED_SAMPLES {
+            self.sustained = false;
+        }
+        snapshot.throttled_samples = throttled as u32;
+        snapshot.window_samples = self.window.len() as u32;
+        snapshot.sustained = self.sustained;
+        Some(snapshot)
+    }
+
+    /// The current reading, or the last one while it is still fresh.
+    fn temperature(&mut self, reading: Option<f32>) -> Option<f32> {
+        match (reading, &mut self.last_temperature) {
+            (Some(reading), last) => *last = Some((reading, 0)),
+            (None, Some((_, age))) => *age = age.saturating_add(1),
+            (None, None) => {}
+        }
+        self.last_temperature
+            .filter(|(_, age)| *age <= STALE_TEMPERATURE_SAMPLES)
+            .map(|(temperature, _)| temperature)
+    }
+}
+
+/// `(logical cpu, MHz)` for every core publishing a non-zero clock.
+pub fn core_frequencies(metrics: &HashMap<String, f64>) -> Vec<(usize, f64)> {
+    let count = metrics.get("cpu.core_count").copied().unwrap_or_default() as usize;
+    (0..count)
+        .filter_map(|id| {
+            metrics
+                .get(&format!("cpu.core.{id}.frequency"))
+                .filter(|mhz| **mhz > 0.0)
+                .map(|mhz| (id, *mhz))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(mhz: f64, events: f64) -> HashMap<String, f64> {
+        let mut metrics = HashMap::from([
+            ("cpu.core_count".to_string(), 2.0),
+            ("cpu.max_clock_mhz".to_string(), 4000.0),
+            ("cpu.throttle.core_events_per_sec".to_string(), events),
+        ]);
+        for id in 0..2 {
+            metrics.insert(format!("cpu.core.{id}.frequency"), mhz);
+        }
+        metrics
+    }
+
+    #[test]
+    fn hot_slow_busy_cpu_becomes_sustained_and_clears() {
+        let mut detector = ThrottleDetector::new();
+        let hot = metrics(2000.0, 0.0);
+        for _ in 0..SUSTAINED_SAMPLES - 1 {
+            assert!(!detector.observe(&hot, 100.0, Some(98.0), None).unwrap().sustained);
+        }
+        let snapshot = detector.observe(&hot, 100.0, Some(98.0), None).unwrap();
+        assert!(snapshot.sustained);
+        assert_eq!(snapshot.clock_ratio(), Some(0.5));
+        assert_eq!(snapshot.throttled_samples as usize, SUSTAINED_SAMPLES);
+
+        // Cool but slow clocks keep the verdict until the window drains.
+        let cool = metrics(2000.0, 0.0);
+        let mut last = snapshot;
+        for _ in 0..WINDOW_SAMPLES - CLEARED_SAMPLES {
+            last = detector.observe(&cool, 100.0, Some(60.0), None).unwrap();
+        }
+        assert!(!last.sustained);
+    }
+
+    #[test]
+    fn sparse_temperature_readings_still_reach_a_sustained_verdict() {
+        let mut detector = ThrottleDetector::new();
+        let hot = metrics(2000.0, 0.0);
+        let mut last = None;
+        for sample in 0..WINDOW_SAMPLES {
+            let reading = (sample % 10 == 0).then_some(98.0);
+            last = detector.observe(&hot, 100.0, reading, None);
+        }
+        let last = last.unwrap();
+        assert!(last.sustained);
+        assert_eq!(last.temperature, Some(98.0));
+        assert_eq!(last.throttled_samples as usize, WINDOW_SAMPLES);
+
+        // A reading that is never refreshed stops counting once it goes stale.
+        let mut detector = ThrottleDetector::new();
+        detector.observe(&hot, 100.0, Some(98.0), None);
+        let mut last = None;
+        for _ in 1..WINDOW_SAMPLES {
+            last = detector.observe(&hot, 100.0, None, None);
+        }
+        let last = last.unwrap();
+        assert_eq!(last.temperature, None);
+        assert_eq!(last.throttled_samples, 1 + STALE_TEMPERATURE_SAMPLES);
+        assert!(!last.sustained);
+    }
+
+    #[test]
+    fn slow_clocks_without_heat_or_kernel_events_are_not_throttling() {
+        let mut detector = ThrottleDetector::new();
+        for _ in 0..WINDOW_SAMPLES {
+            let snapshot = detector.observe(&metrics(1200.0, 0.0), 100.0, None, None).unwrap();
+            assert_eq!(snapshot.throttled_samples, 0);
+        }
+
+        let mut detector = ThrottleDetector::new();
+        let mut last = None;
+        for _ in 0..SUSTAINED_SAMPLES {
+            last = detector.observe(&metrics(3900.0, 2.0), 10.0, None, None);
+        }
+        assert!(last.unwrap().sustained);
+        assert!(detector.observe(&HashMap::new(), 10.0, None, None).is_none());
+    }
+}
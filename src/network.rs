@@ -1,23 +1,186 @@
-//! Active TCP and UDP socket connection monitoring with process PID resolution.
+//! Active TCP and UDP socket connection monitoring with process PID resolution,
+//! plus per-interface link state, addressing and packet counters.
 
 use std::collections::HashMap;
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+/// IP version of a socket's endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+pub enum AddressFamily {
+    Ipv4,
+    Ipv6,
+}
+
+impl AddressFamily {
+    pub fn of(addr: &SocketAddr) -> Self {
+        match addr {
+            SocketAddr::V4(_) => Self::Ipv4,
+            SocketAddr::V6(_) => Self::Ipv6,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ipv4 => "IPv4",
+            Self::Ipv6 => "IPv6",
+        }
+    }
+}
 
 #[derive(Debug, Clone, serde::Serialize, PartialEq)]
 pub struct SocketConnection {
     pub protocol: &'static str,
-    pub local_addr: String,
-    pub remote_addr: String,
+    pub family: AddressFamily,
+    pub local_addr: SocketAddr,
+    /// `None` for listeners and unconnected UDP sockets.
+    pub remote_addr: Option<SocketAddr>,
     pub state: &'static str,
     pub pid: u32,
     pub process_name: Option<String>,
 }
 
+impl SocketConnection {
+    /// Local endpoint as `ip:port`, with IPv6 addresses bracketed (`[::1]:22`).
+    pub fn local_display(&self) -> String {
+        self.local_addr.to_string()
+    }
+
+    /// Remote endpoint, or `*:*` when the socket has no peer.
+    pub fn remote_display(&self) -> String {
+        self.remote_addr
+            .map_or_else(|| "*:*".to_string(), |addr| addr.to_string())
+    }
+}
+
+/// Broad class of a network interface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize)]
+pub enum InterfaceKind {
+    #[default]
+    Unknown,
+    Ethernet,
+    Wifi,
+    Loopback,
+    Bridge,
+    /// Software-only links such as veth pairs, tun/tap and VPN tunnels.
+    Virtual,
+}
+
+impl InterfaceKind {
+    /// Best guess from the adapter name, for platforms without sysfs.
+    pub fn from_name(name: &str) -> Self {
+        let name = name.to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|needle| name.contains(needle));
+        if name == "lo" || has(&["loopback"]) {
+            Self::Loopback
+        } else if has(&["vethernet", "virtual", "vpn", "tap", "tun", "wsl", "docker", "veth"]) {
+            Self::Virtual
+        } else if has(&["bridge"]) {
+            Self::Bridge
+        } else if has(&["wi-fi", "wifi", "wireless", "wlan"]) {
+            Self::Wifi
+        } else if has(&["ethernet", "eth"]) || name.starts_with("en") {
+            Self::Ethernet
+        } else {
+            Self::Unknown
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Ethernet => "Ethernet",
+            Self::Wifi => "Wi-Fi",
+            Self::Loopback => "Loopback",
+            Self::Bridge => "Bridge",
+            Self::Virtual => "Virtual",
+        }
+    }
+}
+
+/// Cumulative packet, error and drop counters since the interface appeared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct InterfaceCounters {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+}
+
+impl InterfaceCounters {
+    pub fn errors(&self) -> u64 {
+        self.rx_errors.saturating_add(self.tx_errors)
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.rx_dropped.saturating_add(self.tx_dropped)
+    }
+}
+
+/// Link state, addressing and counters for one interface. Anything the
+/// platform does not report is left as `None` or empty.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct InterfaceDetails {
+    pub kind: InterfaceKind,
+    /// RFC 2863 operational state as the kernel reports it (`up`, `down`, `dormant`, ...).
+    pub operstate: Option<String>,
+    pub carrier: Option<bool>,
+    pub speed_mbps: Option<u32>,
+    pub duplex: Option<String>,
+    pub mtu: Option<u32>,
+    pub mac: Option<String>,
+    pub ipv4: Vec<Ipv4Addr>,
+    pub ipv6: Vec<Ipv6Addr>,
+    pub counters: InterfaceCounters,
+}
+
+impl InterfaceDetails {
+    /// Whether the link can pass traffic, or `None` when the state is unknown.
+    /// Loopback and tunnel devices report `unknown` and fall back to the carrier.
+    pub fn link_up(&self) -> Option<bool> {
+        match self.operstate.as_deref() {
+            Some("up") => Some(true),
+            Some("down" | "lowerlayerdown" | "notpresent") => Some(false),
+            _ => self.carrier,
+        }
+    }
+}
+
+/// Treat an all-zero peer (`0.0.0.0:0` / `[::]:0`) as "no remote endpoint".
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn remote_endpoint(addr: SocketAddr) -> Option<SocketAddr> {
+    (!(addr.ip().is_unspecified() && addr.port() == 0)).then_some(addr)
+}
+
+/// Name a `MIB_TCP_STATE` value. Linux maps its kernel states onto these so
+/// both platforms report the same state names.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn tcp_state_str(state: u32) -> &'static str {
+    match state {
+        1 => "CLOSED",
+        2 => "LISTEN",
+        3 => "SYN_SENT",
+        4 => "SYN_RCVD",
+        5 => "ESTABLISHED",
+        6 => "FIN_WAIT1",
+        7 => "FIN_WAIT2",
+        8 => "CLOSE_WAIT",
+        9 => "CLOSING",
+        10 => "LAST_ACK",
+        11 => "TIME_WAIT",
+        12 => "DELETE_TCB",
+        _ => "UNKNOWN",
+    }
+}
+
 #[cfg(target_os = "windows")]
 mod windows_impl {
     use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
     const AF_INET: u32 = 2;
+    const AF_INET6: u32 = 23;
     const TCP_TABLE_OWNER_PID_ALL: u32 = 5;
     const UDP_TABLE_OWNER_PID: u32 = 1;
 
@@ -40,6 +203,30 @@ mod windows_impl {
         dw_owning_pid: u32,
     }
 
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct MIB_TCP6ROW_OWNER_PID {
+        uc_local_addr: [u8; 16],
+        dw_local_scope_id: u32,
+        dw_local_port: u32,
+        uc_remote_addr: [u8; 16],
+        dw_remote_scope_id: u32,
+        dw_remote_port: u32,
+        dw_state: u32,
+        dw_owning_pid: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct MIB_UDP6ROW_OWNER_PID {
+        uc_local_addr: [u8; 16],
+        dw_local_scope_id: u32,
+        dw_local_port: u32,
+        dw_owning_pid: u32,
+    }
+
+    type TableFn = unsafe extern "system" fn(*mut u8, *mut u32, i32, u32, u32, u32) -> u32;
+
     #[link(name = "iphlpapi")]
     extern "system" {
         fn GetExtendedTcpTable(
@@ -61,92 +248,547 @@ mod windows_impl {
         ) -> u32;
     }
 
-    fn tcp_state_str(state: u32) -> &'static str {
-        match state {
-            1 => "CLOSED",
-            2 => "LISTEN",
-            3 => "SYN_SENT",
-            4 => "SYN_RCVD",
-            5 => "ESTABLISHED",
-            6 => "FIN_WAIT1",
-            7 => "FIN_WAIT2",
-            8 => "CLOSE_WAIT",
-            9 => "CLOSING",
-            10 => "LAST_ACK",
-            11 => "TIME_WAIT",
-            12 => "DELETE_TCB",
-            _ => "UNKNOWN",
-        }
+    /// Ports in MIB rows are in network byte order in the lower 16 bits.
+    fn port(port_raw: u32) -> u16 {
+        u16::from_be((port_raw & 0xFFFF) as u16)
     }
 
-    fn parse_ipv4(addr_raw: u32, port_raw: u32) -> String {
-        let ip = Ipv4Addr::from(addr_raw.to_le());
-        // Ports in MIB rows are in network byte order in the lower 16 bits
-        let port = u16::from_be((port_raw & 0xFFFF) as u16);
-        format!("{ip}:{port}")
+    fn ipv4_endpoint(addr_raw: u32, port_raw: u32) -> SocketAddr {
+        // The address is stored in network byte order, i.e. its in-memory bytes are the octets.
+        SocketAddr::V4(SocketAddrV4::new(
+            Ipv4Addr::from(addr_raw.to_ne_bytes()),
+            port(port_raw),
+        ))
     }
 
-    pub fn get_connections_internal() -> Vec<SocketConnection> {
-        let mut connections = Vec::new();
+    fn ipv6_endpoint(addr: [u8; 16], scope_id: u32, port_raw: u32) -> SocketAddr {
+        SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::from(addr), port(port_raw), 0, scope_id))
+    }
 
-        // 1. Fetch TCP Table
+    /// Fetch an owner-PID table for `family` and copy its rows out of the returned buffer.
+    fn read_table<T: Copy>(fetch: TableFn, family: u32, class: u32) -> Vec<T> {
         unsafe {
             let mut size: u32 = 0;
-            let _ = GetExtendedTcpTable(std::ptr::null_mut(), &mut size, 0, AF_INET, TCP_TABLE_OWNER_PID_ALL, 0);
-            if size > 0 {
-                let mut buffer = vec![0u8; size as usize];
-                if GetExtendedTcpTable(buffer.as_mut_ptr(), &mut size, 0, AF_INET, TCP_TABLE_OWNER_PID_ALL, 0) == 0 {
-                    let num_entries = *(buffer.as_ptr() as *const u32) as usize;
-                    let table_ptr = buffer.as_ptr().add(std::mem::size_of::<u32>()) as *const MIB_TCPROW_OWNER_PID;
-                    for i in 0..num_entries {
-                        let row = *table_ptr.add(i);
-                        let local = parse_ipv4(row.dw_local_addr, row.dw_local_port);
-                        let remote = if row.dw_remote_addr == 0 {
-                            "*:*".to_string()
-                        } else {
-                            parse_ipv4(row.dw_remote_addr, row.dw_remote_port)
-                        };
-
-                        connections.push(SocketConnection {
-                            protocol: "TCP",
-                            local_addr: local,
-                            remote_addr: remote,
-                            state: tcp_state_str(row.dw_state),
-                            pid: row.dw_owning_pid,
-                            process_name: None,
-                        });
+            let _ = fetch(std::ptr::null_mut(), &mut size, 0, family, class, 0);
+            if size == 0 {
+                return Vec::new();
+            }
+            let mut buffer = vec![0u8; size as usize];
+            if fetch(buffer.as_mut_ptr(), &mut size, 0, family, class, 0) != 0 {
+                return Vec::new();
+            }
+            let num_entries = (buffer.as_ptr() as *const u32).read_unaligned() as usize;
+            let table_ptr = buffer.as_ptr().add(std::mem::size_of::<u32>()) as *const T;
+            (0..num_entries).map(|i| table_ptr.add(i).read_unaligned()).collect()
+        }
+    }
+
+    pub fn get_connections_internal() -> Vec<SocketConnection> {
+        let mut connections = Vec::new();
+
+        for row in read_table::<MIB_TCPROW_OWNER_PID>(GetExtendedTcpTable, AF_INET, TCP_TABLE_OWNER_PID_ALL) {
+            let local_addr = ipv4_endpoint(row.dw_local_addr, row.dw_local_port);
+            connections.push(SocketConnection {
+                protocol: "TCP",
+                family: AddressFamily::of(&local_addr),
+                local_addr,
+                remote_addr: remote_endpoint(ipv4_endpoint(row.dw_remote_addr, row.dw_remote_port)),
+                state: tcp_state_str(row.dw_state),
+                pid: row.dw_owning_pid,
+                process_name: None,
+            });
+        }
+
+        for row in read_table::<MIB_TCP6ROW_OWNER_PID>(GetExtendedTcpTable, AF_INET6, TCP_TABLE_OWNER_PID_ALL) {
+            let local_addr = ipv6_endpoint(row.uc_local_addr, row.dw_local_scope_id, row.dw_local_port);
+            connections.push(SocketConnection {
+                protocol: "TCP",
+                family: AddressFamily::of(&local_addr),
+                local_addr,
+                remote_addr: remote_endpoint(ipv6_endpoint(
+                    row.uc_remote_addr,
+                    row.dw_remote_scope_id,
+                    row.dw_remote_port,
+                )),
+                state: tcp_state_str(row.dw_state),
+                pid: row.dw_owning_pid,
+                process_name: None,
+            });
+        }
+
+        for row in read_table::<MIB_UDPROW_OWNER_PID>(GetExtendedUdpTable, AF_INET, UDP_TABLE_OWNER_PID) {
+            let local_addr = ipv4_endpoint(row.dw_local_addr, row.dw_local_port);
+            connections.push(SocketConnection {
+                protocol: "UDP",
+                family: AddressFamily::of(&local_addr),
+                local_addr,
+                remote_addr: None,
+                state: "LISTEN",
+                pid: row.dw_owning_pid,
+                process_name: None,
+            });
+        }
+
+        for row in read_table::<MIB_UDP6ROW_OWNER_PID>(GetExtendedUdpTable, AF_INET6, UDP_TABLE_OWNER_PID) {
+            let local_addr = ipv6_endpoint(row.uc_local_addr, row.dw_local_scope_id, row.dw_local_port);
+            connections.push(SocketConnection {
+                protocol: "UDP",
+                family: AddressFamily::of(&local_addr),
+                local_addr,
+                remote_addr: None,
+                state: "LISTEN",
+                pid: row.dw_owning_pid,
+                process_name: None,
+            });
+        }
+
+        connections
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux_impl {
+    use super::*;
+    use std::net::IpAddr;
+    use std::path::Path;
+
+    /// `/proc/net` tables and the protocol label each one is reported under.
+    const TABLES: [(&str, &str); 4] = [("tcp", "TCP"), ("tcp6", "TCP"), ("udp", "UDP"), ("udp6", "UDP")];
+
+    /// Translate a kernel `TCP_*` state (include/net/tcp_states.h) into the
+    /// matching `MIB_TCP_STATE` value.
+    fn mib_tcp_state(state: u8) -> u32 {
+        match state {
+            0x01 => 5,
+            0x02 => 3,
+            0x03 | 0x0C => 4,
+            0x04 => 6,
+            0x05 => 7,
+            0x06 => 11,
+            0x07 => 1,
+            0x08 => 8,
+            0x09 => 10,
+            0x0A => 2,
+            0x0B => 9,
+            _ => 0,
+        }
+    }
+
+    /// Parse an `ADDR:PORT` column. The address is printed as native-endian
+    /// 32-bit words (one for IPv4, four for IPv6); the port is plain hex.
+    pub(super) fn parse_endpoint(raw: &str) -> Option<SocketAddr> {
+        let (addr_hex, port_hex) = raw.split_once(':')?;
+        let port = u16::from_str_radix(port_hex, 16).ok()?;
+        let mut bytes = Vec::with_capacity(16);
+        for index in (0..addr_hex.len()).step_by(8) {
+            let word = u32::from_str_radix(addr_hex.get(index..index + 8)?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        let ip = match bytes.len() {
+            4 => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(bytes).ok()?)),
+            16 => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(bytes).ok()?)),
+            _ => return None,
+        };
+        Some(SocketAddr::new(ip, port))
+    }
+
+    /// Parse one `/proc/net/{tcp,udp}[6]` table into connections paired with
+    /// their socket inode. Malformed rows are skipped.
+    pub(super) fn parse_table(contents: &str, protocol: &'static str) -> Vec<(SocketConnection, u64)> {
+        contents
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let local = parse_endpoint(fields.get(1)?)?;
+                let remote = parse_endpoint(fields.get(2)?)?;
+                let state_raw = u8::from_str_radix(fields.get(3)?, 16).ok()?;
+                let inode = fields.get(9)?.parse().ok()?;
+                let state = if protocol == "UDP" {
+                    // Unconnected UDP sockets report TCP_CLOSE; Windows lists them as listeners.
+                    if state_raw == 0x01 {
+                        "ESTABLISHED"
+                    } else {
+                        "LISTEN"
                     }
+                } else {
+                    tcp_state_str(mib_tcp_state(state_raw))
+                };
+                Some((
+                    SocketConnection {
+                        protocol,
+                        family: AddressFamily::of(&local),
+                        local_addr: local,
+                        remote_addr: remote_endpoint(remote),
+                        state,
+                        pid: 0,
+                        process_name: None,
+                    },
+                    inode,
+                ))
+            })
+            .collect()
+    }
+
+    /// Map socket inodes to the lowest PID holding a descriptor for them.
+    ///
+    /// Processes whose `fd` directory is unreadable (other users without
+    /// privileges, or already exited) are skipped, so their sockets keep PID 0.
+    pub(super) fn socket_owners(proc_root: &Path) -> HashMap<u64, u32> {
+        let Ok(entries) = std::fs::read_dir(proc_root) else {
+            return HashMap::new();
+        };
+        let mut pids: Vec<u32> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+            .collect();
+        pids.sort_unstable();
+
+        let mut owners = HashMap::new();
+        for pid in pids {
+            let Ok(fds) = std::fs::read_dir(proc_root.join(pid.to_string()).join("fd")) else {
+                continue;
+            };
+            for fd in fds.filter_map(Result::ok) {
+                let Ok(target) = std::fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let inode = target
+                    .to_str()
+                    .and_then(|target| target.strip_prefix("socket:["))
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .and_then(|inode| inode.parse::<u64>().ok());
+                if let Some(inode) = inode {
+                    owners.entry(inode).or_insert(pid);
                 }
             }
         }
+        owners
+    }
 
-        // 2. Fetch UDP Table
-        unsafe {
-            let mut size: u32 = 0;
-            let _ = GetExtendedUdpTable(std::ptr::null_mut(), &mut size, 0, AF_INET, UDP_TABLE_OWNER_PID, 0);
-            if size > 0 {
-                let mut buffer = vec![0u8; size as usize];
-                if GetExtendedUdpTable(buffer.as_mut_ptr(), &mut size, 0, AF_INET, UDP_TABLE_OWNER_PID, 0) == 0 {
-                    let num_entries = *(buffer.as_ptr() as *const u32) as usize;
-                    let table_ptr = buffer.as_ptr().add(std::mem::size_of::<u32>()) as *const MIB_UDPROW_OWNER_PID;
-                    for i in 0..num_entries {
-                        let row = *table_ptr.add(i);
-                        let local = parse_ipv4(row.dw_local_addr, row.dw_local_port);
-
-                        connections.push(SocketConnection {
-                            protocol: "UDP",
-                            local_addr: local,
-                            remote_addr: "*:*".to_string(),
-                            state: "LISTEN",
-                            pid: row.dw_owning_pid,
-                            process_name: None,
-                        });
-                    }
+    /// Details for every interface under `/sys/class/net` (or a fake tree in tests).
+    pub(super) fn interface_details(class_net: &Path) -> HashMap<String, InterfaceDetails> {
+        let Ok(entries) = std::fs::read_dir(class_net) else {
+            return HashMap::new();
+        };
+        entries
+            .flatten()
+            .map(|entry| {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                (name, read_interface(&entry.path()))
+            })
+            .collect()
+    }
+
+    pub(super) fn read_interface(dir: &Path) -> InterfaceDetails {
+        let read = |name: &str| {
+            std::fs::read_to_string(dir.join(name))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let number = |name: &str| read(name).and_then(|value| value.parse::<u64>().ok());
+        let arp_type = number("type");
+
+        // `carrier`, `speed` and `duplex` fail with EINVAL while the link is
+        // administratively down; `speed` is -1 when the driver cannot tell.
+        InterfaceDetails {
+            kind: interface_kind(dir, arp_type),
+            operstate: read("operstate"),
+            carrier: read("carrier").map(|carrier| carrier == "1"),
+            speed_mbps: read("speed")
+                .and_then(|speed| speed.parse::<i64>().ok())
+                .filter(|speed| *speed > 0)
+                .and_then(|speed| u32::try_from(speed).ok()),
+            duplex: read("duplex").filter(|duplex| duplex != "unknown"),
+            mtu: number("mtu").and_then(|mtu| u32::try_from(mtu).ok()),
+            mac: read("address").filter(|mac| mac != "00:00:00:00:00:00"),
+            ipv4: Vec::new(),
+            ipv6: Vec::new(),
+            counters: InterfaceCounters {
+                rx_packets: number("statistics/rx_packets").unwrap_or_default(),
+                tx_packets: number("statistics/tx_packets").unwrap_or_default(),
+                rx_errors: number("statistics/rx_errors").unwrap_or_default(),
+                tx_errors: number("statistics/tx_errors").unwrap_or_default(),
+                rx_dropped: number("statistics/rx_dropped").unwrap_or_default(),
+                tx_dropped: number("statistics/tx_dropped").unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Classify from the ARP hardware type (`type`) and the sysfs layout:
+    /// wireless devices expose `wireless`/`phy80211`, bridges a `bridge`
+    /// directory, and only hardware-backed links have a `device` link.
+    fn interface_kind(dir: &Path, arp_type: Option<u64>) -> InterfaceKind {
+        const ARPHRD_ETHER: u64 = 1;
+        const ARPHRD_LOOPBACK: u64 = 772;
+        if arp_type == Some(ARPHRD_LOOPBACK) {
+            InterfaceKind::Loopback
+        } else if dir.join("wireless").exists() || dir.join("phy80211").exists() {
+            InterfaceKind::Wifi
+        } else if dir.join("bridge").is_dir() {
+            InterfaceKind::Bridge
+        } else if dir.join("device").exists() {
+            if arp_type == Some(ARPHRD_ETHER) {
+                InterfaceKind::Ethernet
+            } else {
+                InterfaceKind::Unknown
+            }
+        } else if arp_type.is_some() {
+            InterfaceKind::Virtual
+        } else {
+            InterfaceKind::Unknown
+        }
+    }
+
+    /// IPv4 and IPv6 addresses per interface name from `getifaddrs(3)`.
+    pub(super) fn interface_addresses() -> HashMap<String, (Vec<Ipv4Addr>, Vec<Ipv6Addr>)> {
+        let mut addresses: HashMap<String, (Vec<Ipv4Addr>, Vec<Ipv6Addr>)> = HashMap::new();
+        let mut head: *mut libc::ifaddrs = std::ptr::null_mut();
+        // SAFETY: on success the list is owned by us until `freeifaddrs` below.
+        if unsafe { libc::getifaddrs(&mut head) } != 0 {
+            return addresses;
+        }
+        let mut cursor = head;
+        while !cursor.is_null() {
+            // SAFETY: `cursor` is a node of the list returned by `getifaddrs`.
+            let entry = unsafe { &*cursor };
+            cursor = entry.ifa_next;
+            if entry.ifa_addr.is_null() || entry.ifa_name.is_null() {
+                continue;
+            }
+            // SAFETY: `ifa_name` is a NUL-terminated string owned by the list.
+            let name = unsafe { std::ffi::CStr::from_ptr(entry.ifa_name) }
+                .to_string_lossy()
+                .into_owned();
+            // SAFETY: `ifa_addr` is non-null and its family tag says which
+            // `sockaddr_*` layout it points to.
+            match i32::from(unsafe { (*entry.ifa_addr).sa_family }) {
+                libc::AF_INET => {
+                    let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in) };
+                    let ip = Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr));
+                    addresses.entry(name).or_default().0.push(ip);
                 }
+                libc::AF_INET6 => {
+                    let addr = unsafe { &*(entry.ifa_addr as *const libc::sockaddr_in6) };
+                    let ip = Ipv6Addr::from(addr.sin6_addr.s6_addr);
+                    addresses.entry(name).or_default().1.push(ip);
+                }
+                _ => {}
             }
         }
+        // SAFETY: `head` came from a successful `getifaddrs` call and is freed once.
+        unsafe { libc::freeifaddrs(head) };
+        addresses
+    }
 
-        connections
+    /// Interface index of every local IPv6 address, from `/proc/net/if_inet6`.
+    /// Each row is the address as 32 hex digits in network order followed by
+    /// the interface index in hex.
+    pub(super) fn ipv6_scopes(proc_root: &Path) -> HashMap<Ipv6Addr, u32> {
+        let Ok(contents) = std::fs::read_to_string(proc_root.join("net/if_inet6")) else {
+            return HashMap::new();
+        };
+        contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let addr = u128::from_str_radix(fields.next()?, 16).ok()?;
+                let index = u32::from_str_radix(fields.next()?, 16).ok()?;
+                Some((Ipv6Addr::from(addr), index))
+            })
+            .collect()
+    }
+
+    /// `/proc/net/tcp6` leaves out the zone of link-local endpoints. Take it
+    /// from the interface owning the local address; a link-local peer is
+    /// necessarily on that same link.
+    pub(super) fn fill_scope_id(connection: &mut SocketConnection, scopes: &HashMap<Ipv6Addr, u32>) {
+        let SocketAddr::V6(local) = &mut connection.local_addr else {
+            return;
+        };
+        if !local.ip().is_unicast_link_local() {
+            return;
+        }
+        let Some(&scope_id) = scopes.get(local.ip()) else {
+            return;
+        };
+        local.set_scope_id(scope_id);
+        if let Some(SocketAddr::V6(remote)) = &mut connection.remote_addr {
+            if remote.ip().is_unicast_link_local() {
+                remote.set_scope_id(scope_id);
+            }
+        }
+    }
+
+    pub fn get_connections_internal(proc_root: &Path) -> Vec<SocketConnection> {
+        let owners = socket_owners(proc_root);
+        let scopes = ipv6_scopes(proc_root);
+        TABLES
+            .iter()
+            .filter_map(|(table, protocol)| {
+                let contents = std::fs::read_to_string(proc_root.join("net").join(table)).ok()?;
+                Some(parse_table(&contents, protocol))
+            })
+            .flatten()
+            .map(|(mut connection, inode)| {
+                connection.pid = owners.get(&inode).copied().unwrap_or_default();
+                fill_scope_id(&mut connection, &scopes);
+                connection
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::providers::sysfs::TempTree;
+
+        const TCP: &str = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 4242 1 0000000000000000 100 0 0 10 0
+   1: 0F02000A:A2C4 5DB8D822:01BB 01 00000000:00000000 02:000A7C2B 00000000  1000        0 4343 2 0000000000000000 20 4 30 10 -1
+   2: 0F02000A:A2C6 5DB8D822:01BB 06 00000000:00000000 03:00001770 00000000     0        0 0 3 0000000000000000
+";
+        const TCP6: &str = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000001000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 5151 1 0000000000000000 100 0 0 10 0
+";
+        const UDP: &str = "   sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  100: 00000000:0035 00000000:0000 07 00000000:00000000 00:00000000 00000000   101        0 6161 2 0000000000000000 0
+";
+
+        fn socket_fd(root: &TempTree, pid: u32, fd: u32, inode: u64) {
+            root.symlink(format!("{pid}/fd/{fd}"), format!("socket:[{inode}]"));
+        }
+
+        #[test]
+        fn endpoints_decode_native_endian_words() {
+            assert_eq!(parse_endpoint("0100007F:1F90"), Some("127.0.0.1:8080".parse().unwrap()));
+            assert_eq!(
+                parse_endpoint("00000000000000000000000001000000:0016"),
+                Some("[::1]:22".parse().unwrap())
+            );
+            assert_eq!(parse_endpoint("0100007F"), None);
+            assert_eq!(parse_endpoint("XYZ:0016"), None);
+        }
+
+        #[test]
+        fn tcp_rows_use_windows_state_names() {
+            let rows = parse_table(TCP, "TCP");
+            assert_eq!(rows.len(), 3);
+            assert_eq!(rows[0].0.local_display(), "127.0.0.1:8080");
+            assert_eq!(rows[0].0.remote_addr, None);
+            assert_eq!(rows[0].0.remote_display(), "*:*");
+            assert_eq!(rows[0].0.state, "LISTEN");
+            assert_eq!(rows[0].1, 4242);
+            assert_eq!(rows[1].0.remote_display(), "34.216.184.93:443");
+            assert_eq!(rows[1].0.state, "ESTABLISHED");
+            assert_eq!(rows[2].0.state, "TIME_WAIT");
+            assert_eq!(parse_table(UDP, "UDP")[0].0.state, "LISTEN");
+        }
+
+        #[test]
+        fn interfaces_read_from_a_fake_sysfs_tree() {
+            let root = TempTree::with_files(
+                "classnet",
+                &[
+                    ("eth0/type", "1\n"),
+                    ("eth0/operstate", "up\n"),
+                    ("eth0/carrier", "1\n"),
+                    ("eth0/speed", "1000\n"),
+                    ("eth0/duplex", "full\n"),
+                    ("eth0/mtu", "1500\n"),
+                    ("eth0/address", "52:54:00:12:34:56\n"),
+                    ("eth0/statistics/rx_packets", "1200\n"),
+                    ("eth0/statistics/tx_errors", "3\n"),
+                    ("eth0/statistics/rx_dropped", "7\n"),
+                    ("wlan0/type", "1\n"),
+                    ("wlan0/operstate", "down\n"),
+                    ("wlan0/speed", "-1\n"),
+                    ("lo/type", "772\n"),
+                    ("lo/operstate", "unknown\n"),
+                    ("lo/carrier", "1\n"),
+                    ("lo/address", "00:00:00:00:00:00\n"),
+                    ("docker0/type", "1\n"),
+                    ("veth1a2b/type", "1\n"),
+                ],
+            );
+            for dir in ["eth0/device", "wlan0/phy80211", "wlan0/device", "docker0/bridge"] {
+                std::fs::create_dir_all(root.join(dir)).unwrap();
+            }
+
+            let details = interface_details(&root);
+            let eth = &details["eth0"];
+            assert_eq!(eth.kind, InterfaceKind::Ethernet);
+            assert_eq!(eth.link_up(), Some(true));
+            assert_eq!(
+                (eth.speed_mbps, eth.duplex.as_deref(), eth.mtu),
+                (Some(1000), Some("full"), Some(1500))
+            );
+            assert_eq!(eth.mac.as_deref(), Some("52:54:00:12:34:56"));
+            assert_eq!(eth.counters.rx_packets, 1200);
+            assert_eq!((eth.counters.errors(), eth.counters.dropped()), (3, 7));
+
+            let wlan = &details["wlan0"];
+            assert_eq!(wlan.kind, InterfaceKind::Wifi);
+            assert_eq!(wlan.link_up(), Some(false));
+            assert_eq!((wlan.carrier, wlan.speed_mbps), (None, None));
+
+            assert_eq!(details["lo"].kind, InterfaceKind::Loopback);
+            assert_eq!(details["lo"].link_up(), Some(true));
+            assert_eq!(details["lo"].mac, None);
+            assert_eq!(details["docker0"].kind, InterfaceKind::Bridge);
+            assert_eq!(details["veth1a2b"].kind, InterfaceKind::Virtual);
+        }
+
+        #[test]
+        fn loopback_has_an_address() {
+            let addresses = interface_addresses();
+            if let Some((ipv4, _)) = addresses.get("lo") {
+                assert!(ipv4.contains(&Ipv4Addr::LOCALHOST));
+            }
+        }
+
+        #[test]
+        fn connections_resolve_inodes_to_pids() {
+            let root = TempTree::with_files("procnet", &[("net/tcp", TCP), ("net/tcp6", TCP6), ("net/udp", UDP)]);
+            socket_fd(&root, 812, 3, 4242);
+            socket_fd(&root, 812, 4, 5151);
+            socket_fd(&root, 77, 9, 6161);
+            socket_fd(&root, 900, 5, 6161);
+            std::fs::create_dir_all(root.join("self")).unwrap();
+
+            let connections = get_connections_internal(&root);
+            assert_eq!(connections.len(), 5);
+            let pid_of = |local: &str| connections.iter().find(|c| c.local_display() == local).map(|c| c.pid);
+            assert_eq!(pid_of("127.0.0.1:8080"), Some(812));
+            assert_eq!(pid_of("[::1]:22"), Some(812));
+            assert_eq!(
+                connections.iter().filter(|c| c.family == AddressFamily::Ipv6).count(),
+                1
+            );
+            assert_eq!(pid_of("0.0.0.0:53"), Some(77));
+            assert_eq!(pid_of("10.0.2.15:41670"), Some(0));
+        }
+
+        #[test]
+        fn link_local_endpoints_get_their_interface_zone() {
+            const TCP6_LINK_LOCAL: &str = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 000080FE00000000FF00FC00010000FE:0016 000080FE00000000FF00FC00020000FE:D431 01 00000000:00000000 00:00000000 00000000     0        0 7171 1 0000000000000000 20 4 30 10 -1
+   1: 00000000000000000000000001000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 5151 1 0000000000000000 100 0 0 10 0
+";
+            const IF_INET6: &str = "00000000000000000000000000000001 01 80 10 80       lo
+fe8000000000000000fc00fffe000001 04 40 20 80     eth0
+";
+            let root = TempTree::with_files("procnet6", &[("net/tcp6", TCP6_LINK_LOCAL), ("net/if_inet6", IF_INET6)]);
+
+            let connections = get_connections_internal(&root);
+            assert_eq!(connections.len(), 2);
+            assert_eq!(connections[0].local_display(), "[fe80::fc:ff:fe00:1%4]:22");
+            assert_eq!(connections[0].remote_display(), "[fe80::fc:ff:fe00:2%4]:54321");
+            assert_eq!(connections[0].state, "ESTABLISHED");
+            // Global and loopback addresses carry no zone.
+            assert_eq!(connections[1].local_display(), "[::1]:22");
+        }
     }
 }
 
@@ -155,7 +797,10 @@ pub fn get_active_connections(process_names: &HashMap<u32, String>) -> Vec<Socke
     #[cfg(target_os = "windows")]
     let mut connections = windows_impl::get_connections_internal();
 
-    #[cfg(not(target_os = "windows"))]
+    #[cfg(target_os = "linux")]
+    let mut connections = linux_impl::get_connections_internal(std::path::Path::new("/proc"));
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
     let mut connections: Vec<SocketConnection> = Vec::new();
 
     for conn in &mut connections {
@@ -167,18 +812,43 @@ pub fn get_active_connections(process_names: &HashMap<u32, String>) -> Vec<Socke
     connections
 }
 
-/// Filter socket connections by search substring (PID, IP, Port, Process name, or State).
-pub fn filter_connections(items: &[SocketConnection], query: &str) -> Vec<SocketConnection> {
-    if query.trim().is_empty() {
-        return items.to_vec();
+/// Link state, addresses and counters for every interface, keyed by name.
+/// Only Linux reports these; elsewhere the map is empty and callers fall
+/// back to what `sysinfo` provides.
+pub fn get_interface_details() -> HashMap<String, InterfaceDetails> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut details = linux_impl::interface_details(std::path::Path::new("/sys/class/net"));
+        for (name, (ipv4, ipv6)) in linux_impl::interface_addresses() {
+            if let Some(interface) = details.get_mut(&name) {
+                interface.ipv4 = ipv4;
+                interface.ipv6 = ipv6;
+            }
+        }
+        details
     }
-    let q = query.to_lowercase();
+
+    #[cfg(not(target_os = "linux"))]
+    HashMap::new()
+}
+
+/// Filter socket connections by address family and search substring
+/// (PID, IP, Port, Process name, State, or family label).
+pub fn filter_connections(
+    items: &[SocketConnection],
+    query: &str,
+    family: Option<AddressFamily>,
+) -> Vec<SocketConnection> {
+    let q = query.trim().to_lowercase();
     items
         .iter()
+        .filter(|c| family.is_none_or(|family| c.family == family))
         .filter(|c| {
-            c.protocol.to_lowercase().contains(&q)
-                || c.local_addr.to_lowercase().contains(&q)
-                || c.remote_addr.to_lowercase().contains(&q)
+            q.is_empty()
+                || c.protocol.to_lowercase().contains(&q)
+                || c.family.label().to_lowercase().contains(&q)
+                || c.local_display().to_lowercase().contains(&q)
+                || c.remote_display().to_lowercase().contains(&q)
                 || c.state.to_lowercase().contains(&q)
                 || c.pid.to_string().contains(&q)
                 || c.process_name
@@ -193,30 +863,61 @@ pub fn filter_connections(items: &[SocketConnection], query: &str) -> Vec<Socket
 mod tests {
     use super::*;
 
+    fn connection(protocol: &'static str, local: &str, remote: Option<&str>, pid: u32, name: &str) -> SocketConnection {
+        let local_addr: SocketAddr = local.parse().unwrap();
+        SocketConnection {
+            protocol,
+            family: AddressFamily::of(&local_addr),
+            local_addr,
+            remote_addr: remote.map(|remote| remote.parse().unwrap()),
+            state: "LISTEN",
+            pid,
+            process_name: Some(name.into()),
+        }
+    }
+
     #[test]
     fn test_filter_connections() {
         let items = vec![
-            SocketConnection {
-                protocol: "TCP",
-                local_addr: "127.0.0.1:8080".into(),
-                remote_addr: "0.0.0.0:0".into(),
-                state: "LISTEN",
-                pid: 1234,
-                process_name: Some("server.exe".into()),
-            },
-            SocketConnection {
-                protocol: "UDP",
-                local_addr: "0.0.0.0:53".into(),
-                remote_addr: "*:*".into(),
-                state: "LISTEN",
-                pid: 5678,
-                process_name: Some("dns.exe".into()),
-            },
+            connection("TCP", "127.0.0.1:8080", None, 1234, "server.exe"),
+            connection("UDP", "0.0.0.0:53", None, 5678, "dns.exe"),
+            connection("TCP", "[fe80::1%3]:22", Some("[2001:db8::7]:51000"), 42, "sshd"),
         ];
 
-        assert_eq!(filter_connections(&items, "8080").len(), 1);
-        assert_eq!(filter_connections(&items, "server").len(), 1);
-        assert_eq!(filter_connections(&items, "UDP").len(), 1);
-        assert_eq!(filter_connections(&items, "").len(), 2);
+        assert_eq!(filter_connections(&items, "8080", None).len(), 1);
+        assert_eq!(filter_connections(&items, "server", None).len(), 1);
+        assert_eq!(filter_connections(&items, "UDP", None).len(), 1);
+        assert_eq!(filter_connections(&items, "", None).len(), 3);
+        assert_eq!(filter_connections(&items, "2001:db8", None).len(), 1);
+        assert_eq!(filter_connections(&items, "ipv6", None).len(), 1);
+        assert_eq!(filter_connections(&items, "", Some(AddressFamily::Ipv4)).len(), 2);
+        assert_eq!(filter_connections(&items, "tcp", Some(AddressFamily::Ipv6)).len(), 1);
+    }
+
+    #[test]
+    fn interface_kind_guessed_from_adapter_names() {
+        assert_eq!(InterfaceKind::from_name("Wi-Fi"), InterfaceKind::Wifi);
+        assert_eq!(InterfaceKind::from_name("Ethernet 2"), InterfaceKind::Ethernet);
+        assert_eq!(InterfaceKind::from_name("vEthernet (WSL)"), InterfaceKind::Virtual);
+        assert_eq!(
+            InterfaceKind::from_name("Loopback Pseudo-Interface 1"),
+            InterfaceKind::Loopback
+        );
+        assert_eq!(InterfaceKind::from_name("en0"), InterfaceKind::Ethernet);
+        assert_eq!(InterfaceKind::from_name("Bluetooth Network"), InterfaceKind::Unknown);
+    }
+
+    #[test]
+    fn ipv6_endpoints_render_bracketed() {
+        let items = [connection(
+            "TCP",
+            "[fe80::1%3]:22",
+            Some("[2001:db8::7]:51000"),
+            42,
+            "sshd",
+        )];
+        assert_eq!(items[0].local_display(), "[fe80::1%3]:22");
+        assert_eq!(items[0].remote_display(), "[2001:db8::7]:51000");
+        assert_eq!(items[0].family.label(), "IPv6");
     }
 }
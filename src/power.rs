@@ -1,16 +1,23 @@
+#[cfg(target_os = "windows")]
 use std::ptr;
+#[cfg(target_os = "windows")]
 use windows_sys::Win32::Foundation::{LocalFree, ERROR_NO_MORE_ITEMS, ERROR_SUCCESS};
+#[cfg(target_os = "windows")]
 use windows_sys::Win32::System::Power::{
     PowerEnumerate, PowerGetActiveScheme, PowerReadFriendlyName, PowerSetActiveScheme, ACCESS_SCHEME,
 };
 
 /// Root registry page under which power schemes live.
+#[cfg(target_os = "windows")]
 const GUID_POWER_SCHEME_PAGE: windows_sys::core::GUID =
     windows_sys::core::GUID::from_u128(0xa1841308_3541_4fab_bc81_f71556f20b4a);
 /// Subgroup containing the power schemes themselves.
+#[cfg(target_os = "windows")]
 const SUB_GUID_POWERSCHEME: windows_sys::core::GUID =
     windows_sys::core::GUID::from_u128(0xe73a048d_bf26_4f12_9b60_c51e967cb42f);
 
+/// A selectable power plan. `guid` is what `set_active_power_plan` takes: a
+/// scheme GUID on Windows, `<backend>:<profile>` on Linux.
 #[derive(Debug, Clone)]
 pub struct PowerPlan {
     pub guid: String,
@@ -21,6 +28,7 @@ pub struct PowerPlan {
 /// Parse a GUID string like `{381b4222-f694-41f0-9685-ff5bb260df2e}` (braces and
 /// hyphens optional) into a `windows_sys::core::GUID`. Falls back to the nil
 /// GUID on malformed input.
+#[cfg(target_os = "windows")]
 fn parse_guid(s: &str) -> Result<windows_sys::core::GUID, String> {
     let hex: String = s
         .trim()
@@ -38,6 +46,7 @@ fn parse_guid(s: &str) -> Result<windows_sys::core::GUID, String> {
 }
 
 /// Format a `windows_sys::core::GUID` as a canonical `{xxxxxxxx-xxxx-...}` string.
+#[cfg(target_os = "windows")]
 fn format_guid(g: &windows_sys::core::GUID) -> String {
     format!(
         "{{{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}}}",
@@ -56,6 +65,7 @@ fn format_guid(g: &windows_sys::core::GUID) -> String {
 }
 
 /// Normalize a GUID string for equality comparison (lowercase, braces/hyphens stripped).
+#[cfg(target_os = "windows")]
 fn normalized(s: &str) -> String {
     s.trim()
         .trim_start_matches('{')
@@ -66,6 +76,7 @@ fn normalized(s: &str) -> String {
         .to_lowercase()
 }
 
+#[cfg(target_os = "windows")]
 pub fn get_power_plans() -> Vec<PowerPlan> {
     let mut plans = Vec::new();
     let mut active_guid: Option<String> = None;
@@ -162,6 +173,7 @@ pub fn get_power_plans() -> Vec<PowerPlan> {
     plans
 }
 
+#[cfg(target_os = "windows")]
 pub fn set_active_power_plan(guid: &str) -> Result<(), String> {
     // SAFETY: PowerSetActiveScheme is called with a valid GUID reference.
     unsafe {
@@ -175,6 +187,207 @@ pub fn set_active_power_plan(guid: &str) -> Result<(), String> {
     }
 }
 
+/// cpufreq policies live here; tests point the sysfs backend at a fixture tree.
+#[cfg(target_os = "linux")]
+const CPU_SYSFS_ROOT: &str = "/sys/devices/system/cpu";
+
+/// Linux profile ids are `<backend>:<name>`: `ppd` for power-profiles-daemon,
+/// `epp` for `energy_performance_preference`, `governor` for cpufreq governors.
+#[cfg(target_os = "linux")]
+pub fn get_power_plans() -> Vec<PowerPlan> {
+    if let Ok(output) = powerprofilesctl(&["list"]) {
+        let plans = parse_ppd_profiles(&output);
+        if !plans.is_empty() {
+            return plans;
+        }
+    }
+    cpufreq_profiles(std::path::Path::new(CPU_SYSFS_ROOT))
+}
+
+#[cfg(target_os = "linux")]
+pub fn set_active_power_plan(guid: &str) -> Result<(), String> {
+    match guid.split_once(':') {
+        Some(("ppd", profile)) => {
+            if !get_power_plans().iter().any(|plan| plan.guid == guid) {
+                return Err(format!(
+                    "Power profile {profile} is not offered by power-profiles-daemon"
+                ));
+            }
+            powerprofilesctl(&["set", profile]).map(|_| ())
+        }
+        Some(("epp" | "governor", _)) => set_cpufreq_profile(std::path::Path::new(CPU_SYSFS_ROOT), guid),
+        _ => Err(format!("Unknown power profile: {guid}")),
+    }
+}
+
+/// Whether switching to `guid` writes root-owned state rather than asking a daemon.
+pub fn plan_requires_admin(guid: &str) -> bool {
+    guid.starts_with("epp:") || guid.starts_with("governor:")
+}
+
+#[cfg(target_os = "linux")]
+fn powerprofilesctl(args: &[&str]) -> Result<String, String> {
+    let output = std::process::Command::new("powerprofilesctl")
+        .args(args)
+        .output()
+        .map_err(|error| format!("could not run powerprofilesctl: {error}"))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// "power-saver" → "Power Saver", "balance_performance" → "Balance Performance".
+#[cfg(target_os = "linux")]
+fn profile_label(name: &str) -> String {
+    name.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse `powerprofilesctl list`: one unindented `name:` header per profile,
+/// prefixed with `* ` for the active one, followed by indented details.
+#[cfg(target_os = "linux")]
+fn parse_ppd_profiles(output: &str) -> Vec<PowerPlan> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (is_active, rest) = match line.strip_prefix("* ") {
+                Some(rest) => (true, rest),
+                None => (false, line.strip_prefix("  ")?),
+            };
+            let profile = rest.strip_suffix(':')?;
+            if profile.is_empty() || profile.starts_with(char::is_whitespace) || profile.contains(' ') {
+                return None;
+            }
+            Some(PowerPlan {
+                guid: format!("ppd:{profile}"),
+                name: profile_label(profile),
+                is_active,
+            })
+        })
+        .collect()
+}
+
+/// cpufreq policy directories, falling back to per-CPU directories on kernels
+/// without `cpufreq/policyN`.
+#[cfg(target_os = "linux")]
+fn cpufreq_policies(cpu_root: &std::path::Path) -> Vec<std::path::PathBuf> {
+    use crate::providers::sysfs::numbered_entries;
+
+    let policies = numbered_entries(&cpu_root.join("cpufreq"), "policy");
+    if !policies.is_empty() {
+        return policies;
+    }
+    numbered_entries(cpu_root, "cpu")
+        .into_iter()
+        .map(|cpu| cpu.join("cpufreq"))
+        .filter(|dir| dir.is_dir())
+        .collect()
+}
+
+/// The attribute pair a sysfs backend switches: EPP when the driver offers it,
+/// otherwise the scaling governor.
+#[cfg(target_os = "linux")]
+fn cpufreq_backend(policy: &std::path::Path) -> Option<(&'static str, &'static str, &'static str)> {
+    if policy.join("energy_performance_available_preferences").is_file() {
+        Some((
+            "epp",
+            "energy_performance_available_preferences",
+            "energy_performance_preference",
+        ))
+    } else if policy.join("scaling_available_governors").is_file() {
+        Some(("governor", "scaling_available_governors", "scaling_governor"))
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn cpufreq_profiles(cpu_root: &std::path::Path) -> Vec<PowerPlan> {
+    use crate::providers::sysfs::read_trimmed;
+
+    let policies = cpufreq_policies(cpu_root);
+    let Some(policy) = policies.first() else {
+        return Vec::new();
+    };
+    let Some((backend, available, current)) = cpufreq_backend(policy) else {
+        return Vec::new();
+    };
+    let active = read_trimmed(&policy.join(current)).unwrap_or_default();
+    read_trimmed(&policy.join(available))
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|profile| PowerPlan {
+            guid: format!("{backend}:{profile}"),
+            name: if backend == "governor" {
+                format!("{} Governor", profile_label(profile))
+            } else {
+                profile_label(profile)
+            },
+            is_active: profile == active,
+        })
+        .collect()
+}
+
+/// Write the profile to every policy, rolling back the ones already changed if
+/// a later write fails so CPUs are not left on mixed settings.
+#[cfg(target_os = "linux")]
+fn set_cpufreq_profile(cpu_root: &std::path::Path, guid: &str) -> Result<(), String> {
+    use crate::providers::sysfs::read_trimmed;
+
+    let (backend, profile) = guid
+        .split_once(':')
+        .ok_or_else(|| format!("Unknown power profile: {guid}"))?;
+    if !cpufreq_profiles(cpu_root).iter().any(|plan| plan.guid == guid) {
+        return Err(format!("Power profile {profile} is not offered by this CPU"));
+    }
+
+    let mut changed: Vec<(std::path::PathBuf, String)> = Vec::new();
+    for policy in cpufreq_policies(cpu_root) {
+        let Some((policy_backend, _, current)) = cpufreq_backend(&policy) else {
+            continue;
+        };
+        if policy_backend != backend {
+            continue;
+        }
+        let path = policy.join(current);
+        let previous = read_trimmed(&path).unwrap_or_default();
+        if let Err(error) = std::fs::write(&path, profile) {
+            for (path, previous) in changed.iter().rev() {
+                let _ = std::fs::write(path, previous);
+            }
+            return Err(match error.kind() {
+                std::io::ErrorKind::PermissionDenied => {
+                    format!("Switching to {profile} requires administrator rights")
+                }
+                _ => format!("Could not write {}: {error}", path.display()),
+            });
+        }
+        changed.push((path, previous));
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn get_power_plans() -> Vec<PowerPlan> {
+    Vec::new()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+pub fn set_active_power_plan(_guid: &str) -> Result<(), String> {
+    Err("Power plans are not supported on this platform".into())
+}
+
 #[derive(Debug, Clone, serde::Serialize, PartialEq)]
 pub struct BatteryHealth {
     pub has_battery: bool,
@@ -204,6 +417,13 @@ impl BatteryHealth {
     }
 }
 
+/// Remaining capacity as a percentage of the design capacity. Either figure may
+/// be in any unit as long as both use the same one.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn health_percent(full: u64, design: u64) -> Option<f32> {
+    (full > 0 && design > 0).then(|| (full as f64 / design as f64 * 100.0) as f32)
+}
+
 /// Queries system power and battery status natively on Windows.
 #[cfg(target_os = "windows")]
 pub fn get_battery_health() -> BatteryHealth {
@@ -221,6 +441,11 @@ pub fn get_battery_health() -> BatteryHealth {
             let is_charging = (sps.BatteryFlag & 8) != 0;
             let ac_online = sps.ACLineStatus == 1;
             let battery_saver = sps.SystemStatusFlag == 1;
+            let capacity = if has_battery {
+                battery_capacity_wmi()
+            } else {
+                BatteryCapacity::default()
+            };
 
             BatteryHealth {
                 has_battery,
@@ -228,10 +453,13 @@ pub fn get_battery_health() -> BatteryHealth {
                 is_charging,
                 ac_online,
                 battery_saver,
-                full_charge_mwh: None,
-                design_capacity_mwh: None,
-                health_percent: if has_battery { Some(100.0) } else { None },
-                cycle_count: None,
+                full_charge_mwh: capacity.full_charge_mwh,
+                design_capacity_mwh: capacity.design_capacity_mwh,
+                health_percent: capacity
+                    .full_charge_mwh
+                    .zip(capacity.design_capacity_mwh)
+                    .and_then(|(full, design)| health_percent(full, design)),
+                cycle_count: capacity.cycle_count,
             }
         } else {
             BatteryHealth::empty()
@@ -239,7 +467,190 @@ pub fn get_battery_health() -> BatteryHealth {
     }
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(target_os = "windows")]
+#[derive(Default)]
+struct BatteryCapacity {
+    full_charge_mwh: Option<u64>,
+    design_capacity_mwh: Option<u64>,
+    cycle_count: Option<u32>,
+}
+
+/// `GetSystemPowerStatus` has no capacity data; the battery miniport exposes it
+/// through the `ROOT\WMI` classes instead (values are summed across batteries).
+#[cfg(target_os = "windows")]
+fn battery_capacity_wmi() -> BatteryCapacity {
+    use std::collections::HashMap;
+    use wmi::Variant;
+
+    let Some(wmi_con) = crate::providers::init_com()
+        .ok()
+        .and_then(|com| wmi::WMIConnection::with_namespace_path("ROOT\\WMI", std::rc::Rc::new(com)).ok())
+    else {
+        return BatteryCapacity::default();
+    };
+    let sum = |query: &str, field: &str| -> Option<u64> {
+        let rows: Vec<HashMap<String, Variant>> = wmi_con.raw_query(query).ok()?;
+        let values: Vec<u64> = rows
+            .iter()
+            .filter_map(|row| match row.get(field)? {
+                Variant::UI4(value) => Some(u64::from(*value)),
+                Variant::I4(value) => u64::try_from(*value).ok(),
+                _ => None,
+            })
+            .filter(|value| *value > 0)
+            .collect();
+        (!values.is_empty()).then(|| values.iter().sum())
+    };
+
+    BatteryCapacity {
+        full_charge_mwh: sum(
+            "SELECT FullChargedCapacity FROM BatteryFullChargedCapacity",
+            "FullChargedCapacity",
+        ),
+        design_capacity_mwh: sum("SELECT DesignedCapacity FROM BatteryStaticData", "DesignedCapacity"),
+        cycle_count: sum("SELECT CycleCount FROM BatteryCycleCount", "CycleCount")
+            .and_then(|count| u32::try_from(count).ok()),
+    }
+}
+
+/// Where the kernel lists batteries and AC adapters.
+#[cfg(target_os = "linux")]
+const POWER_SUPPLY_ROOT: &str = "/sys/class/power_supply";
+
+#[cfg(target_os = "linux")]
+pub fn get_battery_health() -> BatteryHealth {
+    battery_health_from_sysfs(std::path::Path::new(POWER_SUPPLY_ROOT))
+}
+
+/// One system battery from `power_supply`, with capacities normalized to mWh.
+#[cfg(target_os = "linux")]
+struct SupplyBattery {
+    status: String,
+    capacity_percent: Option<f32>,
+    now_mwh: Option<u64>,
+    full_mwh: Option<u64>,
+    design_mwh: Option<u64>,
+    health_percent: Option<f32>,
+    cycle_count: Option<u32>,
+}
+
+#[cfg(target_os = "linux")]
+fn read_supply_battery(dir: &std::path::Path) -> Option<SupplyBattery> {
+    use crate::providers::sysfs::{read_trimmed, read_u64};
+
+    // Peripheral batteries (mice, headsets) report scope "Device".
+    if read_trimmed(&dir.join("scope")).is_some_and(|scope| scope == "Device")
+        || read_u64(&dir.join("present")) == Some(0)
+    {
+        return None;
+    }
+
+    // Gauges report either energy (µWh) or charge (µAh); charge needs a voltage to become energy.
+    let energy = dir.join("energy_full").exists();
+    let (family, microvolts) = if energy {
+        ("energy", None)
+    } else {
+        let microvolts = read_u64(&dir.join("voltage_min_design"))
+            .or_else(|| read_u64(&dir.join("voltage_now")))
+            .filter(|uv| *uv > 0);
+        ("charge", microvolts)
+    };
+    let to_mwh = |raw: u64| -> Option<u64> {
+        if energy {
+            Some(raw / 1_000)
+        } else {
+            microvolts.map(|uv| (u128::from(raw) * u128::from(uv) / 1_000_000_000) as u64)
+        }
+    };
+    let raw = |name: &str| read_u64(&dir.join(format!("{family}_{name}")));
+    let (now, full, design) = (raw("now"), raw("full"), raw("full_design"));
+
+    Some(SupplyBattery {
+        status: read_trimmed(&dir.join("status")).unwrap_or_default(),
+        capacity_percent: read_u64(&dir.join("capacity")).map(|percent| percent.min(100) as f32),
+        now_mwh: now.and_then(to_mwh),
+        full_mwh: full.and_then(to_mwh),
+        design_mwh: design.and_then(to_mwh),
+        health_percent: full.zip(design).and_then(|(full, design)| health_percent(full, design)),
+        cycle_count: read_u64(&dir.join("cycle_count"))
+            .filter(|count| *count > 0)
+            .and_then(|count| u32::try_from(count).ok()),
+    })
+}
+
+/// Build the battery summary from a `power_supply` class directory. Multiple
+/// system batteries are combined into one pack, as the Windows status API does.
+#[cfg(target_os = "linux")]
+fn battery_health_from_sysfs(root: &std::path::Path) -> BatteryHealth {
+    use crate::providers::sysfs::{read_trimmed, read_u64};
+
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return BatteryHealth::empty();
+    };
+    let mut supplies: Vec<std::path::PathBuf> = entries.filter_map(Result::ok).map(|entry| entry.path()).collect();
+    supplies.sort();
+
+    let mut batteries = Vec::new();
+    let mut adapters_online = Vec::new();
+    for dir in &supplies {
+        match read_trimmed(&dir.join("type")).as_deref() {
+            Some("Battery") => batteries.extend(read_supply_battery(dir)),
+            Some("Mains" | "USB" | "USB_C" | "USB_PD") => {
+                adapters_online.push(read_u64(&dir.join("online")) == Some(1));
+            }
+            _ => {}
+        }
+    }
+    if batteries.is_empty() {
+        return BatteryHealth {
+            ac_online: adapters_online.is_empty() || adapters_online.contains(&true),
+            ..BatteryHealth::empty()
+        };
+    }
+
+    let total = |field: fn(&SupplyBattery) -> Option<u64>| -> Option<u64> { batteries.iter().map(field).sum() };
+    let (now_mwh, full_mwh, design_mwh) = (
+        total(|battery| battery.now_mwh),
+        total(|battery| battery.full_mwh),
+        total(|battery| battery.design_mwh),
+    );
+    let percentage = match (now_mwh, full_mwh) {
+        (Some(now), Some(full)) if full > 0 => (now as f64 / full as f64 * 100.0).min(100.0) as f32,
+        _ => {
+            let readings: Vec<f32> = batteries
+                .iter()
+                .filter_map(|battery| battery.capacity_percent)
+                .collect();
+            if readings.is_empty() {
+                0.0
+            } else {
+                readings.iter().sum::<f32>() / readings.len() as f32
+            }
+        }
+    };
+    let discharging = batteries.iter().any(|battery| battery.status == "Discharging");
+
+    BatteryHealth {
+        has_battery: true,
+        percentage,
+        is_charging: batteries.iter().any(|battery| battery.status == "Charging"),
+        ac_online: if adapters_online.is_empty() {
+            !discharging
+        } else {
+            adapters_online.contains(&true)
+        },
+        battery_saver: false,
+        full_charge_mwh: full_mwh,
+        design_capacity_mwh: design_mwh,
+        health_percent: full_mwh
+            .zip(design_mwh)
+            .and_then(|(full, design)| health_percent(full, design))
+            .or_else(|| batteries.iter().find_map(|battery| battery.health_percent)),
+        cycle_count: batteries.iter().filter_map(|battery| battery.cycle_count).max(),
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn get_battery_health() -> BatteryHealth {
     BatteryHealth::empty()
 }
@@ -261,12 +672,14 @@ pub fn activate_power_mode(mode: &str) -> Result<String, String> {
 mod tests {
     use super::*;
 
+    #[cfg(target_os = "windows")]
     #[test]
     fn rejects_malformed_guid() {
         assert!(parse_guid("not-a-guid").is_err());
         assert!(parse_guid("{00000000-0000-0000-0000-00000000000z}").is_err());
     }
 
+    #[cfg(target_os = "windows")]
     #[test]
     fn parses_canonical_guid() {
         assert_eq!(
@@ -284,6 +697,178 @@ mod tests {
         assert!(!empty.is_charging);
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sysfs_energy_battery_reports_health_and_cycles() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "power-energy",
+            &[
+                ("BAT0/type", "Battery"),
+                ("BAT0/present", "1"),
+                ("BAT0/status", "Discharging"),
+                ("BAT0/capacity", "49"),
+                ("BAT0/energy_now", "20000000"),
+                ("BAT0/energy_full", "40000000"),
+                ("BAT0/energy_full_design", "50000000"),
+                ("BAT0/cycle_count", "312"),
+                ("AC/type", "Mains"),
+                ("AC/online", "0"),
+                ("hid-mouse-battery/type", "Battery"),
+                ("hid-mouse-battery/scope", "Device"),
+                ("hid-mouse-battery/capacity", "5"),
+            ],
+        );
+        let health = battery_health_from_sysfs(&root);
+        assert!(health.has_battery);
+        assert!(!health.ac_online);
+        assert!(!health.is_charging);
+        assert_eq!(health.percentage, 50.0);
+        assert_eq!(health.full_charge_mwh, Some(40_000));
+        assert_eq!(health.design_capacity_mwh, Some(50_000));
+        assert_eq!(health.health_percent, Some(80.0));
+        assert_eq!(health.cycle_count, Some(312));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sysfs_charge_battery_converts_with_design_voltage() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "power-charge",
+            &[
+                ("BAT1/type", "Battery"),
+                ("BAT1/status", "Charging"),
+                ("BAT1/capacity", "75"),
+                ("BAT1/charge_now", "3000000"),
+                ("BAT1/charge_full", "4000000"),
+                ("BAT1/charge_full_design", "5000000"),
+                ("BAT1/voltage_min_design", "11400000"),
+                ("BAT1/cycle_count", "0"),
+                ("ucsi-source-psy-USBC000:001/type", "USB"),
+                ("ucsi-source-psy-USBC000:001/online", "1"),
+            ],
+        );
+        let health = battery_health_from_sysfs(&root);
+        assert!(health.is_charging);
+        assert!(health.ac_online);
+        assert_eq!(health.full_charge_mwh, Some(45_600));
+        assert_eq!(health.design_capacity_mwh, Some(57_000));
+        assert_eq!(health.health_percent, Some(80.0));
+        assert_eq!(health.percentage, 75.0);
+        assert_eq!(health.cycle_count, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sysfs_charge_battery_without_voltage_keeps_health_ratio() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "power-novoltage",
+            &[
+                ("BAT0/type", "Battery"),
+                ("BAT0/status", "Full"),
+                ("BAT0/capacity", "100"),
+                ("BAT0/charge_full", "2700000"),
+                ("BAT0/charge_full_design", "3000000"),
+            ],
+        );
+        let health = battery_health_from_sysfs(&root);
+        assert_eq!(health.full_charge_mwh, None);
+        assert_eq!(health.health_percent, Some(90.0));
+        assert!(health.ac_online);
+        assert_eq!(health.percentage, 100.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sysfs_without_batteries_is_a_desktop() {
+        let root =
+            crate::providers::sysfs::TempTree::with_files("power-desktop", &[("AC/type", "Mains"), ("AC/online", "1")]);
+        assert_eq!(battery_health_from_sysfs(&root), BatteryHealth::empty());
+        assert_eq!(battery_health_from_sysfs(&root.join("absent")), BatteryHealth::empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn ppd_list_marks_the_active_profile() {
+        let output = "  performance:\n    CpuDriver:  intel_pstate\n    Degraded:   no\n\n* balanced:\n    CpuDriver:  intel_pstate\n    PlatformDriver: platform_profile\n\n  power-saver:\n    CpuDriver:  intel_pstate\n";
+        let plans = parse_ppd_profiles(output);
+        let summary: Vec<(&str, &str, bool)> = plans
+            .iter()
+            .map(|plan| (plan.guid.as_str(), plan.name.as_str(), plan.is_active))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("ppd:performance", "Performance", false),
+                ("ppd:balanced", "Balanced", true),
+                ("ppd:power-saver", "Power Saver", false),
+            ]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn cpufreq_profiles_prefer_epp_and_switch_every_policy() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "power-cpufreq",
+            &[
+                ("cpufreq/policy0/scaling_available_governors", "performance powersave"),
+                ("cpufreq/policy0/scaling_governor", "powersave"),
+                (
+                    "cpufreq/policy0/energy_performance_available_preferences",
+                    "default performance balance_power power",
+                ),
+                ("cpufreq/policy0/energy_performance_preference", "balance_power"),
+                ("cpufreq/policy1/scaling_available_governors", "performance powersave"),
+                ("cpufreq/policy1/scaling_governor", "powersave"),
+                (
+                    "cpufreq/policy1/energy_performance_available_preferences",
+                    "default performance balance_power power",
+                ),
+                ("cpufreq/policy1/energy_performance_preference", "balance_power"),
+            ],
+        );
+        let plans = cpufreq_profiles(&root);
+        assert_eq!(plans.len(), 4);
+        assert!(plans
+            .iter()
+            .any(|plan| plan.guid == "epp:balance_power" && plan.is_active));
+        assert_eq!(plans[2].name, "Balance Power");
+        assert!(plan_requires_admin("epp:performance"));
+
+        set_cpufreq_profile(&root, "epp:performance").unwrap();
+        for policy in ["policy0", "policy1"] {
+            let written =
+                std::fs::read_to_string(root.join("cpufreq").join(policy).join("energy_performance_preference"));
+            assert_eq!(written.unwrap(), "performance");
+        }
+        assert!(set_cpufreq_profile(&root, "epp:turbo").is_err());
+        assert!(set_cpufreq_profile(&root, "governor:powersave").is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn cpufreq_profiles_fall_back_to_governors_per_cpu() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "power-governor",
+            &[
+                (
+                    "cpu0/cpufreq/scaling_available_governors",
+                    "conservative ondemand userspace powersave performance schedutil",
+                ),
+                ("cpu0/cpufreq/scaling_governor", "schedutil"),
+            ],
+        );
+        let plans = cpufreq_profiles(&root);
+        assert_eq!(plans.len(), 6);
+        let active: Vec<&str> = plans
+            .iter()
+            .filter(|plan| plan.is_active)
+            .map(|plan| plan.name.as_str())
+            .collect();
+        assert_eq!(active, vec!["Schedutil Governor"]);
+        assert!(!plan_requires_admin("ppd:balanced"));
+    }
+
     #[test]
     fn get_battery_health_does_not_panic() {
         let health = get_battery_health();
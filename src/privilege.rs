@@ -48,11 +48,18 @@ pub fn relaunch_as_admin() -> bool {
     if let Ok(path) = std::env::current_exe() {
         let path_w: Vec<u16> = path.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
         let verb: Vec<u16> = OsStr::new("runas").encode_wide().chain(std::iter::once(0)).collect();
+        // This copy is still closing when the elevated one starts, so tell the new
+        // copy to wait for the single-instance lock instead of handing off to us.
+        let parameters: Vec<u16> = OsStr::new(crate::instance::REPLACE_FLAG)
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect();
 
         let mut info = SHELLEXECUTEINFOW {
             cbSize: std::mem::size_of::<SHELLEXECUTEINFOW>() as u32,
             lpVerb: windows::core::PCWSTR(verb.as_ptr()),
             lpFile: windows::core::PCWSTR(path_w.as_ptr()),
+            lpParameters: windows::core::PCWSTR(parameters.as_ptr()),
             nShow: SW_SHOW.0,
             ..Default::default()
         };
@@ -0,0 +1,185 @@
+//! Linux process control syscalls. Errors are returned as `io::Error` and
+//! converted into action errors by the caller.
+
+use std::io;
+
+/// Convert a PID into a `pid_t`, refusing values the kernel would read as a
+/// process group or as "the calling process".
+pub(crate) fn target_pid(pid: u32) -> io::Result<libc::pid_t> {
+    if pid == std::process::id() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "refusing to act on SysMon itself",
+        ));
+    }
+    libc::pid_t::try_from(pid)
+        .ok()
+        .filter(|pid| *pid > 0)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("{pid} is not a valid process ID")))
+}
+
+/// Send a signal to a whole process (SIGSTOP/SIGCONT for suspend and resume).
+pub(crate) fn signal_process(pid: u32, signal: libc::c_int) -> io::Result<()> {
+    let pid = target_pid(pid)?;
+    // SAFETY: kill takes plain integers; `pid` is positive so it addresses exactly one process.
+    if unsafe { libc::kill(pid, signal) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Set the CPU affinity mask of every thread of a process.
+pub(crate) fn set_affinity(pid: u32, mask: usize) -> io::Result<()> {
+    if mask == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "affinity mask must include at least one CPU",
+        ));
+    }
+    // SAFETY: cpu_set_t is a plain bitset for which all-zeroes is the empty set.
+    let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+    for cpu in (0..usize::BITS as usize).filter(|cpu| mask & (1 << cpu) != 0) {
+        unsafe { libc::CPU_SET(cpu, &mut set) };
+    }
+    for_each_thread(pid, |tid| {
+        // SAFETY: `set` outlives the call and the size passed matches its type.
+        unsafe { libc::sched_setaffinity(tid, std::mem::size_of::<libc::cpu_set_t>(), &set) }
+    })
+    .map_err(|error| match error.kind() {
+        // EINVAL here means none of the requested CPUs are online or permitted.
+        io::ErrorKind::InvalidInput => io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("mask {mask:#x} does not select any usable CPU"),
+        ),
+        _ => error,
+    })
+}
+
+/// Set the nice value of every thread of a process from a UI priority name.
+pub(crate) fn set_priority(pid: u32, priority: &str) -> io::Result<()> {
+    let nice = nice_for_priority(priority)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("unknown priority {priority}")))?;
+    for_each_thread(pid, |tid| {
+        // SAFETY: setpriority takes plain integers and reports failure through errno.
+        unsafe { libc::setpriority(libc::PRIO_PROCESS, tid as libc::id_t, nice) }
+    })
+}
+
+/// Apply a per-thread syscall to every thread of a process. Linux scheduling
+/// attributes (nice value, affinity) belong to threads, so targeting only the
+/// PID would leave every other thread of the process untouched.
+fn for_each_thread(pid: u32, mut apply: impl FnMut(libc::pid_t) -> libc::c_int) -> io::Result<()> {
+    let pid = target_pid(pid)?;
+    let tasks = std::fs::read_dir(format!("/proc/{pid}/task"))?;
+    let tids = tasks.filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<libc::pid_t>().ok());
+    apply_to_threads(tids, |tid| {
+        if apply(tid) == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    })
+}
+
+/// Walk `tids` in order, skipping threads that exit mid-walk. A failure after
+/// some threads were already changed is reported with the TIDs that were
+/// changed, since the process is then left with mixed settings.
+fn apply_to_threads(
+    tids: impl IntoIterator<Item = libc::pid_t>,
+    mut apply: impl FnMut(libc::pid_t) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut applied = Vec::new();
+    for tid in tids {
+        match apply(tid) {
+            Ok(()) => applied.push(tid),
+            Err(error) if error.raw_os_error() == Some(libc::ESRCH) => {}
+            Err(error) if applied.is_empty() => return Err(error),
+            Err(error) => {
+                return Err(io::Error::other(format!(
+                    "thread {tid} failed ({error}) after threads {applied:?} were already changed"
+                )))
+            }
+        }
+    }
+    if applied.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "process has no live threads"));
+    }
+    Ok(())
+}
+
+/// Map the Windows priority class names used by the UI onto nice values.
+fn nice_for_priority(priority: &str) -> Option<libc::c_int> {
+    match priority {
+        "Realtime" => Some(-20),
+        "High" => Some(-10),
+        "AboveNormal" => Some(-5),
+        "Normal" => Some(0),
+        "BelowNormal" => Some(5),
+        "Idle" => Some(19),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priority_names_map_to_nice_values() {
+        let ordered: Vec<_> = ["Realtime", "High", "AboveNormal", "Normal", "BelowNormal", "Idle"]
+            .iter()
+            .map(|name| nice_for_priority(name).unwrap())
+            .collect();
+        assert!(ordered.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(nice_for_priority("Normal"), Some(0));
+        assert_eq!(nice_for_priority("Turbo"), None);
+    }
+
+    #[test]
+    fn actions_reject_unsafe_targets() {
+        let invalid = Some(io::ErrorKind::InvalidInput);
+        assert_eq!(target_pid(0).err().map(|error| error.kind()), invalid);
+        assert_eq!(target_pid(u32::MAX).err().map(|error| error.kind()), invalid);
+        assert_eq!(target_pid(std::process::id()).err().map(|error| error.kind()), invalid);
+        assert_eq!(set_affinity(1, 0).err().map(|error| error.kind()), invalid);
+        assert_eq!(set_priority(1, "Turbo").err().map(|error| error.kind()), invalid);
+    }
+
+    #[test]
+    fn actions_report_missing_process() {
+        // PIDs above pid_max (at most 2^22) can never exist.
+        assert_eq!(set_affinity(1 << 23, 1).unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(
+            signal_process(1 << 23, libc::SIGCONT).unwrap_err().raw_os_error(),
+            Some(libc::ESRCH)
+        );
+    }
+
+    #[test]
+    fn partial_thread_failures_name_the_changed_threads() {
+        let fail_on = |bad: libc::pid_t, errno: i32| {
+            move |tid| {
+                if tid == bad {
+                    Err(io::Error::from_raw_os_error(errno))
+                } else {
+                    Ok(())
+                }
+            }
+        };
+
+        let error = apply_to_threads([10, 11, 12], fail_on(12, libc::EPERM)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Other);
+        assert!(error.to_string().contains("thread 12"));
+        assert!(error.to_string().contains("[10, 11]"));
+
+        // Nothing changed yet: the original error comes back untouched.
+        let error = apply_to_threads([10, 11], fail_on(10, libc::EPERM)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+        // Threads that exit mid-walk are skipped, but a process with none left is missing.
+        assert!(apply_to_threads([10, 11], fail_on(11, libc::ESRCH)).is_ok());
+        let error = apply_to_threads([10], fail_on(10, libc::ESRCH)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
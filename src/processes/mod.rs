@@ -4,6 +4,9 @@ use serde::Serialize;
 use std::collections::HashMap;
 use sysinfo::{Pid, System};
 
+#[cfg(target_os = "linux")]
+pub(crate) mod linux;
+
 // ─── Data Models ─────────────────────────────────────────────
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -17,6 +20,13 @@ pub struct ProcessInfo {
     pub status: String,
     pub disk_read_bytes: u64,
     pub disk_written_bytes: u64,
+    /// Busiest GPU engine as a percentage; only Linux DRM drivers report this per process.
+    pub gpu_usage: f32,
+    pub gpu_memory: u64,
+    /// cgroup v2 path such as `/system.slice/sshd.service`; Linux only.
+    pub cgroup: Option<String>,
+    /// Name of the Docker/Podman container the process runs in.
+    pub container: Option<String>,
 }
 
 #[derive(PartialEq, Clone, Copy)]
@@ -26,6 +36,7 @@ pub enum ProcessSortColumn {
     Memory,
     Cpu,
     Disk,
+    Gpu,
 }
 
 #[derive(Clone)]
@@ -39,6 +50,68 @@ pub struct ProcessDetails {
     pub parent_name: Option<String>,
 }
 
+/// Resource accounting for one cgroup v2 group. Counters cover the group's
+/// whole subtree; limits are the tightest set on the group or an ancestor.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct CgroupInfo {
+    pub path: String,
+    /// Same scale as `ProcessInfo::cpu_usage`: percent of all logical CPUs.
+    pub cpu_usage: f32,
+    /// `cpu.max` quota on the same scale as `cpu_usage`.
+    pub cpu_limit: Option<f32>,
+    pub memory_current: u64,
+    pub memory_max: Option<u64>,
+    pub io_read_rate: f64,
+    pub io_write_rate: f64,
+    pub pids_current: u64,
+    pub pids_max: Option<u64>,
+}
+
+impl CgroupInfo {
+    /// Last path segment, or `/` for the root group.
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').find(|segment| !segment.is_empty()).unwrap_or("/")
+    }
+
+    fn parent_path(&self) -> Option<&str> {
+        if self.path == "/" {
+            return None;
+        }
+        match self.path.rfind('/') {
+            Some(0) => Some("/"),
+            Some(index) => Some(&self.path[..index]),
+            None => None,
+        }
+    }
+
+    /// Highest share of any limit in use (CPU quota, memory or pids), in percent.
+    pub fn limit_utilization(&self) -> Option<f32> {
+        let ratio = |used: f64, limit: f64| (limit > 0.0).then(|| (used / limit * 100.0) as f32);
+        [
+            self.cpu_limit
+                .and_then(|limit| ratio(self.cpu_usage as f64, limit as f64)),
+            self.memory_max
+                .and_then(|limit| ratio(self.memory_current as f64, limit as f64)),
+            self.pids_max
+                .and_then(|limit| ratio(self.pids_current as f64, limit as f64)),
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(f32::max)
+    }
+}
+
+/// One row of the grouped process view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CgroupRow<'a> {
+    pub cgroup: &'a CgroupInfo,
+    pub depth: usize,
+    /// Processes that live directly in this group.
+    pub processes: Vec<&'a ProcessInfo>,
+    /// Processes in this group and every group below it.
+    pub subtree_processes: usize,
+}
+
 // ─── Pure Logic ──────────────────────────────────────────────
 
 /// Case-insensitive substring filter on name or PID. Empty query returns all.
@@ -77,6 +150,12 @@ pub fn sort_processes_refs(items: &mut [&ProcessInfo], column: ProcessSortColumn
             (a.disk_read_bytes + a.disk_written_bytes).cmp(&(b.disk_read_bytes + b.disk_written_bytes)),
             ascending,
         ),
+        ProcessSortColumn::Gpu => ord(
+            a.gpu_usage
+                .total_cmp(&b.gpu_usage)
+                .then(a.gpu_memory.cmp(&b.gpu_memory)),
+            ascending,
+        ),
     });
 }
 /// Build pid -> [child pids] adjacency from a pid -> parent_pid map.
@@ -249,6 +328,79 @@ pub fn build_tree_rows(items: &[ProcessInfo], tree: &HashMap<u32, Vec<u32>>, que
     rows
 }
 
+/// Order cgroups as an indented hierarchy, keeping only groups that contain at
+/// least one of `processes` somewhere in their subtree. Siblings are sorted by
+/// `column`; columns without a cgroup equivalent fall back to memory.
+pub fn build_cgroup_rows<'a>(
+    cgroups: &'a [CgroupInfo],
+    processes: &[&'a ProcessInfo],
+    column: ProcessSortColumn,
+    ascending: bool,
+) -> Vec<CgroupRow<'a>> {
+    let by_path: HashMap<&str, &CgroupInfo> = cgroups.iter().map(|group| (group.path.as_str(), group)).collect();
+    let mut members: HashMap<&str, Vec<&ProcessInfo>> = HashMap::new();
+    let mut subtree: HashMap<&str, usize> = HashMap::new();
+    for &process in processes {
+        let Some(group) = process.cgroup.as_deref().and_then(|path| by_path.get(path)) else {
+            continue;
+        };
+        members.entry(group.path.as_str()).or_default().push(process);
+        let mut current = Some(*group);
+        while let Some(group) = current {
+            *subtree.entry(group.path.as_str()).or_default() += 1;
+            current = group.parent_path().and_then(|parent| by_path.get(parent).copied());
+        }
+    }
+
+    let mut children: HashMap<Option<&str>, Vec<&CgroupInfo>> = HashMap::new();
+    for group in cgroups.iter().filter(|group| subtree.contains_key(group.path.as_str())) {
+        let parent = group.parent_path().filter(|parent| by_path.contains_key(parent));
+        children.entry(parent).or_default().push(group);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by(|a, b| {
+            let order = match column {
+                ProcessSortColumn::Name => a.path.cmp(&b.path),
+                ProcessSortColumn::Cpu => a.cpu_usage.total_cmp(&b.cpu_usage),
+                ProcessSortColumn::Disk => {
+                    (a.io_read_rate + a.io_write_rate).total_cmp(&(b.io_read_rate + b.io_write_rate))
+                }
+                _ => a.memory_current.cmp(&b.memory_current),
+            };
+            if ascending {
+                order
+            } else {
+                order.reverse()
+            }
+        });
+    }
+
+    fn visit<'a>(
+        group: &'a CgroupInfo,
+        depth: usize,
+        children: &HashMap<Option<&str>, Vec<&'a CgroupInfo>>,
+        members: &mut HashMap<&str, Vec<&'a ProcessInfo>>,
+        subtree: &HashMap<&str, usize>,
+        rows: &mut Vec<CgroupRow<'a>>,
+    ) {
+        rows.push(CgroupRow {
+            cgroup: group,
+            depth,
+            processes: members.remove(group.path.as_str()).unwrap_or_default(),
+            subtree_processes: subtree.get(group.path.as_str()).copied().unwrap_or_default(),
+        });
+        for &child in children.get(&Some(group.path.as_str())).into_iter().flatten() {
+            visit(child, depth + 1, children, members, subtree, rows);
+        }
+    }
+
+    let mut rows = Vec::new();
+    for &root in children.get(&None).into_iter().flatten() {
+        visit(root, 0, &children, &mut members, &subtree, &mut rows);
+    }
+    rows
+}
+
 /// Set CPU core affinity mask for a process by PID on Windows.
 #[cfg(target_os = "windows")]
 pub fn set_process_affinity(pid: u32, mask: usize) -> Result<(), String> {
@@ -274,9 +426,10 @@ pub fn set_process_affinity(pid: u32, mask: usize) -> Result<(), String> {
     }
 }
 
-#[cfg(not(target_os = "windows"))]
+/// Linux affinity goes through [`linux::set_affinity`] so its errors stay structured.
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn set_process_affinity(_pid: u32, _mask: usize) -> Result<(), String> {
-    Err("Process affinity is only supported on Windows".into())
+    Err("Process affinity is only supported on Windows and Linux".into())
 }
 
 /// Look up detailed information for one PID from a sysinfo snapshot.
@@ -312,6 +465,10 @@ mod tests {
             status: status.to_string(),
             disk_read_bytes: 0,
             disk_written_bytes: 0,
+            gpu_usage: 0.0,
+            gpu_memory: 0,
+            cgroup: None,
+            container: None,
         }
     }
 
@@ -396,6 +553,20 @@ mod tests {
         assert_eq!(pids, vec![2, 1, 3]);
     }
 
+    #[test]
+    fn sort_gpu_breaks_usage_ties_by_memory() {
+        let mut p1 = p(1, "a", 0.0, 0, "Running");
+        p1.gpu_usage = 40.0;
+        let mut p2 = p(2, "b", 0.0, 0, "Running");
+        p2.gpu_memory = 512;
+        let p3 = p(3, "c", 0.0, 0, "Running");
+        let items = [p1, p2, p3];
+        let mut refs: Vec<_> = items.iter().collect();
+        sort_processes_refs(&mut refs, ProcessSortColumn::Gpu, false);
+        let pids: Vec<u32> = refs.iter().map(|x| x.pid).collect();
+        assert_eq!(pids, vec![1, 2, 3]);
+    }
+
     #[test]
     fn build_tree_maps_parents() {
         let parents: HashMap<u32, u32> = [(2, 1), (3, 1), (4, 2)].into_iter().collect();
@@ -480,4 +651,63 @@ mod tests {
             vec![1, 2, 3]
         );
     }
+
+    #[test]
+    fn cgroup_rows_nest_groups_that_hold_processes() {
+        let group = |path: &str, memory_current: u64| CgroupInfo {
+            path: path.to_string(),
+            memory_current,
+            ..Default::default()
+        };
+        let cgroups = vec![
+            group("/", 0),
+            group("/system.slice", 300),
+            group("/system.slice/sshd.service", 100),
+            group("/system.slice/docker-1a2b.scope", 200),
+            group("/user.slice", 50),
+        ];
+        let in_group = |pid: u32, path: &str| ProcessInfo {
+            cgroup: Some(path.to_string()),
+            container: None,
+            ..p(pid, "worker", 0.0, 10, "Running")
+        };
+        let items = [
+            in_group(1, "/system.slice/sshd.service"),
+            in_group(2, "/system.slice/docker-1a2b.scope"),
+            in_group(3, "/system.slice/docker-1a2b.scope"),
+            in_group(4, "/gone.scope"),
+        ];
+        let refs: Vec<_> = items.iter().collect();
+
+        let rows = build_cgroup_rows(&cgroups, &refs, ProcessSortColumn::Memory, false);
+        let layout: Vec<_> = rows
+            .iter()
+            .map(|row| (row.cgroup.name(), row.depth, row.processes.len(), row.subtree_processes))
+            .collect();
+        assert_eq!(
+            layout,
+            vec![
+                ("/", 0, 0, 3),
+                ("system.slice", 1, 0, 3),
+                ("docker-1a2b.scope", 2, 2, 2),
+                ("sshd.service", 2, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn cgroup_limit_utilization_reports_tightest_limit() {
+        let group = CgroupInfo {
+            path: "/app.slice".into(),
+            cpu_usage: 10.0,
+            cpu_limit: Some(25.0),
+            memory_current: 900,
+            memory_max: Some(1000),
+            pids_current: 5,
+            pids_max: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(group.limit_utilization(), Some(90.0));
+        assert_eq!(CgroupInfo::default().limit_utilization(), None);
+    }
 }
@@ -0,0 +1,336 @@
+//! Docker/Podman containers from the Engine API socket.
+//!
+//! Lists every container and, for running ones, reads one-shot stats and the
+//! init PID. Published keys, with `N` indexing containers sorted by name:
+//!
+//! - `container.count`
+//! - `container.N.{id,name,image,state,status,pid}` — labels; `pid` is empty
+//!   while the container is stopped
+//! - `container.N.cpu_percent` — share of all logical CPUs since the previous poll
+//! - `container.N.memory_bytes` / `container.N.memory_limit_bytes`
+//! - `container.N.{net_rx,net_tx,block_read,block_write}_bytes_per_sec`
+//!
+//! The init PID is inspected once per container start. Stats requests stop
+//! once a poll has used `POLL_BUDGET`, least recently sampled containers
+//! first, and containers left out repeat their previous values.
+//!
+//! Stopped containers publish zeros so their rows never show stale usage. The
+//! socket follows `AppSettings::container_socket`; with nothing configured and
+//! no engine socket present the provider polls successfully with a zero count.
+
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use crate::containers::{container_pid, container_stats, list_containers, resolve_socket, ContainerCounters};
+use crate::AppSettings;
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Each engine request may take up to its 5 s timeout; past this much time a
+/// poll stops sending new ones.
+const POLL_BUDGET: Duration = Duration::from_secs(2);
+
+/// Metrics of a stopped container, or one whose stats request failed.
+const IDLE: [(&str, f64); 7] = [
+    ("cpu_percent", 0.0),
+    ("memory_bytes", 0.0),
+    ("memory_limit_bytes", 0.0),
+    ("net_rx_bytes_per_sec", 0.0),
+    ("net_tx_bytes_per_sec", 0.0),
+    ("block_read_bytes_per_sec", 0.0),
+    ("block_write_bytes_per_sec", 0.0),
+];
+
+/// The last stats sample of a running container and the metrics published from it.
+struct Sample {
+    counters: ContainerCounters,
+    at: Instant,
+    metrics: [(&'static str, f64); 7],
+}
+
+enum SocketSource {
+    Settings(Arc<Mutex<AppSettings>>),
+    #[cfg(all(test, target_os = "linux"))]
+    Fixed(PathBuf),
+}
+
+pub struct ContainerProvider {
+    source: SocketSource,
+    samples: HashMap<String, Sample>,
+    /// Init PIDs of running containers, dropped when one stops or its process exits.
+    pids: HashMap<String, u32>,
+}
+
+impl ContainerProvider {
+    /// Re-reads the configured socket on every poll, so a changed setting
+    /// takes effect without a restart.
+    pub(crate) fn new(settings: Arc<Mutex<AppSettings>>) -> Self {
+        Self {
+            source: SocketSource::Settings(settings),
+            samples: HashMap::new(),
+            pids: HashMap::new(),
+        }
+    }
+
+    /// Talk to `socket` regardless of settings.
+    #[cfg(all(test, target_os = "linux"))]
+    pub(crate) fn with_socket(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            source: SocketSource::Fixed(socket.into()),
+            samples: HashMap::new(),
+            pids: HashMap::new(),
+        }
+    }
+
+    fn socket(&self) -> Option<PathBuf> {
+        match &self.source {
+            SocketSource::Settings(settings) => resolve_socket(&settings.lock().container_socket),
+            #[cfg(all(test, target_os = "linux"))]
+            SocketSource::Fixed(socket) => Some(socket.clone()),
+        }
+    }
+
+    fn poll_at(&mut self, now: Instant) -> Result<ProviderData, ProviderError> {
+        let started = Instant::now();
+        let mut data = ProviderData::new();
+        let Some(socket) = self.socket() else {
+            self.samples.clear();
+            self.pids.clear();
+            data.insert("container.count".into(), MetricValue::UInt(0));
+            return Ok(data);
+        };
+        let mut containers = list_containers(&socket).map_err(ProviderError::PollFailed)?;
+        containers.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+        let running: HashSet<&str> = containers
+            .iter()
+            .filter(|container| container.state == "running")
+            .map(|container| container.id.as_str())
+            .collect();
+        self.samples.retain(|id, _| running.contains(id.as_str()));
+        self.pids
+            .retain(|id, pid| running.contains(id.as_str()) && process_exists(*pid));
+
+        // `None` marks containers skipped for time, `Some(None)` a failed request:
+        // one that stops between the list and the stats request just reads as idle.
+        let mut fresh: Vec<Option<Option<ContainerCounters>>> = vec![None; containers.len()];
+        let mut order: Vec<usize> = (0..containers.len())
+            .filter(|&index| running.contains(containers[index].id.as_str()))
+            .collect();
+        order.sort_by_key(|&index| self.samples.get(&containers[index].id).map(|sample| sample.at));
+        for index in order {
+            if started.elapsed() >= POLL_BUDGET {
+                break;
+            }
+            let id = &containers[index].id;
+            if !self.pids.contains_key(id) {
+                if let Some(pid) = container_pid(&socket, id) {
+                    self.pids.insert(id.clone(), pid);
+                }
+            }
+            fresh[index] = Some(container_stats(&socket, id).ok());
+        }
+
+        data.insert("container.count".into(), MetricValue::UInt(containers.len() as u64));
+        for (index, container) in containers.iter().enumerate() {
+            let prefix = format!("container.{index}");
+            for (key, value) in [
+                ("id", &container.id),
+                ("name", &container.name),
+                ("image", &container.image),
+                ("state", &container.state),
+                ("status", &container.status),
+            ] {
+                data.insert(format!("{prefix}.{key}"), MetricValue::Text(value.clone()));
+            }
+            let pid = self
+                .pids
+                .get(&container.id)
+                .filter(|_| running.contains(container.id.as_str()));
+            data.insert(
+                format!("{prefix}.pid"),
+                MetricValue::Text(pid.map(|pid| pid.to_string()).unwrap_or_default()),
+            );
+
+            let metrics = match fresh[index] {
+                Some(Some(counters)) => {
+                    let metrics = self.metrics_from(&container.id, counters, now);
+                    self.samples.insert(
+                        container.id.clone(),
+                        Sample {
+                            counters,
+                            at: now,
+                            metrics,
+                        },
+                    );
+                    metrics
+                }
+                Some(None) => {
+                    self.samples.remove(&container.id);
+                    IDLE
+                }
+                None => self.samples.get(&container.id).map_or(IDLE, |sample| sample.metrics),
+            };
+            for (key, value) in metrics {
+                data.insert(format!("{prefix}.{key}"), MetricValue::Float(value));
+            }
+        }
+        Ok(data)
+    }
+
+    /// Usage and rates from `counters` against the container's previous sample.
+    fn metrics_from(&self, id: &str, counters: ContainerCounters, now: Instant) -> [(&'static str, f64); 7] {
+        let mut metrics = IDLE;
+        metrics[1].1 = counters.memory_usage as f64;
+        metrics[2].1 = counters.memory_limit as f64;
+        if let Some(sample) = self.samples.get(id) {
+            let before = &sample.counters;
+            let elapsed = now.saturating_duration_since(sample.at);
+            metrics[0].1 = counters.cpu_percent_since(before, elapsed);
+            let rate = |before: u64, current: u64| {
+                crate::monitoring::rates::counter_rate(Some(before), current, elapsed).value_per_second
+            };
+            metrics[3].1 = rate(before.net_rx, counters.net_rx);
+            metrics[4].1 = rate(before.net_tx, counters.net_tx);
+            metrics[5].1 = rate(before.block_read, counters.block_read);
+            metrics[6].1 = rate(before.block_write, counters.block_write);
+        }
+        metrics
+    }
+}
+
+/// A cached PID whose process is gone means the container restarted between polls.
+fn process_exists(pid: u32) -> bool {
+    !cfg!(target_os = "linux") || std::path::Path::new("/proc").join(pid.to_string()).exists()
+}
+
+impl TelemetryProvider for ContainerProvider {
+    fn name(&self) -> &str {
+        "containers"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        self.poll_at(Instant::now())
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(target_os = "linux") && self.socket().is_some_and(|socket| socket.exists())
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::containers::fake_engine;
+
+    fn stats(cpu_ns: u64, system_ns: u64, rx: u64) -> String {
+        format!(
+            r#"{{"cpu_stats":{{"cpu_usage":{{"total_usage":{cpu_ns}}},"system_cpu_usage":{system_ns},"online_cpus":2}},
+                "memory_stats":{{"usage":52428800,"limit":536870912,"stats":{{"inactive_file":0}}}},
+                "networks":{{"eth0":{{"rx_bytes":{rx},"tx_bytes":0}}}}}}"#
+        )
+    }
+
+    #[test]
+    fn publishes_containers_and_rates_from_a_fake_engine() {
+        let (web, batch) = ("1".repeat(64), "2".repeat(64));
+        let list = format!(
+            r#"[{{"Id":"{web}","Names":["/web"],"Image":"nginx:1.27","State":"running","Status":"Up 5 minutes"}},
+                {{"Id":"{batch}","Names":["/batch"],"Image":"alpine","State":"exited","Status":"Exited (0) 1 hour ago"}}]"#
+        );
+        let routes = |cpu_ns: u64, system_ns: u64, rx: u64| {
+            vec![
+                ("GET", "/containers/json".to_string(), 200, list.clone()),
+                (
+                    "GET",
+                    format!("/containers/{web}/stats"),
+                    200,
+                    stats(cpu_ns, system_ns, rx),
+                ),
+                (
+                    "GET",
+                    format!("/containers/{web}/json"),
+                    200,
+                    r#"{"State":{"Pid":777}}"#.to_string(),
+                ),
+            ]
+        };
+        let first = fake_engine::serve("provider-a", routes(1_000_000_000, 10_000_000_000, 1_000));
+        let second = fake_engine::serve("provider-b", routes(1_500_000_000, 12_000_000_000, 3_000));
+
+        let mut provider = ContainerProvider::with_socket(&first.socket);
+        assert!(provider.is_available());
+        let start = Instant::now();
+        let data = provider.poll_at(start).unwrap();
+        let text = |data: &ProviderData, key: &str| match data.get(key) {
+            Some(MetricValue::Text(text)) => text.clone(),
+            other => panic!("{key}: {other:?}"),
+        };
+        assert_eq!(data["container.count"].as_f64(), 2.0);
+        // Sorted by name, so the stopped batch job comes first.
+        assert_eq!(text(&data, "container.0.name"), "batch");
+        assert_eq!(text(&data, "container.0.pid"), "");
+        assert_eq!(data["container.0.memory_bytes"].as_f64(), 0.0);
+        assert_eq!(text(&data, "container.1.pid"), "777");
+        assert_eq!(data["container.1.memory_bytes"].as_f64(), 50.0 * 1_048_576.0);
+        assert_eq!(data["container.1.cpu_percent"].as_f64(), 0.0);
+
+        provider.source = SocketSource::Fixed(second.socket.clone());
+        let data = provider.poll_at(start + Duration::from_secs(2)).unwrap();
+        assert_eq!(data["container.1.cpu_percent"].as_f64(), 25.0);
+        assert_eq!(data["container.1.net_rx_bytes_per_sec"].as_f64(), 1_000.0);
+    }
+
+    #[test]
+    fn init_pid_is_inspected_once_per_start() {
+        let id = "3".repeat(64);
+        let list = |state: &str| {
+            format!(r#"[{{"Id":"{id}","Names":["/db"],"Image":"postgres","State":"{state}","Status":""}}]"#)
+        };
+        let inspect = (
+            "GET",
+            format!("/containers/{id}/json"),
+            200,
+            format!(r#"{{"State":{{"Pid":{}}}}}"#, std::process::id()),
+        );
+        let with_inspect = fake_engine::serve(
+            "pid-a",
+            vec![("GET", "/containers/json".to_string(), 200, list("running")), inspect],
+        );
+        let without_inspect = fake_engine::serve(
+            "pid-b",
+            vec![("GET", "/containers/json".to_string(), 200, list("running"))],
+        );
+        let stopped = fake_engine::serve(
+            "pid-c",
+            vec![("GET", "/containers/json".to_string(), 200, list("exited"))],
+        );
+        let pid = |provider: &mut ContainerProvider| match provider.poll().unwrap().remove("container.0.pid") {
+            Some(MetricValue::Text(pid)) => pid,
+            other => panic!("container.0.pid: {other:?}"),
+        };
+
+        let mut provider = ContainerProvider::with_socket(&with_inspect.socket);
+        assert_eq!(pid(&mut provider), std::process::id().to_string());
+        provider.source = SocketSource::Fixed(without_inspect.socket.clone());
+        assert_eq!(pid(&mut provider), std::process::id().to_string());
+
+        // Once it has been seen stopped, the next start is inspected again.
+        provider.source = SocketSource::Fixed(stopped.socket.clone());
+        assert_eq!(pid(&mut provider), "");
+        provider.source = SocketSource::Fixed(without_inspect.socket.clone());
+        assert_eq!(pid(&mut provider), "");
+    }
+
+    #[test]
+    fn unreachable_socket_fails_the_poll() {
+        let mut provider = ContainerProvider::with_socket("/nonexistent/sysmon-engine.sock");
+        assert!(!provider.is_available());
+        assert!(matches!(provider.poll(), Err(ProviderError::PollFailed(_))));
+    }
+}
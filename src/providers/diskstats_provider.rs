@@ -0,0 +1,256 @@
+//! Linux per-device block I/O statistics from `/proc/diskstats`.
+//!
+//! Reports whole disks only: partitions are skipped when `/sys/block` is
+//! available to tell them apart, as are loop and RAM disks and devices that
+//! have never completed an I/O. Counters are cumulative, so rates need two
+//! samples; the first poll only publishes the in-flight count. Published keys:
+//!
+//! - `disk.<device>.read_iops` / `write_iops` — completed requests per second
+//! - `disk.<device>.read_bytes_per_sec` / `write_bytes_per_sec`
+//! - `disk.<device>.await_ms` — average time a request spent queued and in service
+//! - `disk.<device>.queue_depth` — average number of requests in flight
+//! - `disk.<device>.util_percent` — share of wall time the device was busy
+//! - `disk.<device>.in_flight` — requests in flight right now
+
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use crate::monitoring::rates::counter_rate;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// `/proc/diskstats` always counts in 512-byte sectors, whatever the device's block size.
+const SECTOR_BYTES: f64 = 512.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct DiskCounters {
+    reads: u64,
+    sectors_read: u64,
+    read_ms: u64,
+    writes: u64,
+    sectors_written: u64,
+    write_ms: u64,
+    in_flight: u64,
+    busy_ms: u64,
+    weighted_ms: u64,
+}
+
+/// Rates derived from two samples of the same device.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct DiskRates {
+    read_iops: f64,
+    write_iops: f64,
+    read_bytes_per_sec: f64,
+    write_bytes_per_sec: f64,
+    await_ms: f64,
+    queue_depth: f64,
+    util_percent: f64,
+}
+
+impl DiskRates {
+    fn between(previous: &DiskCounters, current: &DiskCounters, elapsed: Duration) -> Self {
+        let rate = |now: u64, then: u64| counter_rate(Some(then), now, elapsed).value_per_second;
+        let read_iops = rate(current.reads, previous.reads);
+        let write_iops = rate(current.writes, previous.writes);
+        // Milliseconds of service (or busy, or weighted queue) time per second.
+        let service_ms = rate(current.read_ms, previous.read_ms) + rate(current.write_ms, previous.write_ms);
+        Self {
+            read_iops,
+            write_iops,
+            read_bytes_per_sec: rate(current.sectors_read, previous.sectors_read) * SECTOR_BYTES,
+            write_bytes_per_sec: rate(current.sectors_written, previous.sectors_written) * SECTOR_BYTES,
+            await_ms: if read_iops + write_iops > 0.0 {
+                service_ms / (read_iops + write_iops)
+            } else {
+                0.0
+            },
+            queue_depth: rate(current.weighted_ms, previous.weighted_ms) / 1000.0,
+            util_percent: (rate(current.busy_ms, previous.busy_ms) / 1000.0 * 100.0).min(100.0),
+        }
+    }
+}
+
+pub struct DiskStatsProvider {
+    proc_root: PathBuf,
+    sys_root: PathBuf,
+    previous: HashMap<String, DiskCounters>,
+    sampled_at: Option<Instant>,
+    available: bool,
+}
+
+impl DiskStatsProvider {
+    pub fn new() -> Self {
+        Self::with_roots("/proc", "/sys")
+    }
+
+    /// Read `diskstats` below `proc_root` and `block/` below `sys_root`.
+    pub fn with_roots(proc_root: impl Into<PathBuf>, sys_root: impl Into<PathBuf>) -> Self {
+        Self {
+            proc_root: proc_root.into(),
+            sys_root: sys_root.into(),
+            previous: HashMap::new(),
+            sampled_at: None,
+            available: false,
+        }
+    }
+
+    fn is_whole_disk(&self, name: &str) -> bool {
+        if name.starts_with("loop") || name.starts_with("ram") {
+            return false;
+        }
+        let block_dir = self.sys_root.join("block");
+        !block_dir.is_dir() || block_dir.join(name).exists()
+    }
+
+    fn poll_contents(&mut self, contents: &str, now: Instant) -> Result<ProviderData, ProviderError> {
+        let elapsed = self.sampled_at.map(|then| now.saturating_duration_since(then));
+        let mut data = ProviderData::new();
+        let mut current = HashMap::new();
+        for (name, counters) in parse_diskstats(contents) {
+            if !self.is_whole_disk(name) || counters.reads + counters.writes == 0 {
+                continue;
+            }
+            let prefix = format!("disk.{name}");
+            data.insert(format!("{prefix}.in_flight"), MetricValue::UInt(counters.in_flight));
+            if let (Some(previous), Some(elapsed)) = (self.previous.get(name), elapsed) {
+                let rates = DiskRates::between(previous, &counters, elapsed);
+                for (key, value) in [
+                    ("read_iops", rates.read_iops),
+                    ("write_iops", rates.write_iops),
+                    ("read_bytes_per_sec", rates.read_bytes_per_sec),
+                    ("write_bytes_per_sec", rates.write_bytes_per_sec),
+                    ("await_ms", rates.await_ms),
+                    ("queue_depth", rates.queue_depth),
+                    ("util_percent", rates.util_percent),
+                ] {
+                    data.insert(format!("{prefix}.{key}"), MetricValue::Float(value));
+                }
+            }
+            current.insert(name.to_string(), counters);
+        }
+        self.previous = current;
+        self.sampled_at = Some(now);
+
+        if data.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(
+                "no active block devices in diskstats".into(),
+            ));
+        }
+        self.available = true;
+        Ok(data)
+    }
+}
+
+impl Default for DiskStatsProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for DiskStatsProvider {
+    fn name(&self) -> &str {
+        "diskstats"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        let path = self.proc_root.join("diskstats");
+        let contents = std::fs::read_to_string(&path).map_err(|error| {
+            self.available = false;
+            ProviderError::Unavailable(format!("{}: {error}", path.display()))
+        })?;
+        self.poll_contents(&contents, Instant::now())
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+/// Parse `/proc/diskstats` rows:
+/// `major minor name reads merged sectors ms writes merged sectors ms in_flight io_ms weighted_ms ...`.
+/// Newer kernels append discard and flush columns, which are ignored.
+fn parse_diskstats(contents: &str) -> Vec<(&str, DiskCounters)> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 14 {
+                return None;
+            }
+            let number = |index: usize| fields[index].parse::<u64>().ok();
+            Some((
+                fields[2],
+                DiskCounters {
+                    reads: number(3)?,
+                    sectors_read: number(5)?,
+                    read_ms: number(6)?,
+                    writes: number(7)?,
+                    sectors_written: number(9)?,
+                    write_ms: number(10)?,
+                    in_flight: number(11)?,
+                    busy_ms: number(12)?,
+                    weighted_ms: number(13)?,
+                },
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    const FIRST: &str = "   7       0 loop0 120 0 2400 30 0 0 0 0 0 40 30 0 0 0 0\n\
+                         259       0 nvme0n1 1000 10 80000 500 2000 20 160000 4000 0 3000 4500 0 0 0 0 0 0\n\
+                         259       1 nvme0n1p1 900 10 72000 450 1900 20 150000 3900 0 2900 4350 0 0 0 0\n\
+                         8       0 sda 0 0 0 0 0 0 0 0 0 0 0\n";
+    const SECOND: &str = "259       0 nvme0n1 1100 10 88192 700 2300 20 184576 6800 4 3950 8500 0 0 0 0 0 0\n";
+
+    #[test]
+    fn parses_rows_and_ignores_short_ones() {
+        let rows = parse_diskstats(FIRST);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[1].0, "nvme0n1");
+        assert_eq!(rows[1].1.sectors_written, 160_000);
+        assert_eq!(rows[1].1.weighted_ms, 4500);
+        assert!(parse_diskstats("8 0 sda 1 2 3").is_empty());
+    }
+
+    #[test]
+    fn rates_between_two_samples() {
+        let root = TempTree::new("diskstats");
+        std::fs::create_dir_all(root.join("block/nvme0n1")).unwrap();
+        std::fs::create_dir_all(root.join("block/sda")).unwrap();
+        let mut provider = DiskStatsProvider::with_roots(root.path(), root.path());
+        let start = Instant::now();
+
+        let first = provider.poll_contents(FIRST, start).unwrap();
+        assert!(provider.is_available());
+        // The partition, the loop device and the idle disk are skipped; no rates yet.
+        assert_eq!(first.keys().collect::<Vec<_>>(), vec!["disk.nvme0n1.in_flight"]);
+
+        let second = provider.poll_contents(SECOND, start + Duration::from_secs(1)).unwrap();
+        let value = |key: &str| second[&format!("disk.nvme0n1.{key}")].as_f64();
+        assert_eq!(value("read_iops"), 100.0);
+        assert_eq!(value("write_iops"), 300.0);
+        assert_eq!(value("read_bytes_per_sec"), 8192.0 * 512.0);
+        assert_eq!(value("write_bytes_per_sec"), 24_576.0 * 512.0);
+        // 200 ms reading + 2800 ms writing over 400 requests.
+        assert_eq!(value("await_ms"), 7.5);
+        assert_eq!(value("queue_depth"), 4.0);
+        assert_eq!(value("util_percent"), 95.0);
+        assert_eq!(value("in_flight"), 4.0);
+
+        root.remove("block");
+        assert!(matches!(
+            provider.poll_contents("", start + Duration::from_secs(2)),
+            Err(ProviderError::Unavailable(_))
+        ));
+        assert!(!provider.is_available());
+    }
+}
@@ -0,0 +1,292 @@
+//! Linux GPU telemetry from the DRM subsystem in sysfs.
+//!
+//! Reads `class/drm/card*/device` below a sysfs root (normally `/sys`), which
+//! covers amdgpu, i915/xe and nouveau without vendor libraries. Devices are
+//! published with the same `gpu.<n>.*` keys as `NvmlProvider`:
+//!
+//! - `name`, `driver`
+//! - `utilization` — `gpu_busy_percent` (amdgpu)
+//! - `vram_used` / `vram_total` / `vram_free` — bytes, from `mem_info_vram_*`
+//! - `temperature` — °C from the device's hwmon
+//! - `fan_speed` — percent, from hwmon `pwm1`
+//! - `clock_graphics` / `clock_memory` — current MHz
+//! - `power_draw_mw` / `power_limit_mw`
+//!
+//! When NVML already reports NVIDIA cards, those are skipped here and the
+//! remaining cards are numbered after the NVML devices.
+
+use super::sysfs::{numbered_entries, read_trimmed, read_u64};
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const VENDOR_NVIDIA: &str = "0x10de";
+
+pub struct DrmGpuProvider {
+    root: PathBuf,
+    first_index: u32,
+    available: bool,
+}
+
+impl DrmGpuProvider {
+    /// `nvml_devices` is the number of GPUs NVML publishes; DRM devices are
+    /// numbered after them and NVIDIA cards are left to NVML.
+    pub fn new(nvml_devices: u32) -> Self {
+        Self::with_root("/sys", nvml_devices)
+    }
+
+    /// Read devices below `root` instead of `/sys`.
+    pub fn with_root(root: impl Into<PathBuf>, nvml_devices: u32) -> Self {
+        Self {
+            root: root.into(),
+            first_index: nvml_devices,
+            available: false,
+        }
+    }
+}
+
+impl TelemetryProvider for DrmGpuProvider {
+    fn name(&self) -> &str {
+        "drm"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_millis(500)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        let drm_dir = self.root.join("class/drm");
+        let skip_nvidia = self.first_index > 0;
+        let cards: Vec<PathBuf> = numbered_entries(&drm_dir, "card")
+            .into_iter()
+            // `card0-DP-1` and friends are connectors, not devices.
+            .filter(|card| card.file_name().is_some_and(|name| !name.to_string_lossy().contains('-')))
+            .map(|card| card.join("device"))
+            .filter(|device| device.join("vendor").is_file())
+            .filter(|device| !(skip_nvidia && read_trimmed(&device.join("vendor")).as_deref() == Some(VENDOR_NVIDIA)))
+            .collect();
+        if cards.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!(
+                "no DRM GPUs under {}",
+                drm_dir.display()
+            )));
+        }
+
+        let mut data = ProviderData::new();
+        let count = self.first_index + cards.len() as u32;
+        data.insert("gpu.device_count".into(), MetricValue::UInt(count as u64));
+        for (offset, device) in cards.iter().enumerate() {
+            let prefix = format!("gpu.{}", self.first_index as usize + offset);
+            read_device(device, &prefix, &mut data);
+        }
+
+        self.available = true;
+        Ok(data)
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+fn read_device(device: &Path, prefix: &str, data: &mut ProviderData) {
+    let mut put = |name: &str, value: MetricValue| {
+        data.insert(format!("{prefix}.{name}"), value);
+    };
+
+    let driver = driver_name(device);
+    put("name", MetricValue::Text(device_name(device, driver.as_deref())));
+    if let Some(driver) = driver {
+        put("driver", MetricValue::Text(driver));
+    }
+
+    if let Some(busy) = read_u64(&device.join("gpu_busy_percent")) {
+        put("utilization", MetricValue::UInt(busy.min(100)));
+    }
+    let vram_used = read_u64(&device.join("mem_info_vram_used"));
+    let vram_total = read_u64(&device.join("mem_info_vram_total")).filter(|total| *total > 0);
+    if let Some(used) = vram_used {
+        put("vram_used", MetricValue::UInt(used));
+    }
+    if let Some(total) = vram_total {
+        put("vram_total", MetricValue::UInt(total));
+        if let Some(used) = vram_used {
+            put("vram_free", MetricValue::UInt(total.saturating_sub(used)));
+        }
+    }
+
+    let hwmon = numbered_entries(&device.join("hwmon"), "hwmon").into_iter().next();
+    if let Some(hwmon) = &hwmon {
+        if let Some(millidegrees) = read_u64(&hwmon.join("temp1_input")) {
+            put("temperature", MetricValue::UInt(millidegrees / 1000));
+        }
+        if let Some(pwm) = read_u64(&hwmon.join("pwm1")) {
+            put("fan_speed", MetricValue::UInt(pwm.min(255) * 100 / 255));
+        }
+        // amdgpu reports `power1_average` (older) or `power1_input` (newer) in µW.
+        if let Some(microwatts) =
+            read_u64(&hwmon.join("power1_average")).or_else(|| read_u64(&hwmon.join("power1_input")))
+        {
+            put("power_draw_mw", MetricValue::UInt(microwatts / 1000));
+        }
+        if let Some(microwatts) = read_u64(&hwmon.join("power1_cap")).filter(|cap| *cap > 0) {
+            put("power_limit_mw", MetricValue::UInt(microwatts / 1000));
+        }
+    }
+
+    if let Some(mhz) = graphics_clock(device, hwmon.as_deref()) {
+        put("clock_graphics", MetricValue::UInt(mhz));
+    }
+    if let Some(mhz) = hwmon
+        .as_ref()
+        .and_then(|hwmon| read_u64(&hwmon.join("freq2_input")))
+        .map(|hz| hz / 1_000_000)
+        .or_else(|| active_dpm_level(&device.join("pp_dpm_mclk")))
+    {
+        put("clock_memory", MetricValue::UInt(mhz));
+    }
+}
+
+/// Current shader clock: amdgpu hwmon `freq1_input` (Hz) or the active
+/// `pp_dpm_sclk` level, otherwise the i915 actual GT frequency.
+fn graphics_clock(device: &Path, hwmon: Option<&Path>) -> Option<u64> {
+    hwmon
+        .and_then(|hwmon| read_u64(&hwmon.join("freq1_input")))
+        .map(|hz| hz / 1_000_000)
+        .or_else(|| active_dpm_level(&device.join("pp_dpm_sclk")))
+        .or_else(|| {
+            // The GT frequency files live on the card, one level above `device`.
+            let card = device.parent()?;
+            read_u64(&card.join("gt_act_freq_mhz")).or_else(|| read_u64(&card.join("gt_cur_freq_mhz")))
+        })
+        .or_else(|| read_u64(&device.join("tile0/gt0/freq0/act_freq")))
+}
+
+/// The level marked `*` in an amdgpu `pp_dpm_*` table (`1: 1800Mhz *`).
+fn active_dpm_level(path: &Path) -> Option<u64> {
+    parse_active_dpm_level(&std::fs::read_to_string(path).ok()?)
+}
+
+fn parse_active_dpm_level(table: &str) -> Option<u64> {
+    let line = table.lines().find(|line| line.trim_end().ends_with('*'))?;
+    let (_, level) = line.split_once(':')?;
+    let digits: String = level.trim().chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+fn driver_name(device: &Path) -> Option<String> {
+    let uevent = std::fs::read_to_string(device.join("uevent")).ok()?;
+    uevent
+        .lines()
+        .find_map(|line| line.strip_prefix("DRIVER="))
+        .map(str::to_string)
+}
+
+/// sysfs has no marketing name except amdgpu's `product_name` (often blank),
+/// so fall back to vendor plus the PCI ID.
+fn device_name(device: &Path, driver: Option<&str>) -> String {
+    if let Some(product) = read_trimmed(&device.join("product_name")).filter(|name| !name.is_empty()) {
+        return product;
+    }
+    let vendor = read_trimmed(&device.join("vendor")).unwrap_or_default();
+    let vendor_name = match vendor.as_str() {
+        "0x1002" => "AMD Radeon Graphics",
+        "0x8086" => "Intel Graphics",
+        VENDOR_NVIDIA => "NVIDIA GPU",
+        _ => driver.unwrap_or("GPU"),
+    };
+    match read_trimmed(&device.join("device")) {
+        Some(id) => format!(
+            "{vendor_name} [{}:{}]",
+            vendor.trim_start_matches("0x"),
+            id.trim_start_matches("0x")
+        ),
+        None => vendor_name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    fn amd_card(root: &TempTree, card: &str) {
+        let device = format!("class/drm/{card}/device");
+        root.write(format!("{device}/vendor"), "0x1002");
+        root.write(format!("{device}/device"), "0x73bf");
+        root.write(format!("{device}/uevent"), "DRIVER=amdgpu\nPCI_ID=1002:73BF");
+        root.write(format!("{device}/gpu_busy_percent"), "37");
+        root.write(format!("{device}/mem_info_vram_used"), "2147483648");
+        root.write(format!("{device}/mem_info_vram_total"), "17163091968");
+        root.write(format!("{device}/pp_dpm_sclk"), "0: 500Mhz\n1: 2105Mhz *\n2: 2575Mhz");
+        root.write(format!("{device}/pp_dpm_mclk"), "0: 96Mhz\n1: 1000Mhz *");
+        root.write(format!("{device}/hwmon/hwmon4/temp1_input"), "54000");
+        root.write(format!("{device}/hwmon/hwmon4/pwm1"), "102");
+        root.write(format!("{device}/hwmon/hwmon4/power1_average"), "187000000");
+        root.write(format!("{device}/hwmon/hwmon4/power1_cap"), "272000000");
+        root.write(format!("class/drm/{card}-DP-1/status"), "connected");
+    }
+
+    #[test]
+    fn publishes_amdgpu_keys() {
+        let root = TempTree::new("drm-amd");
+        amd_card(&root, "card0");
+
+        let data = DrmGpuProvider::with_root(root.path(), 0).poll().unwrap();
+        let uint = |key: &str| data.get(key).map(MetricValue::as_f64);
+        assert_eq!(uint("gpu.device_count"), Some(1.0));
+        assert!(
+            matches!(data.get("gpu.0.name"), Some(MetricValue::Text(name)) if name == "AMD Radeon Graphics [1002:73bf]")
+        );
+        assert!(matches!(data.get("gpu.0.driver"), Some(MetricValue::Text(driver)) if driver == "amdgpu"));
+        assert_eq!(uint("gpu.0.utilization"), Some(37.0));
+        assert_eq!(uint("gpu.0.vram_used"), Some(2147483648.0));
+        assert_eq!(uint("gpu.0.vram_free"), Some(15015608320.0));
+        assert_eq!(uint("gpu.0.temperature"), Some(54.0));
+        assert_eq!(uint("gpu.0.fan_speed"), Some(40.0));
+        assert_eq!(uint("gpu.0.clock_graphics"), Some(2105.0));
+        assert_eq!(uint("gpu.0.clock_memory"), Some(1000.0));
+        assert_eq!(uint("gpu.0.power_draw_mw"), Some(187000.0));
+        assert_eq!(uint("gpu.0.power_limit_mw"), Some(272000.0));
+    }
+
+    #[test]
+    fn intel_card_follows_nvml_devices() {
+        let root = TempTree::new("drm-hybrid");
+        root.write("class/drm/card0/device/vendor", "0x10de");
+        root.write("class/drm/card0/device/uevent", "DRIVER=nvidia");
+        root.write("class/drm/card1/device/vendor", "0x8086");
+        root.write("class/drm/card1/device/device", "0xa7a0");
+        root.write("class/drm/card1/device/uevent", "DRIVER=i915");
+        root.write("class/drm/card1/gt_act_freq_mhz", "1300");
+
+        let data = DrmGpuProvider::with_root(root.path(), 1).poll().unwrap();
+        assert_eq!(data.get("gpu.device_count").map(MetricValue::as_f64), Some(2.0));
+        assert!(!data.contains_key("gpu.0.name"));
+        assert!(
+            matches!(data.get("gpu.1.name"), Some(MetricValue::Text(name)) if name == "Intel Graphics [8086:a7a0]")
+        );
+        assert_eq!(data.get("gpu.1.clock_graphics").map(MetricValue::as_f64), Some(1300.0));
+        assert!(!data.contains_key("gpu.1.utilization"));
+
+        // Without NVML the NVIDIA card is reported here too.
+        let data = DrmGpuProvider::with_root(root.path(), 0).poll().unwrap();
+        assert_eq!(data.get("gpu.device_count").map(MetricValue::as_f64), Some(2.0));
+        assert!(matches!(data.get("gpu.0.name"), Some(MetricValue::Text(name)) if name == "NVIDIA GPU"));
+    }
+
+    #[test]
+    fn missing_drm_is_unavailable() {
+        let root = TempTree::new("drm-empty");
+        let mut provider = DrmGpuProvider::with_root(root.path(), 0);
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+
+    #[test]
+    fn dpm_table_parsing() {
+        assert_eq!(parse_active_dpm_level("0: 500Mhz\n1: 2105Mhz *\n"), Some(2105));
+        assert_eq!(parse_active_dpm_level("0: 500Mhz\n1: 2105Mhz\n"), None);
+    }
+}
@@ -0,0 +1,335 @@
+//! Linux temperature and fan telemetry from hwmon and ACPI thermal zones.
+//!
+//! Reads `class/hwmon/*` and `class/thermal/thermal_zone*` below a sysfs root
+//! (normally `/sys`). Published keys:
+//!
+//! - `cpu.temperature` — best available package temperature in °C
+//! - `cpu.physical_core.<n>.temperature` — per-core readings (coretemp `Core n`)
+//! - `sensor.<chip>.<label>` — every hwmon/thermal-zone temperature in °C
+//! - `fan.<chip>.<label>` — fan speed in RPM, plus `fan.count`
+
+use super::sysfs::{key_segment, numbered_entries, read_i64, read_trimmed, read_u64};
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// hwmon driver names that report CPU die/package temperatures.
+const CPU_CHIPS: &[&str] = &["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+
+#[derive(Debug, Clone, PartialEq)]
+struct TempReading {
+    chip: String,
+    label: String,
+    celsius: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct FanReading {
+    chip: String,
+    label: String,
+    rpm: u64,
+}
+
+pub struct HwmonProvider {
+    root: PathBuf,
+    available: bool,
+}
+
+impl HwmonProvider {
+    pub fn new() -> Self {
+        Self::with_root("/sys")
+    }
+
+    /// Read sensors below `root` instead of `/sys`.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            available: false,
+        }
+    }
+}
+
+impl Default for HwmonProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for HwmonProvider {
+    fn name(&self) -> &str {
+        "hwmon"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        let hwmon_dir = self.root.join("class/hwmon");
+        let thermal_dir = self.root.join("class/thermal");
+        if !hwmon_dir.is_dir() && !thermal_dir.is_dir() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!(
+                "no hwmon or thermal sysfs under {}",
+                self.root.display()
+            )));
+        }
+
+        let (mut temps, fans) = read_hwmon(&hwmon_dir);
+        let zones = read_thermal_zones(&thermal_dir);
+        let package = package_temperature(&temps, &zones);
+        temps.extend(zones);
+        if temps.is_empty() && fans.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable("no temperature or fan sensors found".into()));
+        }
+
+        let mut data = ProviderData::new();
+        if let Some((source, celsius)) = package {
+            data.insert("cpu.temperature".into(), MetricValue::Float(celsius));
+            data.insert("cpu.temperature.source".into(), MetricValue::Text(source));
+        }
+        for reading in &temps {
+            if !CPU_CHIPS.contains(&reading.chip.as_str()) {
+                continue;
+            }
+            if let Some(core) = reading
+                .label
+                .strip_prefix("core_")
+                .and_then(|id| id.parse::<u32>().ok())
+            {
+                let key = format!("cpu.physical_core.{core}.temperature");
+                let hottest = data
+                    .get(&key)
+                    .map_or(reading.celsius, |value| value.as_f64().max(reading.celsius));
+                data.insert(key, MetricValue::Float(hottest));
+            }
+        }
+        for reading in temps {
+            data.insert(
+                format!("sensor.{}.{}", reading.chip, reading.label),
+                MetricValue::Float(reading.celsius),
+            );
+        }
+        data.insert("fan.count".into(), MetricValue::UInt(fans.len() as u64));
+        for fan in fans {
+            data.insert(format!("fan.{}.{}", fan.chip, fan.label), MetricValue::UInt(fan.rpm));
+        }
+
+        self.available = true;
+        Ok(data)
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+/// Collect temperature and fan channels from every `hwmonN` device.
+///
+/// Chips sharing a driver name (two NVMe drives, say) get an occurrence
+/// suffix so their keys do not collide: `nvme`, `nvme_1`, ...
+fn read_hwmon(hwmon_dir: &Path) -> (Vec<TempReading>, Vec<FanReading>) {
+    let mut temps = Vec::new();
+    let mut fans = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for device in numbered_entries(hwmon_dir, "hwmon") {
+        // Older kernels expose the attributes on the parent device instead.
+        let attr_dir = if device.join("name").is_file() {
+            device.clone()
+        } else {
+            device.join("device")
+        };
+        let Some(name) = read_trimmed(&attr_dir.join("name")) else {
+            continue;
+        };
+        let base = key_segment(&name);
+        let occurrence = seen.entry(base.clone()).or_insert(0);
+        let chip = if *occurrence == 0 {
+            base
+        } else {
+            format!("{base}_{occurrence}")
+        };
+        *occurrence += 1;
+
+        for channel in channels(&attr_dir, "temp") {
+            let Some(millidegrees) = read_i64(&attr_dir.join(format!("temp{channel}_input"))) else {
+                continue;
+            };
+            temps.push(TempReading {
+                chip: chip.clone(),
+                label: channel_label(&attr_dir, "temp", channel),
+                celsius: millidegrees as f64 / 1000.0,
+            });
+        }
+        for channel in channels(&attr_dir, "fan") {
+            let Some(rpm) = read_u64(&attr_dir.join(format!("fan{channel}_input"))) else {
+                continue;
+            };
+            fans.push(FanReading {
+                chip: chip.clone(),
+                label: channel_label(&attr_dir, "fan", channel),
+                rpm,
+            });
+        }
+    }
+    (temps, fans)
+}
+
+/// Channel numbers with a `<kind>N_input` attribute, in ascending order.
+fn channels(attr_dir: &Path, kind: &str) -> Vec<u32> {
+    let Ok(entries) = std::fs::read_dir(attr_dir) else {
+        return Vec::new();
+    };
+    let mut channels: Vec<u32> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.strip_prefix(kind)?.strip_suffix("_input")?.parse().ok()
+        })
+        .collect();
+    channels.sort_unstable();
+    channels
+}
+
+fn channel_label(attr_dir: &Path, kind: &str, channel: u32) -> String {
+    read_trimmed(&attr_dir.join(format!("{kind}{channel}_label")))
+        .filter(|label| !label.is_empty())
+        .map(|label| key_segment(&label))
+        .unwrap_or_else(|| format!("{kind}{channel}"))
+}
+
+/// Read `thermal_zoneN/temp`, keyed as chip `thermal_zoneN` with the zone type as label.
+fn read_thermal_zones(thermal_dir: &Path) -> Vec<TempReading> {
+    numbered_entries(thermal_dir, "thermal_zone")
+        .into_iter()
+        .filter_map(|zone| {
+            let millidegrees = read_i64(&zone.join("temp"))?;
+            let chip = zone.file_name()?.to_string_lossy().into_owned();
+            let label = read_trimmed(&zone.join("type")).map_or_else(|| "temp".to_string(), |kind| key_segment(&kind));
+            Some(TempReading {
+                chip,
+                label,
+                celsius: millidegrees as f64 / 1000.0,
+            })
+        })
+        .collect()
+}
+
+/// Pick the reading that best represents "CPU temperature", returning its
+/// `chip/label` source alongside the value.
+///
+/// Preference order: coretemp package sensors, AMD `Tdie` then `Tctl`, the
+/// hottest CPU-driver channel, the `x86_pkg_temp` / CPU thermal zone, and
+/// finally the ACPI zone (the same source Windows reads through WMI).
+fn package_temperature(hwmon: &[TempReading], zones: &[TempReading]) -> Option<(String, f64)> {
+    let source = |reading: &TempReading| (format!("{}/{}", reading.chip, reading.label), reading.celsius);
+    let cpu: Vec<&TempReading> = hwmon
+        .iter()
+        .filter(|reading| CPU_CHIPS.contains(&reading.chip.as_str()))
+        .collect();
+    let hottest = |readings: Vec<&TempReading>| {
+        readings
+            .into_iter()
+            .max_by(|left, right| left.celsius.total_cmp(&right.celsius))
+            .map(source)
+    };
+
+    hottest(
+        cpu.iter()
+            .copied()
+            .filter(|r| r.label.starts_with("package_id"))
+            .collect(),
+    )
+    .or_else(|| cpu.iter().find(|r| r.label == "tdie").map(|r| source(r)))
+    .or_else(|| cpu.iter().find(|r| r.label == "tctl").map(|r| source(r)))
+    .or_else(|| hottest(cpu.clone()))
+    .or_else(|| {
+        zones
+            .iter()
+            .find(|zone| zone.label == "x86_pkg_temp" || zone.label.contains("cpu"))
+            .map(source)
+    })
+    .or_else(|| zones.iter().find(|zone| zone.label == "acpitz").map(source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    #[test]
+    fn publishes_package_core_sensor_and_fan_keys() {
+        let root = TempTree::new("hwmon-intel");
+        root.write("class/hwmon/hwmon0/name", "acpitz");
+        root.write("class/hwmon/hwmon0/temp1_input", "27800");
+        root.write("class/hwmon/hwmon2/name", "coretemp");
+        root.write("class/hwmon/hwmon2/temp1_label", "Package id 0");
+        root.write("class/hwmon/hwmon2/temp1_input", "61000");
+        root.write("class/hwmon/hwmon2/temp2_label", "Core 0");
+        root.write("class/hwmon/hwmon2/temp2_input", "58000");
+        root.write("class/hwmon/hwmon2/temp3_label", "Core 1");
+        root.write("class/hwmon/hwmon2/temp3_input", "60500");
+        root.write("class/hwmon/hwmon3/name", "nvme");
+        root.write("class/hwmon/hwmon3/temp1_label", "Composite");
+        root.write("class/hwmon/hwmon3/temp1_input", "41850");
+        root.write("class/hwmon/hwmon10/name", "nvme");
+        root.write("class/hwmon/hwmon10/temp1_label", "Composite");
+        root.write("class/hwmon/hwmon10/temp1_input", "39000");
+        root.write("class/hwmon/hwmon4/name", "thinkpad");
+        root.write("class/hwmon/hwmon4/fan1_input", "2450");
+        root.write("class/thermal/thermal_zone0/type", "x86_pkg_temp");
+        root.write("class/thermal/thermal_zone0/temp", "62000");
+
+        let data = HwmonProvider::with_root(root.path()).poll().unwrap();
+        let metric = |key: &str| data.get(key).map(MetricValue::as_f64);
+
+        assert_eq!(metric("cpu.temperature"), Some(61.0));
+        assert!(
+            matches!(data.get("cpu.temperature.source"), Some(MetricValue::Text(s)) if s == "coretemp/package_id_0")
+        );
+        assert_eq!(metric("cpu.physical_core.0.temperature"), Some(58.0));
+        assert_eq!(metric("cpu.physical_core.1.temperature"), Some(60.5));
+        assert_eq!(metric("sensor.acpitz.temp1"), Some(27.8));
+        assert_eq!(metric("sensor.nvme.composite"), Some(41.85));
+        assert_eq!(metric("sensor.nvme_1.composite"), Some(39.0));
+        assert_eq!(metric("sensor.thermal_zone0.x86_pkg_temp"), Some(62.0));
+        assert_eq!(metric("fan.thinkpad.fan1"), Some(2450.0));
+        assert_eq!(metric("fan.count"), Some(1.0));
+    }
+
+    #[test]
+    fn amd_prefers_tdie_and_reads_legacy_device_attributes() {
+        let root = TempTree::new("hwmon-amd");
+        root.write("class/hwmon/hwmon1/device/name", "k10temp");
+        root.write("class/hwmon/hwmon1/device/temp1_label", "Tctl");
+        root.write("class/hwmon/hwmon1/device/temp1_input", "75000");
+        root.write("class/hwmon/hwmon1/device/temp2_label", "Tdie");
+        root.write("class/hwmon/hwmon1/device/temp2_input", "65000");
+
+        let data = HwmonProvider::with_root(root.path()).poll().unwrap();
+        assert_eq!(data.get("cpu.temperature").map(MetricValue::as_f64), Some(65.0));
+        assert_eq!(data.get("sensor.k10temp.tctl").map(MetricValue::as_f64), Some(75.0));
+    }
+
+    #[test]
+    fn falls_back_to_thermal_zones_without_hwmon() {
+        let root = TempTree::new("hwmon-zones");
+        root.write("class/thermal/thermal_zone0/type", "acpitz");
+        root.write("class/thermal/thermal_zone0/temp", "45000");
+
+        let data = HwmonProvider::with_root(root.path()).poll().unwrap();
+        assert_eq!(data.get("cpu.temperature").map(MetricValue::as_f64), Some(45.0));
+        assert_eq!(data.get("fan.count").map(MetricValue::as_f64), Some(0.0));
+    }
+
+    #[test]
+    fn missing_sysfs_is_unavailable() {
+        let root = TempTree::new("hwmon-missing");
+        let mut provider = HwmonProvider::with_root(root.path());
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+}
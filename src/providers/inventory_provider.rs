@@ -0,0 +1,566 @@
+//! Linux hardware inventory: the counterpart of `WmiProvider` for System Info.
+//!
+//! Reads below a sysfs root (normally `/sys`):
+//!
+//! - `class/dmi/id` — `board.manufacturer`, `board.product`, `board.version`,
+//!   `bios.manufacturer`, `bios.version`, `bios.date`, `system.manufacturer`
+//!   and `system.product`
+//! - `bus/pci/devices` — `pci.count` and `pci.N.{address,vendor_id,device_id,class,vendor,name,driver}`;
+//!   vendor and device names come from the `pci.ids` database when installed
+//! - `bus/usb/devices` — `usb.count` and `usb.N.{address,vendor_id,product_id,manufacturer,product,speed_mbps}`
+//! - `/proc/cpuinfo` — `cpu.name` and `cpu.vendor`, plus `cpu.max_clock_mhz` from cpufreq
+//!
+//! Memory modules come from `dmidecode --type 17` when it is installed and
+//! allowed to run (it needs root): `memory.dimm_count` and
+//! `memory.dimm.N.{locator,bank,type,manufacturer,part_number,size_bytes,speed_mts}`.
+//!
+//! Every `pci.N.*` and `usb.N.*` field is published on each poll; an empty
+//! string means the device does not report it.
+//!
+//! Serial numbers and UUIDs are deliberately not read.
+
+use super::sysfs::read_trimmed;
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Locations of the `pci.ids` database across distributions.
+const PCI_IDS_PATHS: [&str; 3] = [
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct PciDevice {
+    address: String,
+    vendor_id: String,
+    device_id: String,
+    class: u32,
+    driver: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct UsbDevice {
+    address: String,
+    vendor_id: String,
+    product_id: String,
+    manufacturer: Option<String>,
+    product: Option<String>,
+    speed_mbps: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct MemoryModule {
+    locator: String,
+    bank: Option<String>,
+    kind: Option<String>,
+    manufacturer: Option<String>,
+    part_number: Option<String>,
+    size_bytes: u64,
+    speed_mts: Option<u64>,
+}
+
+pub struct InventoryProvider {
+    sys_root: PathBuf,
+    proc_root: PathBuf,
+    pci_ids: Vec<PathBuf>,
+    /// `dmidecode` output never changes while running, so it is parsed once.
+    memory_modules: Option<Vec<MemoryModule>>,
+    available: bool,
+}
+
+impl InventoryProvider {
+    pub fn new() -> Self {
+        Self {
+            pci_ids: PCI_IDS_PATHS.iter().map(PathBuf::from).collect(),
+            memory_modules: None,
+            ..Self::with_roots("/sys", "/proc")
+        }
+    }
+
+    /// Read below `sys_root` and `proc_root`, without `pci.ids` or `dmidecode`.
+    pub fn with_roots(sys_root: impl Into<PathBuf>, proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            sys_root: sys_root.into(),
+            proc_root: proc_root.into(),
+            pci_ids: Vec::new(),
+            memory_modules: Some(Vec::new()),
+            available: false,
+        }
+    }
+
+    fn read_inventory(&mut self) -> Result<ProviderData, ProviderError> {
+        let dmi = self.sys_root.join("class/dmi/id");
+        let pci = read_pci_devices(&self.sys_root.join("bus/pci/devices"));
+        if !dmi.is_dir() && pci.is_empty() {
+            return Err(ProviderError::Unavailable(format!(
+                "no DMI or PCI information under {}",
+                self.sys_root.display()
+            )));
+        }
+
+        let mut data = ProviderData::new();
+
+        for (key, attribute) in [
+            ("board.manufacturer", "board_vendor"),
+            ("board.product", "board_name"),
+            ("board.version", "board_version"),
+            ("bios.manufacturer", "bios_vendor"),
+            ("bios.version", "bios_version"),
+            ("bios.date", "bios_date"),
+            ("system.manufacturer", "sys_vendor"),
+            ("system.product", "product_name"),
+        ] {
+            insert_text(
+                &mut data,
+                key.into(),
+                read_trimmed(&dmi.join(attribute)).filter(|value| !is_placeholder(value)),
+            );
+        }
+
+        if let Ok(cpuinfo) = std::fs::read_to_string(self.proc_root.join("cpuinfo")) {
+            insert_text(&mut data, "cpu.name".into(), cpuinfo_field(&cpuinfo, "model name"));
+            insert_text(&mut data, "cpu.vendor".into(), cpuinfo_field(&cpuinfo, "vendor_id"));
+        }
+
+        let names = self
+            .pci_ids
+            .iter()
+            .find_map(|path| std::fs::read_to_string(path).ok())
+            .map(|contents| PciNames::parse(&contents, &pci))
+            .unwrap_or_default();
+        for (index, device) in pci.iter().enumerate() {
+            let prefix = format!("pci.{index}");
+            for (name, value) in [
+                ("address", Some(device.address.clone())),
+                ("vendor_id", Some(device.vendor_id.clone())),
+                ("device_id", Some(device.device_id.clone())),
+                ("class", Some(class_name(device.class).into())),
+                ("vendor", names.vendors.get(&device.vendor_id).cloned()),
+                (
+                    "name",
+                    names
+                        .devices
+                        .get(&(device.vendor_id.clone(), device.device_id.clone()))
+                        .cloned(),
+                ),
+                ("driver", device.driver.clone()),
+            ] {
+                insert_device_field(&mut data, format!("{prefix}.{name}"), value);
+            }
+        }
+
+        let usb = read_usb_devices(&self.sys_root.join("bus/usb/devices"));
+        for (index, device) in usb.iter().enumerate() {
+            let prefix = format!("usb.{index}");
+            for (name, value) in [
+                ("address", Some(device.address.clone())),
+                ("vendor_id", Some(device.vendor_id.clone())),
+                ("product_id", Some(device.product_id.clone())),
+                ("manufacturer", device.manufacturer.clone()),
+                ("product", device.product.clone()),
+                ("speed_mbps", device.speed_mbps.clone()),
+            ] {
+                insert_device_field(&mut data, format!("{prefix}.{name}"), value);
+            }
+        }
+
+        let modules = self.memory_modules.get_or_insert_with(|| {
+            std::process::Command::new("dmidecode")
+                .args(["--type", "17"])
+                .output()
+                .map(|output| parse_dmidecode_memory(&String::from_utf8_lossy(&output.stdout)))
+                .unwrap_or_default()
+        });
+        for (index, module) in modules.iter().enumerate() {
+            let prefix = format!("memory.dimm.{index}");
+            insert_text(&mut data, format!("{prefix}.locator"), Some(module.locator.clone()));
+            insert_text(&mut data, format!("{prefix}.bank"), module.bank.clone());
+            insert_text(&mut data, format!("{prefix}.type"), module.kind.clone());
+            insert_text(&mut data, format!("{prefix}.manufacturer"), module.manufacturer.clone());
+            insert_text(&mut data, format!("{prefix}.part_number"), module.part_number.clone());
+            data.insert(format!("{prefix}.size_bytes"), MetricValue::UInt(module.size_bytes));
+            if let Some(speed) = module.speed_mts {
+                data.insert(format!("{prefix}.speed_mts"), MetricValue::UInt(speed));
+            }
+        }
+        data.insert("memory.dimm_count".into(), MetricValue::UInt(modules.len() as u64));
+        data.insert("pci.count".into(), MetricValue::UInt(pci.len() as u64));
+        data.insert("usb.count".into(), MetricValue::UInt(usb.len() as u64));
+        if let Some(khz) =
+            super::sysfs::read_u64(&self.sys_root.join("devices/system/cpu/cpu0/cpufreq/cpuinfo_max_freq"))
+        {
+            data.insert("cpu.max_clock_mhz".into(), MetricValue::UInt(khz / 1000));
+        }
+        Ok(data)
+    }
+}
+
+impl Default for InventoryProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for InventoryProvider {
+    fn name(&self) -> &str {
+        "inventory"
+    }
+
+    /// Slow poller: picks up hot-plugged USB and Thunderbolt devices once a minute.
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(60)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        let result = self.read_inventory();
+        self.available = result.is_ok();
+        result
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+fn insert_text(data: &mut ProviderData, key: String, value: Option<String>) {
+    if let Some(value) = value.filter(|value| !value.is_empty()) {
+        data.insert(key, MetricValue::Text(value));
+    }
+}
+
+/// Hot-plugged devices shift indices and the hub keeps labels it is no longer
+/// sent, so every per-device field is published, empty when absent.
+fn insert_device_field(data: &mut ProviderData, key: String, value: Option<String>) {
+    data.insert(key, MetricValue::Text(value.unwrap_or_default()));
+}
+
+/// Firmware fields OEMs leave unfilled.
+fn is_placeholder(value: &str) -> bool {
+    let lower = value.to_ascii_lowercase();
+    lower.is_empty()
+        || lower.contains("to be filled")
+        || lower == "default string"
+        || lower == "not applicable"
+        || lower == "system product name"
+        || lower == "system manufacturer"
+}
+
+/// First `name : value` line of `/proc/cpuinfo`.
+fn cpuinfo_field(cpuinfo: &str, name: &str) -> Option<String> {
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == name).then(|| value.trim().to_string())
+    })
+}
+
+/// Hex attribute files such as `0x8086` without the prefix.
+fn read_hex_id(path: &Path) -> Option<String> {
+    let value = read_trimmed(path)?;
+    Some(value.trim_start_matches("0x").to_ascii_lowercase())
+}
+
+fn read_pci_devices(dir: &Path) -> Vec<PciDevice> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut devices: Vec<PciDevice> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let path = entry.path();
+            Some(PciDevice {
+                address: entry.file_name().to_string_lossy().into_owned(),
+                vendor_id: read_hex_id(&path.join("vendor"))?,
+                device_id: read_hex_id(&path.join("device"))?,
+                class: u32::from_str_radix(&read_hex_id(&path.join("class"))?, 16).ok()?,
+                driver: std::fs::read_link(path.join("driver"))
+                    .ok()
+                    .and_then(|target| target.file_name().map(|name| name.to_string_lossy().into_owned())),
+            })
+        })
+        .collect();
+    devices.sort_by(|a, b| a.address.cmp(&b.address));
+    devices
+}
+
+/// Devices (not interfaces such as `1-2:1.0`) below `bus/usb/devices`; root hubs are kept
+/// so the bus layout stays visible.
+fn read_usb_devices(dir: &Path) -> Vec<UsbDevice> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut devices: Vec<UsbDevice> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_name().to_string_lossy().contains(':'))
+        .filter_map(|entry| {
+            let path = entry.path();
+            Some(UsbDevice {
+                address: entry.file_name().to_string_lossy().into_owned(),
+                vendor_id: read_hex_id(&path.join("idVendor"))?,
+                product_id: read_hex_id(&path.join("idProduct"))?,
+                manufacturer: read_trimmed(&path.join("manufacturer")),
+                product: read_trimmed(&path.join("product")),
+                speed_mbps: read_trimmed(&path.join("speed")),
+            })
+        })
+        .collect();
+    devices.sort_by(|a, b| a.address.cmp(&b.address));
+    devices
+}
+
+/// Vendor and device names from `pci.ids`, limited to the devices present.
+#[derive(Debug, Default)]
+struct PciNames {
+    vendors: HashMap<String, String>,
+    devices: HashMap<(String, String), String>,
+}
+
+impl PciNames {
+    /// Vendors start a line, devices are indented by one tab and subsystems by
+    /// two; the class list after the first `C ` line is skipped.
+    fn parse(contents: &str, present: &[PciDevice]) -> Self {
+        let mut names = Self::default();
+        let mut vendor: Option<&str> = None;
+        for line in contents.lines() {
+            if line.starts_with('#') || line.trim().is_empty() || line.starts_with("\t\t") {
+                continue;
+            }
+            if line.starts_with("C ") {
+                break;
+            }
+            let (id, name) = match line.trim_start().split_once("  ") {
+                Some((id, name)) => (id.trim(), name.trim()),
+                None => continue,
+            };
+            if !line.starts_with('\t') {
+                vendor = present.iter().any(|device| device.vendor_id == id).then_some(id);
+                if vendor.is_some() {
+                    names.vendors.insert(id.to_string(), name.to_string());
+                }
+            } else if let Some(vendor) = vendor {
+                if present
+                    .iter()
+                    .any(|device| device.vendor_id == vendor && device.device_id == id)
+                {
+                    names
+                        .devices
+                        .insert((vendor.to_string(), id.to_string()), name.to_string());
+                }
+            }
+        }
+        names
+    }
+}
+
+/// PCI base class from the 24-bit class code.
+fn class_name(class: u32) -> &'static str {
+    match class >> 16 {
+        0x00 => "Unclassified device",
+        0x01 => "Storage controller",
+        0x02 => "Network controller",
+        0x03 => "Display controller",
+        0x04 => "Multimedia controller",
+        0x05 => "Memory controller",
+        0x06 => "Bridge",
+        0x07 => "Communication controller",
+        0x08 => "System peripheral",
+        0x09 => "Input device controller",
+        0x0a => "Docking station",
+        0x0b => "Processor",
+        0x0c => match (class >> 8) & 0xff {
+            0x03 => "USB controller",
+            0x05 => "SMBus",
+            0x0a => "Thunderbolt controller",
+            _ => "Serial bus controller",
+        },
+        0x0d => "Wireless controller",
+        0x10 => "Encryption controller",
+        0x11 => "Signal processing controller",
+        0x12 => "Processing accelerator",
+        _ => "Other device",
+    }
+}
+
+/// Parse populated `Memory Device` blocks from `dmidecode --type 17`.
+fn parse_dmidecode_memory(output: &str) -> Vec<MemoryModule> {
+    output
+        .split("\n\n")
+        .filter(|block| block.lines().any(|line| line.trim() == "Memory Device"))
+        .filter_map(|block| {
+            let field = |name: &str| {
+                block.lines().find_map(|line| {
+                    let (key, value) = line.trim().split_once(": ")?;
+                    let value = value.trim();
+                    (key == name && !matches!(value, "Unknown" | "Not Specified" | "None" | ""))
+                        .then(|| value.to_string())
+                })
+            };
+            let size_bytes = parse_dmi_size(&field("Size")?)?;
+            Some(MemoryModule {
+                locator: field("Locator").unwrap_or_else(|| "DIMM".into()),
+                bank: field("Bank Locator"),
+                kind: field("Type"),
+                manufacturer: field("Manufacturer"),
+                part_number: field("Part Number"),
+                size_bytes,
+                speed_mts: field("Configured Memory Speed")
+                    .or_else(|| field("Speed"))
+                    .and_then(|speed| speed.split_whitespace().next()?.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+/// `16 GB`, `8192 MB`; `No Module Installed` yields `None`.
+fn parse_dmi_size(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let amount: u64 = parts.next()?.parse().ok()?;
+    let unit = match parts.next()? {
+        "kB" | "KB" => 1 << 10,
+        "MB" => 1 << 20,
+        "GB" => 1 << 30,
+        "TB" => 1 << 40,
+        _ => return None,
+    };
+    Some(amount * unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    const DMIDECODE: &str = "# dmidecode 3.5\nGetting SMBIOS data from sysfs.\nSMBIOS 3.3.0 present.\n\n\
+Handle 0x0040, DMI type 17, 92 bytes\nMemory Device\n\tTotal Width: 64 bits\n\tSize: 16 GB\n\tLocator: DIMM_A1\n\
+\tBank Locator: BANK 0\n\tType: DDR5\n\tSpeed: 5600 MT/s\n\tManufacturer: Kingston\n\tSerial Number: 12345678\n\
+\tPart Number: KF556C40-16\n\tConfigured Memory Speed: 4800 MT/s\n\n\
+Handle 0x0041, DMI type 17, 92 bytes\nMemory Device\n\tSize: No Module Installed\n\tLocator: DIMM_A2\n\
+\tType: Unknown\n\n\
+Handle 0x0042, DMI type 17, 92 bytes\nMemory Device\n\tSize: 8192 MB\n\tLocator: DIMM_B1\n\tType: DDR5\n\
+\tSpeed: Unknown\n\tManufacturer: Not Specified\n";
+
+    #[test]
+    fn parses_populated_dimms_only() {
+        let modules = parse_dmidecode_memory(DMIDECODE);
+        assert_eq!(modules.len(), 2);
+        assert_eq!(
+            modules[0],
+            MemoryModule {
+                locator: "DIMM_A1".into(),
+                bank: Some("BANK 0".into()),
+                kind: Some("DDR5".into()),
+                manufacturer: Some("Kingston".into()),
+                part_number: Some("KF556C40-16".into()),
+                size_bytes: 16 << 30,
+                speed_mts: Some(4800),
+            }
+        );
+        assert_eq!(modules[1].size_bytes, 8 << 30);
+        assert_eq!(modules[1].manufacturer, None);
+        assert_eq!(modules[1].speed_mts, None);
+    }
+
+    #[test]
+    fn pci_names_cover_present_devices() {
+        let present = [PciDevice {
+            vendor_id: "8086".into(),
+            device_id: "a7a0".into(),
+            ..Default::default()
+        }];
+        let names = PciNames::parse(
+            "# comment\n10de  NVIDIA Corporation\n\t2860  AD106M\n8086  Intel Corporation\n\
+             \ta7a0  Raptor Lake-P [Iris Xe Graphics]\n\t\t1028 0c1a  Subsystem\n\ta7a1  Other\n\
+             C 03  Display controller\n\t00  VGA compatible controller\n",
+            &present,
+        );
+        assert_eq!(names.vendors.len(), 1);
+        assert_eq!(names.vendors["8086"], "Intel Corporation");
+        assert_eq!(
+            names.devices[&("8086".to_string(), "a7a0".to_string())],
+            "Raptor Lake-P [Iris Xe Graphics]"
+        );
+        assert_eq!(names.devices.len(), 1);
+        assert_eq!(class_name(0x0c0330), "USB controller");
+        assert_eq!(class_name(0x030000), "Display controller");
+    }
+
+    #[test]
+    fn reads_dmi_pci_and_usb_from_sysfs() {
+        let root = TempTree::new("inventory");
+        let write = |path: &str, value: &str| root.write(Path::new("sys").join(path), value);
+        write("class/dmi/id/board_vendor", "Micro-Star International Co., Ltd.\n");
+        write("class/dmi/id/board_name", "MS-1582\n");
+        write("class/dmi/id/bios_version", "E1582IMS.10B\n");
+        write("class/dmi/id/product_name", "To be filled by O.E.M.\n");
+        write("bus/pci/devices/0000:00:02.0/vendor", "0x8086\n");
+        write("bus/pci/devices/0000:00:02.0/device", "0xA7A0\n");
+        write("bus/pci/devices/0000:00:02.0/class", "0x030000\n");
+        write("bus/usb/devices/1-2/idVendor", "046d\n");
+        write("bus/usb/devices/1-2/idProduct", "c52b\n");
+        write("bus/usb/devices/1-2/product", "USB Receiver\n");
+        write("bus/usb/devices/1-2/speed", "12\n");
+        write("bus/usb/devices/1-2:1.0/bInterfaceClass", "03\n");
+        root.write(
+            "proc/cpuinfo",
+            "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: 13th Gen Intel(R) Core(TM) i7-13700H\n",
+        );
+
+        let mut provider = InventoryProvider::with_roots(root.join("sys"), root.join("proc"));
+        let data = provider.poll().unwrap();
+        let label = |key: &str| match &data[key] {
+            MetricValue::Text(value) => value.clone(),
+            other => panic!("{key} is {other:?}"),
+        };
+        assert!(provider.is_available());
+        assert_eq!(label("board.manufacturer"), "Micro-Star International Co., Ltd.");
+        assert_eq!(label("bios.version"), "E1582IMS.10B");
+        assert!(!data.contains_key("system.product"));
+        assert_eq!(label("cpu.name"), "13th Gen Intel(R) Core(TM) i7-13700H");
+        assert_eq!(data["pci.count"].as_f64(), 1.0);
+        assert_eq!(label("pci.0.device_id"), "a7a0");
+        assert_eq!(label("pci.0.class"), "Display controller");
+        assert_eq!(data["usb.count"].as_f64(), 1.0);
+        assert_eq!(label("usb.0.product"), "USB Receiver");
+        assert_eq!(data["memory.dimm_count"].as_f64(), 0.0);
+
+        root.remove("sys");
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+
+    #[test]
+    fn removed_devices_leave_no_fields_behind() {
+        let root = TempTree::new("inventory-hotplug");
+        root.write("bus/pci/devices/0000:00:02.0/vendor", "0x8086\n");
+        root.write("bus/pci/devices/0000:00:02.0/device", "0xa7a0\n");
+        root.write("bus/pci/devices/0000:00:02.0/class", "0x030000\n");
+        for (address, product) in [("1-1", "Keyboard"), ("1-2", "USB Receiver")] {
+            root.write(format!("bus/usb/devices/{address}/idVendor"), "046d\n");
+            root.write(format!("bus/usb/devices/{address}/idProduct"), "c52b\n");
+            root.write(format!("bus/usb/devices/{address}/product"), product);
+        }
+        root.write("bus/usb/devices/1-1/manufacturer", "Logitech\n");
+        root.write("bus/usb/devices/1-1/speed", "12\n");
+
+        let mut provider = InventoryProvider::with_roots(root.path(), root.join("proc"));
+        let first = provider.poll().unwrap();
+        assert!(matches!(&first["usb.0.manufacturer"], MetricValue::Text(value) if value == "Logitech"));
+        assert!(matches!(&first["usb.1.speed_mbps"], MetricValue::Text(value) if value.is_empty()));
+
+        root.remove("bus/usb/devices/1-1");
+        let second = provider.poll().unwrap();
+        let label = |key: &str| match &second[key] {
+            MetricValue::Text(value) => value.as_str(),
+            other => panic!("{key} is {other:?}"),
+        };
+        assert_eq!(second["usb.count"].as_f64(), 1.0);
+        assert_eq!(label("usb.0.address"), "1-2");
+        assert_eq!(label("usb.0.product"), "USB Receiver");
+        assert_eq!(label("usb.0.manufacturer"), "");
+        assert_eq!(label("usb.0.speed_mbps"), "");
+        assert_eq!(label("pci.0.vendor"), "");
+        assert_eq!(label("pci.0.driver"), "");
+    }
+}
@@ -0,0 +1,212 @@
+//! Linux run-queue and scheduler activity from `/proc/loadavg` and `/proc/stat`.
+//!
+//! Published keys:
+//!
+//! - `load.avg_1m` / `load.avg_5m` / `load.avg_15m` — load averages
+//! - `load.procs_running` / `load.procs_blocked` — tasks runnable now and tasks
+//!   in uninterruptible sleep (usually waiting on I/O)
+//! - `load.context_switches_per_sec`, `load.interrupts_per_sec`,
+//!   `load.softirqs_per_sec`, `load.forks_per_sec`
+//!
+//! The `/proc/stat` counters are cumulative, so the rates appear from the second poll.
+
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use crate::monitoring::rates::counter_rate;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Cumulative `/proc/stat` counters and the instantaneous task counts beside them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct StatCounters {
+    context_switches: u64,
+    interrupts: u64,
+    softirqs: u64,
+    forks: u64,
+    procs_running: u64,
+    procs_blocked: u64,
+}
+
+pub struct LoadProvider {
+    root: PathBuf,
+    previous: Option<(StatCounters, Instant)>,
+    available: bool,
+}
+
+impl LoadProvider {
+    pub fn new() -> Self {
+        Self::with_root("/proc")
+    }
+
+    /// Read `loadavg` and `stat` below `root` instead of `/proc`.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            previous: None,
+            available: false,
+        }
+    }
+
+    fn poll_at(&mut self, now: Instant) -> Result<ProviderData, ProviderError> {
+        let path = self.root.join("loadavg");
+        let averages = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| parse_loadavg(&contents));
+        let Some(averages) = averages else {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!("cannot read {}", path.display())));
+        };
+
+        let mut data = ProviderData::new();
+        for (key, value) in [
+            ("avg_1m", averages[0]),
+            ("avg_5m", averages[1]),
+            ("avg_15m", averages[2]),
+        ] {
+            data.insert(format!("load.{key}"), MetricValue::Float(value));
+        }
+
+        if let Some(counters) = std::fs::read_to_string(self.root.join("stat"))
+            .ok()
+            .map(|contents| parse_stat(&contents))
+        {
+            data.insert("load.procs_running".into(), MetricValue::UInt(counters.procs_running));
+            data.insert("load.procs_blocked".into(), MetricValue::UInt(counters.procs_blocked));
+            if let Some((previous, then)) = self.previous {
+                let elapsed = now.saturating_duration_since(then);
+                for (key, before, current) in [
+                    (
+                        "context_switches_per_sec",
+                        previous.context_switches,
+                        counters.context_switches,
+                    ),
+                    ("interrupts_per_sec", previous.interrupts, counters.interrupts),
+                    ("softirqs_per_sec", previous.softirqs, counters.softirqs),
+                    ("forks_per_sec", previous.forks, counters.forks),
+                ] {
+                    let rate = counter_rate(Some(before), current, elapsed);
+                    data.insert(format!("load.{key}"), MetricValue::Float(rate.value_per_second));
+                }
+            }
+            self.previous = Some((counters, now));
+        }
+
+        self.available = true;
+        Ok(data)
+    }
+}
+
+impl Default for LoadProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for LoadProvider {
+    fn name(&self) -> &str {
+        "loadavg"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        self.poll_at(Instant::now())
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+/// Parse the three averages from `0.52 0.58 0.59 2/1234 56789`.
+fn parse_loadavg(contents: &str) -> Option<[f64; 3]> {
+    let mut fields = contents.split_whitespace().map(|field| field.parse::<f64>().ok());
+    Some([fields.next()??, fields.next()??, fields.next()??])
+}
+
+/// Pick the scheduler lines out of `/proc/stat`. `intr` and `softirq` lines
+/// start with the total, followed by one column per source.
+fn parse_stat(contents: &str) -> StatCounters {
+    let mut counters = StatCounters::default();
+    for line in contents.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(name), Some(value)) = (fields.next(), fields.next().and_then(|value| value.parse().ok())) else {
+            continue;
+        };
+        match name {
+            "ctxt" => counters.context_switches = value,
+            "intr" => counters.interrupts = value,
+            "softirq" => counters.softirqs = value,
+            "processes" => counters.forks = value,
+            "procs_running" => counters.procs_running = value,
+            "procs_blocked" => counters.procs_blocked = value,
+            _ => {}
+        }
+    }
+    counters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    const STAT: &str = "cpu  10132153 290696 3084719 46828483 16683 0 25195 0 0 0\n\
+                        cpu0 1393280 32966 572056 13343292 6130 0 17875 0 0 0\n\
+                        intr 1462898 126 9 0 0 0 0 3 0 1 0\n\
+                        ctxt 115315\n\
+                        btime 1769000000\n\
+                        processes 8120\n\
+                        procs_running 3\n\
+                        procs_blocked 1\n\
+                        softirq 2001 0 500 1 300 0 0 200 0 0 1000\n";
+
+    #[test]
+    fn parses_loadavg_and_stat() {
+        assert_eq!(parse_loadavg("0.52 0.58 0.59 2/1234 56789\n"), Some([0.52, 0.58, 0.59]));
+        assert_eq!(parse_loadavg("0.52 nope"), None);
+        assert_eq!(
+            parse_stat(STAT),
+            StatCounters {
+                context_switches: 115_315,
+                interrupts: 1_462_898,
+                softirqs: 2001,
+                forks: 8120,
+                procs_running: 3,
+                procs_blocked: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn rates_between_two_polls() {
+        let root = TempTree::new("loadavg");
+        root.write("loadavg", "1.50 1.00 0.50 3/900 4242\n");
+        root.write("stat", STAT);
+
+        let mut provider = LoadProvider::with_root(root.path());
+        let start = Instant::now();
+        let first = provider.poll_at(start).unwrap();
+        assert!(provider.is_available());
+        assert_eq!(first["load.avg_1m"].as_f64(), 1.5);
+        assert_eq!(first["load.procs_blocked"].as_f64(), 1.0);
+        assert!(!first.contains_key("load.context_switches_per_sec"));
+
+        let later = STAT
+            .replace("ctxt 115315", "ctxt 135315")
+            .replace("intr 1462898", "intr 1464898")
+            .replace("processes 8120", "processes 8130");
+        root.write("stat", &later);
+        let second = provider.poll_at(start + Duration::from_secs(2)).unwrap();
+        assert_eq!(second["load.context_switches_per_sec"].as_f64(), 10_000.0);
+        assert_eq!(second["load.interrupts_per_sec"].as_f64(), 1000.0);
+        assert_eq!(second["load.softirqs_per_sec"].as_f64(), 0.0);
+        assert_eq!(second["load.forks_per_sec"].as_f64(), 5.0);
+
+        root.remove("loadavg");
+        root.remove("stat");
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+}
@@ -0,0 +1,221 @@
+//! Linux memory composition from `/proc/meminfo` and paging activity from `/proc/vmstat`.
+//!
+//! Reads both files below a procfs root (normally `/proc`). Published keys,
+//! all in bytes unless noted:
+//!
+//! - `memory.free`, `memory.cached`, `memory.buffers`, `memory.shared`
+//! - `memory.slab_reclaimable` / `memory.slab_unreclaimable`
+//! - `memory.dirty` / `memory.writeback` — pages waiting for or under write-back
+//! - `memory.hugepages_total` / `memory.hugepages_free` — the static huge page pool
+//! - `memory.committed` / `memory.commit_limit` — commit charge (`Committed_AS`) and its limit
+//! - `memory.swap_in_per_sec` / `memory.swap_out_per_sec` — pages swapped per second
+//! - `memory.major_faults_per_sec` — page faults that had to read from disk
+//!
+//! `vmstat` counters are cumulative, so the rates appear from the second poll.
+
+use super::sysfs::parse_meminfo;
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use crate::monitoring::rates::counter_rate;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// `/proc/meminfo` fields (reported in KiB) and the key each is published under.
+const MEMINFO_FIELDS: [(&str, &str); 10] = [
+    ("MemFree", "memory.free"),
+    ("Cached", "memory.cached"),
+    ("Buffers", "memory.buffers"),
+    ("Shmem", "memory.shared"),
+    ("SReclaimable", "memory.slab_reclaimable"),
+    ("SUnreclaim", "memory.slab_unreclaimable"),
+    ("Dirty", "memory.dirty"),
+    ("Writeback", "memory.writeback"),
+    ("Committed_AS", "memory.committed"),
+    ("CommitLimit", "memory.commit_limit"),
+];
+
+/// Cumulative `/proc/vmstat` counters and the rate key each feeds.
+const VMSTAT_COUNTERS: [(&str, &str); 3] = [
+    ("pswpin", "memory.swap_in_per_sec"),
+    ("pswpout", "memory.swap_out_per_sec"),
+    ("pgmajfault", "memory.major_faults_per_sec"),
+];
+
+pub struct MeminfoProvider {
+    root: PathBuf,
+    previous: Option<(HashMap<String, u64>, Instant)>,
+    available: bool,
+}
+
+impl MeminfoProvider {
+    pub fn new() -> Self {
+        Self::with_root("/proc")
+    }
+
+    /// Read `meminfo` and `vmstat` below `root` instead of `/proc`.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            previous: None,
+            available: false,
+        }
+    }
+
+    fn poll_at(&mut self, now: Instant) -> Result<ProviderData, ProviderError> {
+        let path = self.root.join("meminfo");
+        let Ok(meminfo) = std::fs::read_to_string(&path) else {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!("cannot read {}", path.display())));
+        };
+        let fields = parse_meminfo(&meminfo);
+
+        let mut data = ProviderData::new();
+        for (field, key) in MEMINFO_FIELDS {
+            if let Some(bytes) = fields.get(field) {
+                data.insert(key.into(), MetricValue::UInt(*bytes));
+            }
+        }
+        // HugePages_* are page counts; Hugepagesize is the size of one page.
+        if let Some(page_size) = fields.get("Hugepagesize") {
+            for (field, key) in [
+                ("HugePages_Total", "memory.hugepages_total"),
+                ("HugePages_Free", "memory.hugepages_free"),
+            ] {
+                if let Some(pages) = fields.get(field) {
+                    data.insert(key.into(), MetricValue::UInt(pages.saturating_mul(*page_size)));
+                }
+            }
+        }
+        if data.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!(
+                "no known fields in {}",
+                path.display()
+            )));
+        }
+
+        let counters = std::fs::read_to_string(self.root.join("vmstat"))
+            .map(|vmstat| parse_vmstat(&vmstat))
+            .unwrap_or_default();
+        for (counter, key) in VMSTAT_COUNTERS {
+            let Some(current) = counters.get(counter) else {
+                continue;
+            };
+            let (before, elapsed) = match &self.previous {
+                Some((previous, then)) => (previous.get(counter).copied(), now.saturating_duration_since(*then)),
+                None => (None, Duration::ZERO),
+            };
+            let rate = counter_rate(before, *current, elapsed);
+            if rate.initialized {
+                data.insert(key.into(), MetricValue::Float(rate.value_per_second));
+            }
+        }
+        self.previous = Some((counters, now));
+
+        self.available = true;
+        Ok(data)
+    }
+}
+
+impl Default for MeminfoProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for MeminfoProvider {
+    fn name(&self) -> &str {
+        "meminfo"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        self.poll_at(Instant::now())
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+/// Parse `name value` lines, keeping only the counters this provider uses.
+fn parse_vmstat(contents: &str) -> HashMap<String, u64> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once(' ')?;
+            if !VMSTAT_COUNTERS.iter().any(|(counter, _)| *counter == name) {
+                return None;
+            }
+            Some((name.to_string(), value.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    const MEMINFO: &str = "MemTotal:       16316412 kB\n\
+                           MemFree:         1048576 kB\n\
+                           MemAvailable:    9437184 kB\n\
+                           Buffers:          262144 kB\n\
+                           Cached:          6291456 kB\n\
+                           Shmem:            524288 kB\n\
+                           SReclaimable:     786432 kB\n\
+                           SUnreclaim:       131072 kB\n\
+                           Dirty:               512 kB\n\
+                           Writeback:             0 kB\n\
+                           CommitLimit:    12345678 kB\n\
+                           Committed_AS:   20971520 kB\n\
+                           HugePages_Total:       4\n\
+                           HugePages_Free:        1\n\
+                           Hugepagesize:       2048 kB\n";
+
+    #[test]
+    fn meminfo_values_become_bytes() {
+        let fields = parse_meminfo(MEMINFO);
+        assert_eq!(fields["Cached"], 6_291_456 * 1024);
+        assert_eq!(fields["HugePages_Total"], 4);
+        assert_eq!(fields["Hugepagesize"], 2048 * 1024);
+        assert_eq!(
+            parse_vmstat("nr_free_pages 100\npswpin 12\npswpout 40\npgmajfault 7\n"),
+            HashMap::from([
+                ("pswpin".to_string(), 12),
+                ("pswpout".to_string(), 40),
+                ("pgmajfault".to_string(), 7),
+            ])
+        );
+    }
+
+    #[test]
+    fn polls_a_fake_procfs_tree() {
+        let root = TempTree::new("meminfo");
+        root.write("meminfo", MEMINFO);
+        root.write("vmstat", "pswpin 100\npswpout 200\npgmajfault 1000\n");
+
+        let mut provider = MeminfoProvider::with_root(root.path());
+        let start = Instant::now();
+        let first = provider.poll_at(start).unwrap();
+        assert!(provider.is_available());
+        assert_eq!(first["memory.slab_unreclaimable"].as_f64(), 131_072.0 * 1024.0);
+        assert_eq!(first["memory.hugepages_total"].as_f64(), 4.0 * 2048.0 * 1024.0);
+        assert_eq!(first["memory.hugepages_free"].as_f64(), 2048.0 * 1024.0);
+        assert!(!first.contains_key("memory.swap_out_per_sec"));
+
+        root.write("vmstat", "pswpin 100\npswpout 600\npgmajfault 1050\n");
+        let second = provider.poll_at(start + Duration::from_secs(2)).unwrap();
+        assert_eq!(second["memory.swap_in_per_sec"].as_f64(), 0.0);
+        assert_eq!(second["memory.swap_out_per_sec"].as_f64(), 200.0);
+        assert_eq!(second["memory.major_faults_per_sec"].as_f64(), 25.0);
+
+        root.remove("meminfo");
+        root.remove("vmstat");
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+}
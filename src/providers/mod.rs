@@ -110,7 +110,18 @@ pub trait TelemetryProvider: Send {
     fn shutdown(&mut self) {}
 }
 
+pub mod container_provider;
+pub mod diskstats_provider;
+pub mod drm_provider;
+pub mod hwmon_provider;
+pub mod inventory_provider;
+pub mod loadavg_provider;
+pub mod meminfo_provider;
 pub mod nvml_provider;
+pub mod psi_provider;
+pub(crate) mod sysfs;
 pub mod sysinfo_provider;
+pub mod throttle_provider;
+pub mod virtualization_provider;
 pub mod windows_gpu_provider;
 pub mod wmi_provider;
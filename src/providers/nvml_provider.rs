@@ -30,6 +30,11 @@ impl NvmlProvider {
             },
         }
     }
+
+    /// Number of GPUs this provider publishes (zero when NVML is unavailable).
+    pub fn device_count(&self) -> u32 {
+        self.device_count
+    }
 }
 
 impl Default for NvmlProvider {
@@ -0,0 +1,182 @@
+//! Linux pressure stall information from `/proc/pressure`.
+//!
+//! Reads `pressure/{cpu,memory,io}` below a procfs root (normally `/proc`).
+//! Each resource reports a `some` line (at least one task stalled) and a
+//! `full` line (every non-idle task stalled at once). Published keys:
+//!
+//! - `pressure.<resource>.<some|full>.avg10` / `avg60` / `avg300` — percent of
+//!   wall time spent stalled over the trailing 10 s, 60 s and 300 s
+//! - `pressure.<resource>.<some|full>.total_us` — cumulative stall time in µs
+//!
+//! The system-wide CPU `full` line is undefined and always zero, so it is
+//! skipped.
+
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct PressureLine {
+    avg10: f64,
+    avg60: f64,
+    avg300: f64,
+    total_us: u64,
+}
+
+pub struct PsiProvider {
+    root: PathBuf,
+    available: bool,
+}
+
+impl PsiProvider {
+    pub fn new() -> Self {
+        Self::with_root("/proc")
+    }
+
+    /// Read pressure files below `root` instead of `/proc`.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            available: false,
+        }
+    }
+}
+
+impl Default for PsiProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for PsiProvider {
+    fn name(&self) -> &str {
+        "psi"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        let pressure_dir = self.root.join("pressure");
+        let mut data = ProviderData::new();
+        for resource in RESOURCES {
+            // Reading fails with EOPNOTSUPP when the kernel was booted with `psi=0`.
+            let Ok(contents) = std::fs::read_to_string(pressure_dir.join(resource)) else {
+                continue;
+            };
+            for (kind, line) in parse_pressure(&contents) {
+                if resource == "cpu" && kind == "full" {
+                    continue;
+                }
+                let prefix = format!("pressure.{resource}.{kind}");
+                data.insert(format!("{prefix}.avg10"), MetricValue::Float(line.avg10));
+                data.insert(format!("{prefix}.avg60"), MetricValue::Float(line.avg60));
+                data.insert(format!("{prefix}.avg300"), MetricValue::Float(line.avg300));
+                data.insert(format!("{prefix}.total_us"), MetricValue::UInt(line.total_us));
+            }
+        }
+        if data.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!(
+                "no pressure stall information under {}",
+                pressure_dir.display()
+            )));
+        }
+
+        self.available = true;
+        Ok(data)
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+/// Parse `some avg10=0.12 avg60=0.05 avg300=0.01 total=12345` lines.
+fn parse_pressure(contents: &str) -> Vec<(&str, PressureLine)> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let kind = fields.next().filter(|kind| matches!(*kind, "some" | "full"))?;
+            let mut pressure = PressureLine::default();
+            for field in fields {
+                let (name, value) = field.split_once('=')?;
+                match name {
+                    "avg10" => pressure.avg10 = value.parse().ok()?,
+                    "avg60" => pressure.avg60 = value.parse().ok()?,
+                    "avg300" => pressure.avg300 = value.parse().ok()?,
+                    "total" => pressure.total_us = value.parse().ok()?,
+                    _ => {}
+                }
+            }
+            Some((kind, pressure))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    #[test]
+    fn parses_some_and_full_lines() {
+        let parsed = parse_pressure(
+            "some avg10=12.50 avg60=4.02 avg300=1.00 total=987654\n\
+             full avg10=3.25 avg60=0.50 avg300=0.10 total=1234\n",
+        );
+        assert_eq!(
+            parsed,
+            vec![
+                (
+                    "some",
+                    PressureLine {
+                        avg10: 12.5,
+                        avg60: 4.02,
+                        avg300: 1.0,
+                        total_us: 987_654,
+                    }
+                ),
+                (
+                    "full",
+                    PressureLine {
+                        avg10: 3.25,
+                        avg60: 0.5,
+                        avg300: 0.1,
+                        total_us: 1234,
+                    }
+                ),
+            ]
+        );
+        assert!(parse_pressure("some avg10=bogus avg60=0 avg300=0 total=0").is_empty());
+    }
+
+    #[test]
+    fn polls_a_fake_procfs_tree() {
+        let root = TempTree::new("psi");
+        root.write(
+            "pressure/cpu",
+            "some avg10=1.00 avg60=2.00 avg300=3.00 total=100\nfull avg10=0.00 avg60=0.00 avg300=0.00 total=0\n",
+        );
+        root.write(
+            "pressure/memory",
+            "some avg10=20.00 avg60=10.00 avg300=5.00 total=5000\nfull avg10=8.00 avg60=4.00 avg300=2.00 total=2000\n",
+        );
+
+        let mut provider = PsiProvider::with_root(root.path());
+        let data = provider.poll().unwrap();
+        assert!(provider.is_available());
+        assert_eq!(data["pressure.memory.full.avg10"].as_f64(), 8.0);
+        assert_eq!(data["pressure.cpu.some.total_us"].as_f64(), 100.0);
+        assert!(!data.contains_key("pressure.cpu.full.avg10"));
+        assert!(!data.contains_key("pressure.io.some.avg10"));
+
+        root.remove("pressure");
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+}
@@ -0,0 +1,166 @@
+//! Small helpers for reading Linux pseudo-filesystems (`/sys`, `/proc`).
+//!
+//! Every reader takes an explicit path so providers can be pointed at a fake
+//! tree in tests. Attribute files are tiny, so whole-file reads are fine.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Read an attribute file and strip the trailing newline.
+pub(crate) fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|value| value.trim().to_string())
+}
+
+/// Read an attribute file holding a single unsigned integer.
+pub(crate) fn read_u64(path: &Path) -> Option<u64> {
+    read_parsed(path)
+}
+
+/// Read an attribute file holding a single signed integer.
+pub(crate) fn read_i64(path: &Path) -> Option<i64> {
+    read_parsed(path)
+}
+
+/// Read an attribute file holding a single value of any parseable type.
+pub(crate) fn read_parsed<T: std::str::FromStr>(path: &Path) -> Option<T> {
+    read_trimmed(path)?.parse().ok()
+}
+
+/// Parse `/proc/meminfo` `Name:   1234 kB` lines. Values with a `kB` unit are
+/// converted to bytes; unitless values (the huge page counts) are returned as-is.
+pub(crate) fn parse_meminfo(contents: &str) -> HashMap<&str, u64> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.split_once(':')?;
+            let mut fields = rest.split_whitespace();
+            let value: u64 = fields.next()?.parse().ok()?;
+            let value = match fields.next() {
+                Some("kB") => value.saturating_mul(1024),
+                _ => value,
+            };
+            Some((name.trim(), value))
+        })
+        .collect()
+}
+
+/// List the entries of `dir` whose file name starts with `prefix`, sorted by
+/// the numeric suffix (`hwmon2` before `hwmon10`) and then by name.
+pub(crate) fn numbered_entries(dir: &Path, prefix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<(Option<u64>, PathBuf)> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let suffix = name.strip_prefix(prefix)?;
+            Some((suffix.parse().ok(), entry.path()))
+        })
+        .collect();
+    paths.sort();
+    paths.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Normalize a free-form sensor/device name into a metric key segment:
+/// lowercase ASCII alphanumerics separated by single underscores.
+pub(crate) fn key_segment(raw: &str) -> String {
+    let mut segment = String::with_capacity(raw.len());
+    for character in raw.chars() {
+        if character.is_ascii_alphanumeric() {
+            segment.push(character.to_ascii_lowercase());
+        } else if !segment.is_empty() && !segment.ends_with('_') {
+            segment.push('_');
+        }
+    }
+    while segment.ends_with('_') {
+        segment.pop();
+    }
+    if segment.is_empty() {
+        segment.push_str("unknown");
+    }
+    segment
+}
+
+/// A scratch directory standing in for `/sys` or `/proc` in tests. Each tree
+/// gets its own directory, removed when the guard drops, also on panic.
+#[cfg(test)]
+pub(crate) struct TempTree(PathBuf);
+
+#[cfg(test)]
+impl TempTree {
+    pub(crate) fn new(name: &str) -> Self {
+        static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+        let id = NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let root = std::env::temp_dir().join(format!("sysmon-{name}-{}-{id}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).expect("create temp tree");
+        Self(root)
+    }
+
+    /// A tree holding `files`, given as `(relative path, contents)` pairs.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn with_files(name: &str, files: &[(&str, &str)]) -> Self {
+        let tree = Self::new(name);
+        for (relative, contents) in files {
+            tree.write(relative, contents);
+        }
+        tree
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Write `contents` to `relative`, creating the directories above it.
+    pub(crate) fn write(&self, relative: impl AsRef<Path>, contents: &str) {
+        let path = self.0.join(relative);
+        fs::create_dir_all(path.parent().expect("file below the root")).expect("create parent");
+        fs::write(path, contents).expect("write fixture");
+    }
+
+    /// Create a symlink at `relative` pointing to `target`, creating the
+    /// directories above it, as for `/proc/PID/fd` entries.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn symlink(&self, relative: impl AsRef<Path>, target: impl AsRef<Path>) {
+        let path = self.0.join(relative);
+        fs::create_dir_all(path.parent().expect("link below the root")).expect("create parent");
+        std::os::unix::fs::symlink(target, path).expect("create symlink");
+    }
+
+    /// Remove a file or directory below the root, as when a device goes away.
+    pub(crate) fn remove(&self, relative: impl AsRef<Path>) {
+        let path = self.0.join(relative);
+        let _ = fs::remove_dir_all(&path).or_else(|_| fs::remove_file(&path));
+    }
+}
+
+#[cfg(test)]
+impl std::ops::Deref for TempTree {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl Drop for TempTree {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_segment_collapses_punctuation() {
+        assert_eq!(key_segment("Package id 0"), "package_id_0");
+        assert_eq!(key_segment("  Tctl "), "tctl");
+        assert_eq!(key_segment("cpu-thermal"), "cpu_thermal");
+        assert_eq!(key_segment("--"), "unknown");
+    }
+}
@@ -0,0 +1,195 @@
+//! Linux thermal throttling counters from `/sys/devices/system/cpu`.
+//!
+//! Intel CPUs expose `cpuN/thermal_throttle/{core,package}_throttle_count`,
+//! which the kernel bumps every time a core or package crosses its thermal
+//! limit and is clocked down. Published keys:
+//!
+//! - `cpu.throttle.core_count` / `cpu.throttle.package_count` — cumulative
+//!   throttle events since boot
+//! - `cpu.throttle.core_events_per_sec` / `cpu.throttle.package_events_per_sec`
+//!   — events since the previous poll
+//!
+//! SMT siblings report the same core counter and every CPU in a package reports
+//! the same package counter, so counters are deduplicated by `core_id` and
+//! `physical_package_id` before summing. CPUs without the counters (AMD, ARM)
+//! poll successfully with no keys.
+
+use super::sysfs::{numbered_entries, read_u64};
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use crate::monitoring::rates::counter_rate;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct ThrottleCounters {
+    core: u64,
+    package: u64,
+}
+
+pub struct ThrottleProvider {
+    root: PathBuf,
+    previous: Option<(ThrottleCounters, Instant)>,
+    available: bool,
+}
+
+impl ThrottleProvider {
+    pub fn new() -> Self {
+        Self::with_root("/sys/devices/system/cpu")
+    }
+
+    /// Read `cpuN` directories below `root` instead of `/sys/devices/system/cpu`.
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            previous: None,
+            available: false,
+        }
+    }
+
+    /// Sum the deduplicated counters, or `None` when no CPU exposes them.
+    fn read_counters(&self, cpus: &[PathBuf]) -> Option<ThrottleCounters> {
+        let mut cores = BTreeMap::new();
+        let mut packages = BTreeMap::new();
+        for cpu in cpus {
+            let throttle = cpu.join("thermal_throttle");
+            let Some(core_count) = read_u64(&throttle.join("core_throttle_count")) else {
+                continue;
+            };
+            let package = read_u64(&cpu.join("topology/physical_package_id")).unwrap_or_default();
+            let core = read_u64(&cpu.join("topology/core_id")).unwrap_or_default();
+            cores.insert((package, core), core_count);
+            if let Some(package_count) = read_u64(&throttle.join("package_throttle_count")) {
+                packages.insert(package, package_count);
+            }
+        }
+        (!cores.is_empty()).then(|| ThrottleCounters {
+            core: cores.values().sum(),
+            package: packages.values().sum(),
+        })
+    }
+
+    fn poll_at(&mut self, now: Instant) -> Result<ProviderData, ProviderError> {
+        let cpus: Vec<PathBuf> = numbered_entries(&self.root, "cpu")
+            .into_iter()
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| name.strip_prefix("cpu"))
+                    .is_some_and(|suffix| suffix.parse::<u32>().is_ok())
+            })
+            .collect();
+        if cpus.is_empty() {
+            self.available = false;
+            return Err(ProviderError::Unavailable(format!(
+                "no CPUs listed under {}",
+                self.root.display()
+            )));
+        }
+
+        let mut data = ProviderData::new();
+        if let Some(counters) = self.read_counters(&cpus) {
+            data.insert("cpu.throttle.core_count".into(), MetricValue::UInt(counters.core));
+            data.insert("cpu.throttle.package_count".into(), MetricValue::UInt(counters.package));
+            if let Some((previous, then)) = self.previous {
+                let elapsed = now.saturating_duration_since(then);
+                for (key, before, current) in [
+                    ("core_events_per_sec", previous.core, counters.core),
+                    ("package_events_per_sec", previous.package, counters.package),
+                ] {
+                    let rate = counter_rate(Some(before), current, elapsed);
+                    data.insert(format!("cpu.throttle.{key}"), MetricValue::Float(rate.value_per_second));
+                }
+            }
+            self.previous = Some((counters, now));
+        }
+
+        self.available = true;
+        Ok(data)
+    }
+}
+
+impl Default for ThrottleProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for ThrottleProvider {
+    fn name(&self) -> &str {
+        "thermal_throttle"
+    }
+
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        self.poll_at(Instant::now())
+    }
+
+    fn is_available(&self) -> bool {
+        self.available
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    fn write_cpu(root: &TempTree, cpu: u32, core_id: u32, core: u64, package: u64) {
+        let dir = format!("cpu{cpu}");
+        root.write(format!("{dir}/topology/physical_package_id"), "0\n");
+        root.write(format!("{dir}/topology/core_id"), &format!("{core_id}\n"));
+        root.write(
+            format!("{dir}/thermal_throttle/core_throttle_count"),
+            &format!("{core}\n"),
+        );
+        root.write(
+            format!("{dir}/thermal_throttle/package_throttle_count"),
+            &format!("{package}\n"),
+        );
+    }
+
+    #[test]
+    fn deduplicates_siblings_and_reports_rates() {
+        let root = TempTree::new("throttle");
+        // cpu0 and cpu2 are SMT siblings of core 0; all three share package 0.
+        write_cpu(&root, 0, 0, 10, 40);
+        write_cpu(&root, 1, 1, 5, 40);
+        write_cpu(&root, 2, 0, 10, 40);
+        std::fs::create_dir_all(root.join("cpufreq")).unwrap();
+
+        let mut provider = ThrottleProvider::with_root(root.path());
+        let start = Instant::now();
+        let first = provider.poll_at(start).unwrap();
+        assert!(provider.is_available());
+        assert_eq!(first["cpu.throttle.core_count"].as_f64(), 15.0);
+        assert_eq!(first["cpu.throttle.package_count"].as_f64(), 40.0);
+        assert!(!first.contains_key("cpu.throttle.core_events_per_sec"));
+
+        write_cpu(&root, 0, 0, 14, 48);
+        write_cpu(&root, 2, 0, 14, 48);
+        write_cpu(&root, 1, 1, 5, 48);
+        let second = provider.poll_at(start + Duration::from_secs(2)).unwrap();
+        assert_eq!(second["cpu.throttle.core_events_per_sec"].as_f64(), 2.0);
+        assert_eq!(second["cpu.throttle.package_events_per_sec"].as_f64(), 4.0);
+
+        for cpu in ["cpu0", "cpu1", "cpu2"] {
+            root.remove(cpu);
+        }
+        assert!(matches!(provider.poll(), Err(ProviderError::Unavailable(_))));
+        assert!(!provider.is_available());
+    }
+
+    #[test]
+    fn cpus_without_counters_poll_empty() {
+        let root = TempTree::new("throttle-none");
+        std::fs::create_dir_all(root.join("cpu0/topology")).unwrap();
+
+        let mut provider = ThrottleProvider::with_root(root.path());
+        assert!(provider.poll().unwrap().is_empty());
+        assert!(provider.is_available());
+    }
+}
@@ -0,0 +1,320 @@
+//! Hypervisor detection and CPU steal time.
+//!
+//! The hypervisor is identified once, from the first source that names one:
+//!
+//! 1. `/sys/hypervisor/type` (Xen guests)
+//! 2. CPUID — the "hypervisor present" bit of leaf 1 and the vendor signature
+//!    of leaf `0x4000_0000` (x86 only)
+//! 3. DMI `sys_vendor` / `product_name`, for hypervisors that hide from CPUID
+//!
+//! Published keys:
+//!
+//! - `virt.is_virtual` — 1 inside a virtual machine, 0 otherwise
+//! - `virt.hypervisor` / `virt.source` — labels, only when a hypervisor was found
+//! - `cpu.steal_percent` — share of CPU time since the previous poll that the
+//!   hypervisor spent running something else while this guest had work to do
+//!
+//! Steal comes from the aggregate `cpu` line of `/proc/stat`, so it is Linux
+//! only and appears from the second poll. Bare metal polls successfully with
+//! `virt.is_virtual` = 0 and a steal of zero.
+
+use super::sysfs::read_trimmed;
+use super::{MetricValue, ProviderData, ProviderError, TelemetryProvider};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Hypervisor {
+    name: String,
+    source: &'static str,
+}
+
+/// Aggregate jiffies from the `cpu` line of `/proc/stat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct CpuTimes {
+    total: u64,
+    steal: u64,
+}
+
+pub struct VirtualizationProvider {
+    sys_root: PathBuf,
+    proc_root: PathBuf,
+    use_cpuid: bool,
+    /// `None` until the first poll; the hypervisor cannot change while running.
+    hypervisor: Option<Option<Hypervisor>>,
+    previous: Option<CpuTimes>,
+}
+
+impl VirtualizationProvider {
+    pub fn new() -> Self {
+        Self {
+            use_cpuid: true,
+            ..Self::with_roots("/sys", "/proc")
+        }
+    }
+
+    /// Read below `sys_root` and `proc_root` and skip CPUID, so the result
+    /// does not depend on the machine running the tests.
+    pub fn with_roots(sys_root: impl Into<PathBuf>, proc_root: impl Into<PathBuf>) -> Self {
+        Self {
+            sys_root: sys_root.into(),
+            proc_root: proc_root.into(),
+            use_cpuid: false,
+            hypervisor: None,
+            previous: None,
+        }
+    }
+
+    fn detect(&self) -> Option<Hypervisor> {
+        let found = |name: &str, source| {
+            Some(Hypervisor {
+                name: name.to_string(),
+                source,
+            })
+        };
+        if let Some(kind) = read_trimmed(&self.sys_root.join("hypervisor/type")).filter(|kind| !kind.is_empty()) {
+            return found(&hypervisor_type_name(&kind), "/sys/hypervisor");
+        }
+        if self.use_cpuid {
+            if let Some(name) = cpuid_hypervisor() {
+                return found(&name, "CPUID");
+            }
+        }
+        let dmi = self.sys_root.join("class/dmi/id");
+        let vendor = read_trimmed(&dmi.join("sys_vendor")).unwrap_or_default();
+        let product = read_trimmed(&dmi.join("product_name")).unwrap_or_default();
+        dmi_hypervisor(&vendor, &product).and_then(|name| found(name, "DMI"))
+    }
+
+    fn read_cpu_times(&self) -> Option<CpuTimes> {
+        std::fs::read_to_string(self.proc_root.join("stat"))
+            .ok()
+            .and_then(|contents| parse_cpu_times(&contents))
+    }
+}
+
+impl Default for VirtualizationProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TelemetryProvider for VirtualizationProvider {
+    fn name(&self) -> &str {
+        "virtualization"
+    }
+
+    /// Once a second, so the diagnostics five-minute steal average has enough samples.
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn poll(&mut self) -> Result<ProviderData, ProviderError> {
+        if self.hypervisor.is_none() {
+            self.hypervisor = Some(self.detect());
+        }
+        let mut data = ProviderData::new();
+        let hypervisor = self.hypervisor.as_ref().and_then(Option::as_ref);
+        data.insert("virt.is_virtual".into(), MetricValue::UInt(hypervisor.is_some() as u64));
+        if let Some(hypervisor) = hypervisor {
+            data.insert("virt.hypervisor".into(), MetricValue::Text(hypervisor.name.clone()));
+            data.insert("virt.source".into(), MetricValue::Text(hypervisor.source.into()));
+        }
+
+        if let Some(times) = self.read_cpu_times() {
+            if let Some(previous) = self.previous {
+                data.insert(
+                    "cpu.steal_percent".into(),
+                    MetricValue::Float(steal_percent(previous, times)),
+                );
+            }
+            self.previous = Some(times);
+        }
+        Ok(data)
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+}
+
+/// Parse the aggregate `cpu  user nice system idle iowait irq softirq steal guest guest_nice`
+/// line. Guest time is already counted in `user`/`nice`, so only the first eight columns add
+/// up to the total.
+fn parse_cpu_times(contents: &str) -> Option<CpuTimes> {
+    let line = contents.lines().find(|line| line.starts_with("cpu "))?;
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .take(8)
+        .map(|value| value.parse().ok())
+        .collect::<Option<_>>()?;
+    Some(CpuTimes {
+        total: values.iter().sum(),
+        // Kernels before 2.6.11 have no steal column.
+        steal: values.get(7).copied().unwrap_or_default(),
+    })
+}
+
+fn steal_percent(before: CpuTimes, after: CpuTimes) -> f64 {
+    let total = after.total.saturating_sub(before.total);
+    if total == 0 {
+        return 0.0;
+    }
+    after.steal.saturating_sub(before.steal) as f64 / total as f64 * 100.0
+}
+
+fn hypervisor_type_name(kind: &str) -> String {
+    match kind {
+        "xen" => "Xen".into(),
+        other => other.to_string(),
+    }
+}
+
+/// Map a CPUID leaf `0x4000_0000` vendor signature to a display name.
+fn hypervisor_vendor_name(signature: &str) -> String {
+    match signature {
+        "KVMKVMKVM" => "KVM".into(),
+        "Microsoft Hv" => "Hyper-V".into(),
+        "VMwareVMware" => "VMware".into(),
+        "XenVMMXenVMM" => "Xen".into(),
+        "VBoxVBoxVBox" => "VirtualBox".into(),
+        "TCGTCGTCGTCG" => "QEMU (TCG)".into(),
+        "prl hyperv" => "Parallels".into(),
+        "ACRNACRNACRN" => "ACRN".into(),
+        "bhyve bhyve" => "bhyve".into(),
+        "QNXQVMBSQG" => "QNX".into(),
+        "" => "Unknown hypervisor".into(),
+        other => other.to_string(),
+    }
+}
+
+/// Recognize well-known virtual platforms from their DMI system vendor and product.
+fn dmi_hypervisor(vendor: &str, product: &str) -> Option<&'static str> {
+    let known = [
+        ("QEMU", "QEMU"),
+        ("VMware", "VMware"),
+        ("innotek", "VirtualBox"),
+        ("Xen", "Xen"),
+        ("Amazon EC2", "Amazon EC2"),
+        ("Google", "Google Compute Engine"),
+        ("Parallels", "Parallels"),
+        ("Bochs", "Bochs"),
+    ];
+    if let Some((_, name)) = known.iter().find(|(prefix, _)| vendor.starts_with(prefix)) {
+        return Some(name);
+    }
+    if vendor.starts_with("Microsoft") && product.contains("Virtual Machine") {
+        return Some("Hyper-V");
+    }
+    match product {
+        "KVM" | "Standard PC (Q35 + ICH9, 2009)" | "Standard PC (i440FX + PIIX, 1996)" => Some("KVM"),
+        "VirtualBox" => Some("VirtualBox"),
+        _ => None,
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)] // `__cpuid` is only `unsafe` on older toolchains.
+fn cpuid_hypervisor() -> Option<String> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // SAFETY: CPUID is available on every x86 CPU able to run this binary.
+    let features = unsafe { __cpuid(1) };
+    if features.ecx & (1 << 31) == 0 {
+        return None;
+    }
+    let vendor = unsafe { __cpuid(0x4000_0000) };
+    let bytes: Vec<u8> = [vendor.ebx, vendor.ecx, vendor.edx]
+        .iter()
+        .flat_map(|register| register.to_le_bytes())
+        .collect();
+    let signature = String::from_utf8_lossy(&bytes)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string();
+    // Windows with Hyper-V or VBS enabled runs as the root partition, which also sees
+    // the hypervisor bit. The root is the only partition allowed to create partitions.
+    if signature == "Microsoft Hv" && vendor.eax >= 0x4000_0003 {
+        let privileges = unsafe { __cpuid(0x4000_0003) };
+        if privileges.ebx & 1 != 0 {
+            return None;
+        }
+    }
+    Some(hypervisor_vendor_name(&signature))
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn cpuid_hypervisor() -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::sysfs::TempTree;
+
+    #[test]
+    fn parses_cpu_times_and_steal() {
+        let stat = "cpu  100 0 50 800 10 0 0 40 20 0\ncpu0 50 0 25 400 5 0 0 20 10 0\n";
+        assert_eq!(parse_cpu_times(stat), Some(CpuTimes { total: 1000, steal: 40 }));
+        assert_eq!(parse_cpu_times("cpu0 1 2 3\n"), None);
+
+        let before = CpuTimes { total: 1000, steal: 40 };
+        let after = CpuTimes {
+            total: 1400,
+            steal: 100,
+        };
+        assert_eq!(steal_percent(before, after), 15.0);
+        assert_eq!(steal_percent(after, after), 0.0);
+    }
+
+    #[test]
+    fn names_hypervisors_from_cpuid_and_dmi() {
+        assert_eq!(hypervisor_vendor_name("KVMKVMKVM"), "KVM");
+        assert_eq!(hypervisor_vendor_name("Microsoft Hv"), "Hyper-V");
+        assert_eq!(dmi_hypervisor("innotek GmbH", "VirtualBox"), Some("VirtualBox"));
+        assert_eq!(
+            dmi_hypervisor("Microsoft Corporation", "Virtual Machine"),
+            Some("Hyper-V")
+        );
+        assert_eq!(dmi_hypervisor("Microsoft Corporation", "Surface Laptop 5"), None);
+        assert_eq!(dmi_hypervisor("Dell Inc.", "OptiPlex 7090"), None);
+    }
+
+    #[test]
+    fn detects_hypervisor_and_reports_steal_between_polls() {
+        let root = TempTree::new("virt");
+        let (sys, proc) = (root.join("sys"), root.join("proc"));
+        root.write("sys/class/dmi/id/sys_vendor", "QEMU\n");
+        root.write("sys/class/dmi/id/product_name", "Standard PC (Q35 + ICH9, 2009)\n");
+        root.write("proc/stat", "cpu  100 0 50 800 10 0 0 40 0 0\n");
+
+        let mut provider = VirtualizationProvider::with_roots(&sys, &proc);
+        let first = provider.poll().unwrap();
+        assert_eq!(first["virt.is_virtual"].as_f64(), 1.0);
+        assert!(matches!(&first["virt.hypervisor"], MetricValue::Text(name) if name == "QEMU"));
+        assert!(matches!(&first["virt.source"], MetricValue::Text(source) if source == "DMI"));
+        assert!(!first.contains_key("cpu.steal_percent"));
+
+        // `/sys/hypervisor` would win, but detection only runs once.
+        root.write("sys/hypervisor/type", "xen\n");
+        root.write("proc/stat", "cpu  300 0 50 900 10 0 0 140 0 0\n");
+        let second = provider.poll().unwrap();
+        assert!(matches!(&second["virt.hypervisor"], MetricValue::Text(name) if name == "QEMU"));
+        assert_eq!(second["cpu.steal_percent"].as_f64(), 25.0);
+
+        let mut xen = VirtualizationProvider::with_roots(&sys, &proc);
+        let data = xen.poll().unwrap();
+        assert!(matches!(&data["virt.hypervisor"], MetricValue::Text(name) if name == "Xen"));
+
+        let empty = root.join("empty");
+        let mut bare = VirtualizationProvider::with_roots(&empty, &empty);
+        let data = bare.poll().unwrap();
+        assert_eq!(data["virt.is_virtual"].as_f64(), 0.0);
+        assert!(!data.contains_key("virt.hypervisor"));
+    }
+}
@@ -60,8 +60,10 @@ impl WmiProvider {
             }
 
             // Processor identity
-            let cpu_query: Result<Vec<HashMap<String, Variant>>, _> = wmi_con
-                .raw_query("SELECT Name, MaxClockSpeed, NumberOfCores, NumberOfLogicalProcessors FROM Win32_Processor");
+            let cpu_query: Result<Vec<HashMap<String, Variant>>, _> = wmi_con.raw_query(
+                "SELECT Name, MaxClockSpeed, NumberOfCores, NumberOfLogicalProcessors, L2CacheSize, L3CacheSize \
+                     FROM Win32_Processor",
+            );
             if let Ok(results) = cpu_query {
                 if let Some(row) = results.first() {
                     if let Some(Variant::String(v)) = row.get("Name") {
@@ -76,6 +78,12 @@ impl WmiProvider {
                     if let Some(Variant::UI4(v)) = row.get("NumberOfLogicalProcessors") {
                         data.insert("cpu.logical_processors".into(), MetricValue::UInt(*v as u64));
                     }
+                    if let Some(Variant::UI4(v)) = row.get("L2CacheSize") {
+                        data.insert("cpu.l2_cache_kb".into(), MetricValue::UInt(*v as u64));
+                    }
+                    if let Some(Variant::UI4(v)) = row.get("L3CacheSize") {
+                        data.insert("cpu.l3_cache_kb".into(), MetricValue::UInt(*v as u64));
+                    }
                 }
             }
 
@@ -1,9 +1,6 @@
 // src/services.rs
-use serde::Deserialize;
 use std::fmt;
 use std::time::{Duration, Instant};
-use windows_service::service::{ServiceState, ServiceStatus};
-use wmi::WMIConnection;
 
 #[derive(Debug, Clone)]
 pub struct ServiceInfo {
@@ -73,6 +70,65 @@ impl fmt::Display for ServiceControlAction {
     }
 }
 
+/// Lifecycle state of a service, normalized across service managers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceState {
+    Stopped,
+    StartPending,
+    StopPending,
+    Running,
+    ContinuePending,
+    PausePending,
+    Paused,
+}
+
+impl ServiceState {
+    /// Label in the form the Services page displays and filters on.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stopped => "Stopped",
+            Self::StartPending => "Start Pending",
+            Self::StopPending => "Stop Pending",
+            Self::Running => "Running",
+            Self::ContinuePending => "Continue Pending",
+            Self::PausePending => "Pause Pending",
+            Self::Paused => "Paused",
+        }
+    }
+}
+
+impl fmt::Display for ServiceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// A status sample used by `wait_for_state` to tell a slow transition from a stuck one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceProgress {
+    pub state: ServiceState,
+    /// Increments while a pending operation makes progress (0 if the backend has no notion of it).
+    pub checkpoint: u32,
+    /// Backend estimate of how long the next step may take; zero when unknown.
+    pub wait_hint: Duration,
+}
+
+/// Operations a platform service manager must provide for listing and control.
+///
+/// Errors are plain detail strings; `control_service` attaches the phase they
+/// occurred in so every backend reports failures the same way.
+pub trait ServiceManager {
+    fn list_services(&self) -> Result<Vec<ServiceInfo>, String>;
+    fn query(&self, name: &str) -> Result<ServiceProgress, String>;
+    /// State before a control request. Managers whose `query` reports a failed
+    /// service as an error read it as stopped here, so it can be started again.
+    fn current_state(&self, name: &str) -> Result<ServiceState, String> {
+        self.query(name).map(|status| status.state)
+    }
+    fn start(&self, name: &str) -> Result<(), String>;
+    fn stop(&self, name: &str) -> Result<(), String>;
+}
+
 #[derive(Debug, Clone)]
 pub struct ServiceControlOutcome {
     pub action: ServiceControlAction,
@@ -81,7 +137,7 @@ pub struct ServiceControlOutcome {
 
 impl fmt::Display for ServiceControlOutcome {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} completed; service is {:?}", self.action, self.final_state)
+        write!(f, "{} completed; service is {}", self.action, self.final_state)
     }
 }
 
@@ -105,13 +161,13 @@ pub enum ServiceControlError {
 impl fmt::Display for ServiceControlError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::OpenManager(detail) => write!(f, "Could not open the Windows service manager: {detail}"),
+            Self::OpenManager(detail) => write!(f, "Could not open the service manager: {detail}"),
             Self::OpenService(detail) => write!(f, "Could not open the service: {detail}"),
             Self::RequestFailed { phase, detail } => write!(f, "Service {phase} request failed: {detail}"),
             Self::TimedOut { phase, last_state } => {
                 write!(
                     f,
-                    "Service {phase} timed out after 30 seconds (last state: {last_state:?})"
+                    "Service {phase} timed out after 30 seconds (last state: {last_state})"
                 )
             }
             Self::PartialRestart { detail } => write!(f, "Service stopped but could not be restarted: {detail}"),
@@ -120,27 +176,25 @@ impl fmt::Display for ServiceControlError {
 }
 
 fn wait_for_state(
-    service: &windows_service::service::Service,
+    manager: &dyn ServiceManager,
+    name: &str,
     target: ServiceState,
     phase: &'static str,
-) -> Result<ServiceStatus, ServiceControlError> {
+) -> Result<ServiceProgress, ServiceControlError> {
     let hard_deadline = Instant::now() + Duration::from_secs(30);
     let mut last_progress = None;
     let mut progress_deadline = hard_deadline;
 
     loop {
-        let status = service
-            .query_status()
-            .map_err(|error| ServiceControlError::RequestFailed {
-                phase,
-                detail: error.to_string(),
-            })?;
-        if status.current_state == target {
+        let status = manager
+            .query(name)
+            .map_err(|detail| ServiceControlError::RequestFailed { phase, detail })?;
+        if status.state == target {
             return Ok(status);
         }
 
         let now = Instant::now();
-        let progress = (status.current_state, status.checkpoint);
+        let progress = (status.state, status.checkpoint);
         if last_progress != Some(progress) {
             last_progress = Some(progress);
             let hint = if status.wait_hint.is_zero() {
@@ -153,7 +207,7 @@ fn wait_for_state(
         if now >= hard_deadline || now >= progress_deadline {
             return Err(ServiceControlError::TimedOut {
                 phase,
-                last_state: status.current_state,
+                last_state: status.state,
             });
         }
 
@@ -166,114 +220,159 @@ fn wait_for_state(
     }
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(rename_all = "PascalCase")]
-#[allow(non_camel_case_types)]
-struct Win32_Service {
-    name: String,
-    display_name: Option<String>,
-    state: String,
-}
-
-pub fn send_service_control(
+/// Start, stop or restart `name` through `manager`, waiting for the target state.
+pub fn control_service(
+    manager: &dyn ServiceManager,
     name: &str,
     action: ServiceControlAction,
 ) -> Result<ServiceControlOutcome, ServiceControlError> {
-    let manager = windows_service::service_manager::ServiceManager::local_computer(
-        None::<&str>,
-        windows_service::service_manager::ServiceManagerAccess::CONNECT,
-    )
-    .map_err(|error| ServiceControlError::OpenManager(error.to_string()))?;
-    let desired_access = windows_service::service::ServiceAccess::QUERY_STATUS
-        | windows_service::service::ServiceAccess::START
-        | windows_service::service::ServiceAccess::STOP;
-    let service = manager
-        .open_service(name, desired_access)
-        .map_err(|error| ServiceControlError::OpenService(error.to_string()))?;
+    let current = manager.current_state(name).map_err(ServiceControlError::OpenService)?;
     match action {
         ServiceControlAction::Start => {
-            if service
-                .query_status()
-                .is_ok_and(|status| status.current_state == ServiceState::Running)
-            {
+            if current == ServiceState::Running {
                 return Ok(ServiceControlOutcome {
                     action,
                     final_state: ServiceState::Running,
                 });
             }
-            let empty: Vec<String> = Vec::new();
-            service
-                .start(&empty)
-                .map_err(|error| ServiceControlError::RequestFailed {
-                    phase: "start",
-                    detail: error.to_string(),
-                })?;
-            let status = wait_for_state(&service, ServiceState::Running, "start")?;
+            manager
+                .start(name)
+                .map_err(|detail| ServiceControlError::RequestFailed { phase: "start", detail })?;
+            let status = wait_for_state(manager, name, ServiceState::Running, "start")?;
             Ok(ServiceControlOutcome {
                 action,
-                final_state: status.current_state,
+                final_state: status.state,
             })
         }
         ServiceControlAction::Stop => {
-            if service
-                .query_status()
-                .is_ok_and(|status| status.current_state == ServiceState::Stopped)
-            {
+            if current == ServiceState::Stopped {
                 return Ok(ServiceControlOutcome {
                     action,
                     final_state: ServiceState::Stopped,
                 });
             }
-            service.stop().map_err(|error| ServiceControlError::RequestFailed {
-                phase: "stop",
-                detail: error.to_string(),
-            })?;
-            let status = wait_for_state(&service, ServiceState::Stopped, "stop")?;
+            manager
+                .stop(name)
+                .map_err(|detail| ServiceControlError::RequestFailed { phase: "stop", detail })?;
+            let status = wait_for_state(manager, name, ServiceState::Stopped, "stop")?;
             Ok(ServiceControlOutcome {
                 action,
-                final_state: status.current_state,
+                final_state: status.state,
             })
         }
         ServiceControlAction::Restart => {
-            if !service
-                .query_status()
-                .is_ok_and(|status| status.current_state == ServiceState::Stopped)
-            {
-                service.stop().map_err(|error| ServiceControlError::RequestFailed {
-                    phase: "restart stop",
-                    detail: error.to_string(),
-                })?;
-                wait_for_state(&service, ServiceState::Stopped, "restart stop")?;
+            if current != ServiceState::Stopped {
+                manager
+                    .stop(name)
+                    .map_err(|detail| ServiceControlError::RequestFailed {
+                        phase: "restart stop",
+                        detail,
+                    })?;
+                wait_for_state(manager, name, ServiceState::Stopped, "restart stop")?;
             }
 
-            let empty: Vec<String> = Vec::new();
-            service
-                .start(&empty)
-                .map_err(|error| ServiceControlError::PartialRestart {
-                    detail: error.to_string(),
-                })?;
-            let status = wait_for_state(&service, ServiceState::Running, "restart start").map_err(|error| {
+            manager
+                .start(name)
+                .map_err(|detail| ServiceControlError::PartialRestart { detail })?;
+            let status = wait_for_state(manager, name, ServiceState::Running, "restart start").map_err(|error| {
                 ServiceControlError::PartialRestart {
                     detail: error.to_string(),
                 }
             })?;
             Ok(ServiceControlOutcome {
                 action,
-                final_state: status.current_state,
+                final_state: status.state,
             })
         }
     }
 }
 
-pub fn get_services() -> Vec<ServiceInfo> {
-    get_services_with_com(None)
-}
+#[cfg(target_os = "windows")]
+mod windows_impl {
+    use super::*;
+    use serde::Deserialize;
+    use windows_service::service::{Service, ServiceAccess};
+    use windows_service::service_manager::{ServiceManager as ScManager, ServiceManagerAccess};
+    use wmi::WMIConnection;
+
+    #[derive(Deserialize, Debug)]
+    #[serde(rename_all = "PascalCase")]
+    #[allow(non_camel_case_types)]
+    struct Win32_Service {
+        name: String,
+        display_name: Option<String>,
+        state: String,
+    }
+
+    impl From<windows_service::service::ServiceState> for ServiceState {
+        fn from(state: windows_service::service::ServiceState) -> Self {
+            use windows_service::service::ServiceState as Native;
+            match state {
+                Native::Stopped => Self::Stopped,
+                Native::StartPending => Self::StartPending,
+                Native::StopPending => Self::StopPending,
+                Native::Running => Self::Running,
+                Native::ContinuePending => Self::ContinuePending,
+                Native::PausePending => Self::PausePending,
+                Native::Paused => Self::Paused,
+            }
+        }
+    }
+
+    /// Service Control Manager backend; listing goes through WMI `Win32_Service`.
+    pub struct WindowsServiceManager {
+        manager: ScManager,
+    }
+
+    impl WindowsServiceManager {
+        pub fn connect() -> Result<Self, ServiceControlError> {
+            ScManager::local_computer(None::<&str>, ServiceManagerAccess::CONNECT)
+                .map(|manager| Self { manager })
+                .map_err(|error| ServiceControlError::OpenManager(error.to_string()))
+        }
+
+        fn open(&self, name: &str) -> Result<Service, String> {
+            let desired_access = ServiceAccess::QUERY_STATUS | ServiceAccess::START | ServiceAccess::STOP;
+            self.manager
+                .open_service(name, desired_access)
+                .map_err(|error| error.to_string())
+        }
+    }
+
+    impl ServiceManager for WindowsServiceManager {
+        fn list_services(&self) -> Result<Vec<ServiceInfo>, String> {
+            Ok(get_services_with_com(None))
+        }
+
+        fn query(&self, name: &str) -> Result<ServiceProgress, String> {
+            let status = self.open(name)?.query_status().map_err(|error| error.to_string())?;
+            Ok(ServiceProgress {
+                state: status.current_state.into(),
+                checkpoint: status.checkpoint,
+                wait_hint: status.wait_hint,
+            })
+        }
+
+        fn start(&self, name: &str) -> Result<(), String> {
+            let empty: Vec<String> = Vec::new();
+            self.open(name)?.start(&empty).map_err(|error| error.to_string())
+        }
 
-pub fn get_services_with_com(com: Option<&std::rc::Rc<wmi::COMLibrary>>) -> Vec<ServiceInfo> {
-    let mut result = Vec::new();
+        fn stop(&self, name: &str) -> Result<(), String> {
+            self.open(name)?.stop().map(|_| ()).map_err(|error| error.to_string())
+        }
+    }
 
-    if let Some(com_lib) = com {
-        if let Ok(wmi_con) = WMIConnection::new(com_lib.clone()) {
+    pub fn get_services_with_com(com: Option<&std::rc::Rc<wmi::COMLibrary>>) -> Vec<ServiceInfo> {
+        let com_lib = match com {
+            Some(com_lib) => com_lib.clone(),
+            None => match crate::providers::init_com() {
+                Ok(com_lib) => std::rc::Rc::new(com_lib),
+                Err(_) => return Vec::new(),
+            },
+        };
+        let mut result = Vec::new();
+        if let Ok(wmi_con) = WMIConnection::new(com_lib) {
             let results: Result<Vec<Win32_Service>, _> =
                 wmi_con.raw_query("SELECT Name, DisplayName, State FROM Win32_Service");
             if let Ok(services) = results {
@@ -286,29 +385,488 @@ pub fn get_services_with_com(com: Option<&std::rc::Rc<wmi::COMLibrary>>) -> Vec<
                 }
             }
         }
-    } else if let Ok(com_lib) = crate::providers::init_com() {
-        if let Ok(wmi_con) = WMIConnection::new(std::rc::Rc::new(com_lib)) {
-            let results: Result<Vec<Win32_Service>, _> =
-                wmi_con.raw_query("SELECT Name, DisplayName, State FROM Win32_Service");
-            if let Ok(services) = results {
-                for svc in services {
-                    result.push(ServiceInfo {
-                        name: svc.name,
-                        display_name: svc.display_name.unwrap_or_default(),
-                        state: svc.state,
-                    });
+        result.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+        result
+    }
+}
+
+#[cfg(target_os = "windows")]
+pub use windows_impl::get_services_with_com;
+
+#[cfg(target_os = "linux")]
+pub mod systemd {
+    //! systemd backend driven through `systemctl`.
+    //!
+    //! Commands go through a `CommandTransport` so tests (or a future D-Bus
+    //! client) can stand in for the real `systemctl` binary.
+
+    use super::*;
+    use std::cell::Cell;
+
+    /// Runs a `systemctl` invocation and returns its stdout, or a failure detail.
+    pub trait CommandTransport {
+        fn systemctl(&self, args: &[&str]) -> Result<String, String>;
+    }
+
+    /// Spawns the system `systemctl` binary.
+    pub struct SystemctlCommand;
+
+    impl CommandTransport for SystemctlCommand {
+        fn systemctl(&self, args: &[&str]) -> Result<String, String> {
+            let output = std::process::Command::new("systemctl")
+                .args(args)
+                .output()
+                .map_err(|error| format!("could not run systemctl: {error}"))?;
+            if output.status.success() {
+                Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+            } else {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                Err(if stderr.is_empty() {
+                    format!("systemctl exited with {}", output.status)
+                } else {
+                    stderr
+                })
+            }
+        }
+    }
+
+    /// Transitional states are still moving, so give them the longest progress window.
+    const PENDING_WAIT_HINT: Duration = Duration::from_secs(10);
+
+    pub struct SystemdServiceManager<T> {
+        transport: T,
+        /// `InactiveExitTimestampMonotonic` when the last start was requested. A
+        /// later value means the unit has run since, even if it is inactive again.
+        start_baseline: Cell<Option<u64>>,
+        /// Samples seen in `activating`/`deactivating`, reported as the checkpoint.
+        pending_samples: Cell<u32>,
+    }
+
+    impl<T: CommandTransport> SystemdServiceManager<T> {
+        pub fn new(transport: T) -> Self {
+            Self {
+                transport,
+                start_baseline: Cell::new(None),
+                pending_samples: Cell::new(0),
+            }
+        }
+
+        fn show(&self, name: &str) -> Result<String, String> {
+            self.transport.systemctl(&[
+                "show",
+                "--property=LoadState,ActiveState,SubState,Result,InactiveExitTimestampMonotonic",
+                "--no-pager",
+                "--",
+                name,
+            ])
+        }
+    }
+
+    impl<T: CommandTransport> ServiceManager for SystemdServiceManager<T> {
+        fn list_services(&self) -> Result<Vec<ServiceInfo>, String> {
+            let output = self.transport.systemctl(&[
+                "list-units",
+                "--type=service",
+                "--all",
+                "--plain",
+                "--no-legend",
+                "--no-pager",
+            ])?;
+            let mut services = parse_list_units(&output);
+            services.sort_by(|a, b| a.display_name.cmp(&b.display_name));
+            Ok(services)
+        }
+
+        /// systemd enforces its own start and stop timeouts on a pending job, so
+        /// a unit still activating or deactivating is moving: every such sample
+        /// advances the checkpoint and only the overall deadline applies.
+        fn query(&self, name: &str) -> Result<ServiceProgress, String> {
+            let output = self.show(name)?;
+            let mut status = parse_show(name, &output)?;
+            match status.state {
+                ServiceState::StartPending | ServiceState::StopPending => {
+                    self.pending_samples.set(self.pending_samples.get().wrapping_add(1));
+                    status.checkpoint = self.pending_samples.get();
+                }
+                // A oneshot (or any unit whose process exits cleanly) goes back to
+                // inactive once its run succeeds; that run is what the start asked for.
+                ServiceState::Stopped
+                    if show_property(&output, "Result") == "success"
+                        && self.start_baseline.get().is_some_and(|baseline| {
+                            inactive_exit_timestamp(&output).is_some_and(|exited| exited > baseline)
+                        }) =>
+                {
+                    status.state = ServiceState::Running;
+                }
+                _ => {}
+            }
+            Ok(status)
+        }
+
+        fn current_state(&self, name: &str) -> Result<ServiceState, String> {
+            let output = self.show(name)?;
+            if show_property(&output, "ActiveState") == "failed" {
+                return Ok(ServiceState::Stopped);
+            }
+            parse_show(name, &output).map(|status| status.state)
+        }
+
+        fn start(&self, name: &str) -> Result<(), String> {
+            let baseline = self.show(name).ok().and_then(|output| inactive_exit_timestamp(&output));
+            self.start_baseline.set(baseline);
+            self.transport
+                .systemctl(&["start", "--no-block", "--", name])
+                .map(|_| ())
+        }
+
+        fn stop(&self, name: &str) -> Result<(), String> {
+            self.transport
+                .systemctl(&["stop", "--no-block", "--", name])
+                .map(|_| ())
+        }
+    }
+
+    /// Map a unit's `ActiveState`/`SubState` pair onto the shared lifecycle states.
+    pub(super) fn unit_state(active: &str, sub: &str) -> ServiceState {
+        match (active, sub) {
+            ("active" | "reloading" | "refreshing", _) => ServiceState::Running,
+            ("activating", _) => ServiceState::StartPending,
+            ("deactivating", _) => ServiceState::StopPending,
+            _ => ServiceState::Stopped,
+        }
+    }
+
+    /// Display label for `ServiceInfo.state`; failed units keep their own label.
+    fn unit_state_label(active: &str, sub: &str) -> String {
+        match (active, sub) {
+            ("failed", _) | (_, "failed") => "Failed".to_string(),
+            ("activating", "auto-restart") => "Restarting".to_string(),
+            _ => unit_state(active, sub).label().to_string(),
+        }
+    }
+
+    /// Parse `systemctl list-units --plain --no-legend` rows:
+    /// `UNIT LOAD ACTIVE SUB DESCRIPTION...`.
+    pub(super) fn parse_list_units(output: &str) -> Vec<ServiceInfo> {
+        output
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim_start_matches(['●', '*', ' ']);
+                let mut fields = line.split_whitespace();
+                let unit = fields.next()?;
+                let load = fields.next()?;
+                let active = fields.next()?;
+                let sub = fields.next()?;
+                if load == "not-found" || !unit.ends_with(".service") {
+                    return None;
+                }
+                let description = fields.collect::<Vec<_>>().join(" ");
+                Some(ServiceInfo {
+                    name: unit.to_string(),
+                    display_name: if description.is_empty() {
+                        unit.trim_end_matches(".service").to_string()
+                    } else {
+                        description
+                    },
+                    state: unit_state_label(active, sub),
+                })
+            })
+            .collect()
+    }
+
+    fn show_property<'a>(output: &'a str, key: &str) -> &'a str {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+            .unwrap_or_default()
+    }
+
+    fn inactive_exit_timestamp(output: &str) -> Option<u64> {
+        show_property(output, "InactiveExitTimestampMonotonic").parse().ok()
+    }
+
+    /// Parse `systemctl show --property=...` output. A missing unit is an error,
+    /// and so is a failed one: waiting on it would only run into the timeout.
+    /// A unit whose main process exited successfully (`SubState=exited`) is done.
+    pub(super) fn parse_show(name: &str, output: &str) -> Result<ServiceProgress, String> {
+        let property = |key: &str| show_property(output, key);
+        if property("LoadState") == "not-found" {
+            return Err(format!("unit {name} not found"));
+        }
+        if property("ActiveState") == "failed" {
+            return Err(format!("unit {name} failed ({})", property("SubState")));
+        }
+        let state = if property("SubState") == "exited" && property("Result") == "success" {
+            ServiceState::Running
+        } else {
+            unit_state(property("ActiveState"), property("SubState"))
+        };
+        let pending = matches!(state, ServiceState::StartPending | ServiceState::StopPending);
+        Ok(ServiceProgress {
+            state,
+            checkpoint: 0,
+            wait_hint: if pending { PENDING_WAIT_HINT } else { Duration::ZERO },
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::cell::RefCell;
+
+        /// Canned `systemctl`: replays `show` states in order and records every call.
+        struct CannedSystemctl {
+            show: RefCell<Vec<&'static str>>,
+            calls: RefCell<Vec<String>>,
+        }
+
+        impl CannedSystemctl {
+            fn new(show: Vec<&'static str>) -> Self {
+                Self {
+                    show: RefCell::new(show),
+                    calls: RefCell::new(Vec::new()),
                 }
             }
         }
+
+        impl CommandTransport for CannedSystemctl {
+            fn systemctl(&self, args: &[&str]) -> Result<String, String> {
+                self.calls.borrow_mut().push(args.join(" "));
+                match args[0] {
+                    "list-units" => Ok("\
+sshd.service loaded active running OpenSSH server daemon
+cups.service loaded inactive dead CUPS Scheduler
+● nginx.service loaded failed failed A high performance web server
+ghost.service not-found inactive dead ghost.service
+backup.service loaded activating start Nightly backup
+"
+                    .into()),
+                    "show" => {
+                        let mut show = self.show.borrow_mut();
+                        let next = if show.len() > 1 { show.remove(0) } else { show[0] };
+                        Ok(next.into())
+                    }
+                    "start" | "stop" => Ok(String::new()),
+                    other => Err(format!("unexpected command {other}")),
+                }
+            }
+        }
+
+        const RUNNING: &str = "LoadState=loaded\nActiveState=active\nSubState=running\n";
+        const STOPPED: &str = "LoadState=loaded\nActiveState=inactive\nSubState=dead\n";
+        const STOPPING: &str = "LoadState=loaded\nActiveState=deactivating\nSubState=stop-sigterm\n";
+        const MISSING: &str = "LoadState=not-found\nActiveState=inactive\nSubState=dead\n";
+        const STARTING: &str = "LoadState=loaded\nActiveState=activating\nSubState=start\n";
+        const FAILED: &str = "LoadState=loaded\nActiveState=failed\nSubState=failed\n";
+        const ONESHOT_IDLE: &str =
+            "LoadState=loaded\nActiveState=inactive\nSubState=dead\nResult=success\nInactiveExitTimestampMonotonic=100\n";
+        const ONESHOT_RUNNING: &str =
+            "LoadState=loaded\nActiveState=activating\nSubState=start\nResult=success\nInactiveExitTimestampMonotonic=200\n";
+        const ONESHOT_DONE: &str =
+            "LoadState=loaded\nActiveState=inactive\nSubState=dead\nResult=success\nInactiveExitTimestampMonotonic=200\n";
+        const EXITED: &str = "LoadState=loaded\nActiveState=active\nSubState=exited\nResult=success\n";
+
+        #[test]
+        fn show_reports_failed_and_missing_units_as_errors() {
+            assert_eq!(
+                parse_show("sshd.service", RUNNING).unwrap().state,
+                ServiceState::Running
+            );
+            assert_eq!(
+                parse_show("nginx.service", FAILED),
+                Err("unit nginx.service failed (failed)".to_string())
+            );
+            assert_eq!(
+                parse_show("ghost.service", MISSING),
+                Err("unit ghost.service not found".to_string())
+            );
+        }
+
+        #[test]
+        fn start_that_fails_is_reported_without_waiting() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![STOPPED, STARTING, FAILED]));
+            let started = std::time::Instant::now();
+            let error = control_service(&manager, "nginx.service", ServiceControlAction::Start).unwrap_err();
+            assert!(matches!(
+                error,
+                ServiceControlError::RequestFailed { phase: "start", .. }
+            ));
+            assert!(started.elapsed() < Duration::from_secs(5));
+        }
+
+        #[test]
+        fn failed_unit_can_be_started_again() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![FAILED, STARTING, RUNNING]));
+            let outcome = control_service(&manager, "nginx.service", ServiceControlAction::Start).unwrap();
+            assert_eq!(outcome.final_state, ServiceState::Running);
+        }
+
+        #[test]
+        fn oneshot_unit_that_ran_to_completion_counts_as_started() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![
+                ONESHOT_IDLE,
+                ONESHOT_IDLE,
+                ONESHOT_RUNNING,
+                ONESHOT_DONE,
+            ]));
+            let outcome = control_service(&manager, "backup.service", ServiceControlAction::Start).unwrap();
+            assert_eq!(outcome.final_state, ServiceState::Running);
+            assert_eq!(
+                parse_show("setup.service", EXITED).unwrap().state,
+                ServiceState::Running
+            );
+        }
+
+        #[test]
+        fn units_still_in_transition_keep_making_progress() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![STARTING]));
+            let first = manager.query("backup.service").unwrap();
+            let second = manager.query("backup.service").unwrap();
+            assert_eq!(second.state, ServiceState::StartPending);
+            assert_ne!(first.checkpoint, second.checkpoint);
+        }
+
+        #[test]
+        fn list_units_maps_states_and_skips_missing_units() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![RUNNING]));
+            let services = manager.list_services().unwrap();
+            let state = |name: &str| services.iter().find(|s| s.name == name).map(|s| s.state.as_str());
+            assert_eq!(services.len(), 4);
+            assert_eq!(state("sshd.service"), Some("Running"));
+            assert_eq!(state("cups.service"), Some("Stopped"));
+            assert_eq!(state("nginx.service"), Some("Failed"));
+            assert_eq!(state("backup.service"), Some("Start Pending"));
+            assert_eq!(services[0].display_name, "A high performance web server");
+        }
+
+        #[test]
+        fn restart_stops_then_starts_and_waits_for_running() {
+            let transport = CannedSystemctl::new(vec![RUNNING, STOPPING, STOPPED, STOPPED, RUNNING]);
+            let manager = SystemdServiceManager::new(transport);
+            let outcome = control_service(&manager, "sshd.service", ServiceControlAction::Restart).unwrap();
+            assert_eq!(outcome.final_state, ServiceState::Running);
+            let calls = manager.transport.calls.borrow();
+            assert!(calls.contains(&"stop --no-block -- sshd.service".to_string()));
+            assert!(calls.contains(&"start --no-block -- sshd.service".to_string()));
+        }
+
+        #[test]
+        fn start_on_running_unit_is_a_no_op() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![RUNNING]));
+            let outcome = control_service(&manager, "sshd.service", ServiceControlAction::Start).unwrap();
+            assert_eq!(outcome.final_state, ServiceState::Running);
+            assert!(!manager
+                .transport
+                .calls
+                .borrow()
+                .iter()
+                .any(|call| call.starts_with("start")));
+        }
+
+        #[test]
+        fn unknown_unit_fails_to_open() {
+            let manager = SystemdServiceManager::new(CannedSystemctl::new(vec![MISSING]));
+            assert!(matches!(
+                control_service(&manager, "ghost.service", ServiceControlAction::Stop),
+                Err(ServiceControlError::OpenService(_))
+            ));
+        }
+    }
+}
+
+/// The platform's native service manager.
+pub fn native_manager() -> Result<Box<dyn ServiceManager>, ServiceControlError> {
+    #[cfg(target_os = "windows")]
+    {
+        Ok(Box::new(windows_impl::WindowsServiceManager::connect()?))
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        Ok(Box::new(systemd::SystemdServiceManager::new(systemd::SystemctlCommand)))
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        Err(ServiceControlError::OpenManager(
+            "no supported service manager on this platform".into(),
+        ))
     }
+}
 
-    result.sort_by(|a, b| a.display_name.cmp(&b.display_name));
-    result
+pub fn send_service_control(
+    name: &str,
+    action: ServiceControlAction,
+) -> Result<ServiceControlOutcome, ServiceControlError> {
+    control_service(native_manager()?.as_ref(), name, action)
+}
+
+pub fn get_services() -> Vec<ServiceInfo> {
+    native_manager()
+        .ok()
+        .and_then(|manager| manager.list_services().ok())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::RefCell;
+
+    /// Backend stand-in whose state only changes once `start`/`stop` is requested.
+    struct StuckManager {
+        state: RefCell<ServiceState>,
+        target_after_request: ServiceState,
+    }
+
+    impl ServiceManager for StuckManager {
+        fn list_services(&self) -> Result<Vec<ServiceInfo>, String> {
+            Ok(Vec::new())
+        }
+
+        fn query(&self, _name: &str) -> Result<ServiceProgress, String> {
+            Ok(ServiceProgress {
+                state: *self.state.borrow(),
+                checkpoint: 0,
+                wait_hint: Duration::from_secs(1),
+            })
+        }
+
+        fn start(&self, _name: &str) -> Result<(), String> {
+            *self.state.borrow_mut() = self.target_after_request;
+            Ok(())
+        }
+
+        fn stop(&self, _name: &str) -> Result<(), String> {
+            Err("access denied".into())
+        }
+    }
+
+    #[test]
+    fn control_reports_phase_for_failed_requests() {
+        let manager = StuckManager {
+            state: RefCell::new(ServiceState::Running),
+            target_after_request: ServiceState::Running,
+        };
+        let error = control_service(&manager, "svc", ServiceControlAction::Stop).unwrap_err();
+        assert_eq!(error.to_string(), "Service stop request failed: access denied");
+    }
+
+    #[test]
+    fn control_times_out_when_state_stops_progressing() {
+        let manager = StuckManager {
+            state: RefCell::new(ServiceState::Stopped),
+            target_after_request: ServiceState::StartPending,
+        };
+        let error = control_service(&manager, "svc", ServiceControlAction::Start).unwrap_err();
+        assert!(matches!(
+            error,
+            ServiceControlError::TimedOut {
+                phase: "start",
+                last_state: ServiceState::StartPending
+            }
+        ));
+    }
 
     #[test]
     fn test_sort_services_refs() {
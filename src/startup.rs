@@ -69,6 +69,16 @@ pub enum StartupLocator {
         task_path: String,
         task_name: String,
     },
+    /// A freedesktop autostart entry. `user_path` is where the per-user copy
+    /// lives (or would live as an override of `system_path`).
+    XdgAutostart {
+        user_path: String,
+        system_path: Option<String>,
+    },
+    SystemdUserUnit {
+        unit: String,
+        fragment_path: String,
+    },
 }
 
 impl Default for StartupLocator {
@@ -86,6 +96,46 @@ impl StartupLocator {
             Self::Registry { hive, .. } => *hive == StartupRegistryHive::LocalMachine,
             Self::StartupFolder { approved_hive, .. } => *approved_hive == StartupRegistryHive::LocalMachine,
             Self::ScheduledTask { .. } => true,
+            Self::XdgAutostart { .. } | Self::SystemdUserUnit { .. } => false,
+        }
+    }
+
+    /// The file that defines a Linux session entry, if this locator has one.
+    fn entry_path(&self) -> Option<&str> {
+        match self {
+            Self::XdgAutostart { user_path, system_path } => {
+                if system_path.is_none() || Path::new(user_path).is_file() {
+                    Some(user_path)
+                } else {
+                    system_path.as_deref()
+                }
+            }
+            Self::SystemdUserUnit { fragment_path, .. } => Some(fragment_path),
+            _ => None,
+        }
+    }
+
+    /// The systemd unit that starts this entry, as `systemd-analyze` names it.
+    /// Autostart entries run as `app-<escaped id>@autostart.service` when the
+    /// session uses systemd's XDG autostart generator.
+    fn systemd_unit(&self) -> Option<String> {
+        match self {
+            Self::SystemdUserUnit { unit, .. } => Some(unit.clone()),
+            Self::XdgAutostart { user_path, .. } => {
+                let file_name = Path::new(user_path).file_name()?.to_str()?;
+                let id = file_name.strip_suffix(".desktop").unwrap_or(file_name);
+                let mut escaped = String::new();
+                for (index, byte) in id.bytes().enumerate() {
+                    match byte {
+                        b'/' => escaped.push('-'),
+                        b'.' if index == 0 => escaped.push_str("\\x2e"),
+                        b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b':' | b'_' | b'.' => escaped.push(byte as char),
+                        _ => escaped.push_str(&format!("\\x{byte:02x}")),
+                    }
+                }
+                Some(format!("app-{escaped}@autostart.service"))
+            }
+            _ => None,
         }
     }
 }
@@ -114,6 +164,18 @@ pub struct BootDiagnostics {
     pub post_boot_ms: Option<u64>,
     pub collected_at: String,
     pub degrading_items: Vec<String>,
+    /// `systemd-analyze` phases on Linux. Firmware and loader are only measured
+    /// on EFI systems, and initrd only when one is used.
+    #[serde(default)]
+    pub firmware_ms: Option<u64>,
+    #[serde(default)]
+    pub loader_ms: Option<u64>,
+    #[serde(default)]
+    pub kernel_ms: Option<u64>,
+    #[serde(default)]
+    pub initrd_ms: Option<u64>,
+    #[serde(default)]
+    pub userspace_ms: Option<u64>,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -582,12 +644,318 @@ pub fn get_startup_items() -> Vec<StartupItem> {
     get_startup_data().0
 }
 
-#[cfg(not(target_os = "windows"))]
+// ─── Linux Collection ────────────────────────────────────────
+
+/// The `[Desktop Entry]` keys that decide whether and what an autostart entry runs.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Default, PartialEq)]
+struct DesktopEntry {
+    name: Option<String>,
+    exec: Option<String>,
+    hidden: bool,
+    gnome_autostart_disabled: bool,
+}
+
+#[cfg(target_os = "linux")]
+impl DesktopEntry {
+    fn is_enabled(&self) -> bool {
+        !self.hidden && !self.gnome_autostart_disabled
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn parse_desktop_entry(contents: &str) -> DesktopEntry {
+    let mut entry = DesktopEntry::default();
+    let mut in_main_group = false;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_main_group = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_main_group || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Name" => entry.name = Some(value.to_string()),
+            "Exec" => entry.exec = Some(value.to_string()),
+            "Hidden" => entry.hidden = value.eq_ignore_ascii_case("true"),
+            "X-GNOME-Autostart-enabled" => entry.gnome_autostart_disabled = value.eq_ignore_ascii_case("false"),
+            _ => {}
+        }
+    }
+    entry
+}
+
+/// Set `key=value` in the `[Desktop Entry]` group, replacing any existing
+/// (unlocalized) value and leaving every other line untouched.
+#[cfg(target_os = "linux")]
+fn set_desktop_key(contents: &str, key: &str, value: &str) -> String {
+    let is_key = |line: &str| line.split_once('=').is_some_and(|(name, _)| name.trim() == key);
+    let mut in_main_group = false;
+    let has_key = contents.lines().any(|line| {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_main_group = line == "[Desktop Entry]";
+            false
+        } else {
+            in_main_group && is_key(line)
+        }
+    });
+
+    let mut output = String::with_capacity(contents.len() + key.len() + value.len() + 2);
+    let mut in_main_group = false;
+    let mut written = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_main_group = trimmed == "[Desktop Entry]";
+            output.push_str(line);
+            output.push('\n');
+            if in_main_group && !has_key && !written {
+                output.push_str(&format!("{key}={value}\n"));
+                written = true;
+            }
+            continue;
+        }
+        if in_main_group && is_key(trimmed) {
+            if !written {
+                output.push_str(&format!("{key}={value}\n"));
+                written = true;
+            }
+            continue;
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    if !written {
+        output.push_str(&format!("[Desktop Entry]\n{key}={value}\n"));
+    }
+    output
+}
+
+#[cfg(target_os = "linux")]
+fn xdg_user_autostart_dir() -> Option<PathBuf> {
+    directories::BaseDirs::new().map(|dirs| dirs.config_dir().join("autostart"))
+}
+
+#[cfg(target_os = "linux")]
+fn xdg_system_autostart_dirs() -> Vec<PathBuf> {
+    let dirs = std::env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "/etc/xdg".into());
+    dirs.split(':')
+        .filter(|dir| !dir.is_empty())
+        .map(|dir| Path::new(dir).join("autostart"))
+        .collect()
+}
+
+/// Collect autostart entries, resolving same-named files the way the session
+/// does: the user directory wins, then system directories in `XDG_CONFIG_DIRS` order.
+#[cfg(target_os = "linux")]
+fn collect_xdg_autostart(items: &mut Vec<StartupItem>, user_dir: &Path, system_dirs: &[PathBuf]) {
+    let mut seen = std::collections::HashSet::new();
+    let dirs = std::iter::once(user_dir).chain(system_dirs.iter().map(PathBuf::as_path));
+    for dir in dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "desktop") && path.is_file())
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let Some(file_name) = path.file_name().map(|name| name.to_os_string()) else {
+                continue;
+            };
+            if !seen.insert(file_name.clone()) {
+                continue;
+            }
+            let Ok(contents) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let mut entry = parse_desktop_entry(&contents);
+            let system_path = system_dirs
+                .iter()
+                .map(|dir| dir.join(&file_name))
+                .find(|candidate| candidate.is_file());
+            // A user file that only says `Hidden=true` still describes the system entry.
+            if let Some(system) = system_path.as_ref().filter(|system| **system != path) {
+                if let Ok(system_contents) = std::fs::read_to_string(system) {
+                    let system_entry = parse_desktop_entry(&system_contents);
+                    entry.name = entry.name.or(system_entry.name);
+                    entry.exec = entry.exec.or(system_entry.exec);
+                }
+            }
+
+            let name = entry.name.clone().unwrap_or_else(|| {
+                Path::new(&file_name)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            });
+            let source = if system_path.is_some() {
+                "XDG Autostart (System)"
+            } else {
+                "XDG Autostart (User)"
+            };
+            let locator = StartupLocator::XdgAutostart {
+                user_path: user_dir.join(&file_name).to_string_lossy().into_owned(),
+                system_path: system_path.map(|path| path.to_string_lossy().into_owned()),
+            };
+            items.push(new_item(
+                name,
+                entry.exec.clone().unwrap_or_default(),
+                entry.is_enabled(),
+                source.into(),
+                locator,
+            ));
+        }
+    }
+}
+
+/// One unit from `systemctl --user show`.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Default, PartialEq)]
+struct UserUnit {
+    id: String,
+    description: String,
+    fragment_path: String,
+    command: String,
+}
+
+/// Enabled service units from `list-unit-files --plain --no-legend` rows.
+/// Templates (`name@.service`) are skipped because only their instances run.
+#[cfg(target_os = "linux")]
+fn parse_unit_files(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|unit| unit.ends_with(".service") && !unit.ends_with("@.service"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Parse multi-unit `systemctl show` output; units are separated by blank lines.
+#[cfg(target_os = "linux")]
+fn parse_unit_show(output: &str) -> Vec<UserUnit> {
+    let mut units = Vec::new();
+    let mut current = UserUnit::default();
+    for line in output.lines().chain(std::iter::once("")) {
+        if line.trim().is_empty() {
+            if !current.id.is_empty() {
+                units.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key {
+            "Id" => current.id = value.to_string(),
+            "Description" => current.description = value.to_string(),
+            "FragmentPath" => current.fragment_path = value.to_string(),
+            "ExecStart" if current.command.is_empty() => current.command = exec_start_argv(value),
+            _ => {}
+        }
+    }
+    units
+}
+
+/// Pull the first command line out of systemd's
+/// `{ path=/usr/bin/app ; argv[]=/usr/bin/app --flag ; ... }` formatting.
+#[cfg(target_os = "linux")]
+fn exec_start_argv(value: &str) -> String {
+    value
+        .split(" ; ")
+        .find_map(|field| field.trim_start_matches(['{', ' ']).strip_prefix("argv[]="))
+        .map(|argv| argv.trim().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn collect_systemd_user_units(
+    items: &mut Vec<StartupItem>,
+    transport: &impl crate::services::systemd::CommandTransport,
+) {
+    let Ok(listing) = transport.systemctl(&[
+        "--user",
+        "list-unit-files",
+        "--type=service",
+        "--state=enabled",
+        "--plain",
+        "--no-legend",
+        "--no-pager",
+    ]) else {
+        return;
+    };
+    let unit_names = parse_unit_files(&listing);
+    if unit_names.is_empty() {
+        return;
+    }
+    let mut args = vec![
+        "--user",
+        "show",
+        "--property=Id,Description,FragmentPath,ExecStart",
+        "--no-pager",
+        "--",
+    ];
+    args.extend(unit_names.iter().map(String::as_str));
+    let Ok(details) = transport.systemctl(&args) else {
+        return;
+    };
+
+    for unit in parse_unit_show(&details) {
+        let name = if unit.description.is_empty() {
+            unit.id.trim_end_matches(".service").to_string()
+        } else {
+            unit.description
+        };
+        let locator = StartupLocator::SystemdUserUnit {
+            unit: unit.id,
+            fragment_path: unit.fragment_path,
+        };
+        items.push(new_item(name, unit.command, true, "systemd (User)".into(), locator));
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_startup_data() -> (Vec<StartupItem>, Option<BootDiagnostics>) {
+    let diag = get_boot_diagnostics();
+    let degrading = diag.as_ref().map(|d| d.degrading_items.clone()).unwrap_or_default();
+    let mut items = Vec::new();
+
+    if let Some(user_dir) = xdg_user_autostart_dir() {
+        collect_xdg_autostart(&mut items, &user_dir, &xdg_system_autostart_dirs());
+    }
+    collect_systemd_user_units(&mut items, &crate::services::systemd::SystemctlCommand);
+
+    enrich_startup_items(&mut items);
+    score_startup_items(&mut items, &degrading);
+
+    (items, diag)
+}
+
+#[cfg(target_os = "linux")]
+#[allow(dead_code)]
+pub fn get_startup_items() -> Vec<StartupItem> {
+    get_startup_data().0
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn get_startup_data() -> (Vec<StartupItem>, Option<BootDiagnostics>) {
     (Vec::new(), None)
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 #[allow(dead_code)]
 pub fn get_startup_items() -> Vec<StartupItem> {
     Vec::new()
@@ -674,7 +1042,141 @@ foreach ($p in $paths) {
     }
 }
 
-#[cfg(not(target_os = "windows"))]
+/// Which package, if any, installed a file.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, PartialEq)]
+enum PackageOwnership {
+    Owned(String),
+    Unowned,
+    /// No supported package manager is installed.
+    Unknown,
+}
+
+/// The program an `Exec=`/`ExecStart=` line runs: the first word after
+/// unquoting, skipping an `env VAR=value` prefix.
+#[cfg(target_os = "linux")]
+fn exec_program(command: &str) -> Option<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_quotes = false;
+    let mut chars = command.trim().chars();
+    while let Some(character) = chars.next() {
+        match character {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => word.extend(chars.next()),
+            c if c.is_whitespace() && !in_quotes => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            c => word.push(c),
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    let mut words = words.into_iter().peekable();
+    if words
+        .peek()
+        .is_some_and(|first| first == "env" || first.ends_with("/env"))
+    {
+        words.next();
+        while words
+            .peek()
+            .is_some_and(|word| word.contains('=') || word.starts_with('-'))
+        {
+            words.next();
+        }
+    }
+    words.next()
+}
+
+/// Resolve a bare program name against `PATH`; paths are checked as-is.
+#[cfg(target_os = "linux")]
+fn resolve_program(program: &str, search_path: Option<&std::ffi::OsStr>) -> Option<PathBuf> {
+    if program.contains('/') {
+        let path = PathBuf::from(program);
+        return path.is_file().then_some(path);
+    }
+    std::env::split_paths(search_path?)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Package name from `dpkg-query -S`, `rpm -qf` or `pacman -Qqo` output.
+#[cfg(target_os = "linux")]
+fn parse_package_owner(tool: &str, stdout: &str) -> Option<String> {
+    let line = stdout.lines().find(|line| !line.trim().is_empty())?.trim();
+    let name = if tool == "dpkg-query" {
+        // `pkg[:arch][, other]: /path`
+        let (packages, _) = line.split_once(": ")?;
+        packages.split(',').next()?.trim().split(':').next()?
+    } else {
+        line
+    };
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn package_owner(path: &Path) -> PackageOwnership {
+    const QUERIES: [(&str, &[&str]); 3] = [
+        ("dpkg-query", &["-S"]),
+        ("rpm", &["-qf", "--queryformat", "%{NAME}\\n"]),
+        ("pacman", &["-Qqo"]),
+    ];
+    for (tool, args) in QUERIES {
+        match std::process::Command::new(tool).args(args).arg(path).output() {
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(_) => return PackageOwnership::Unknown,
+            Ok(output) if output.status.success() => {
+                return match parse_package_owner(tool, &String::from_utf8_lossy(&output.stdout)) {
+                    Some(name) => PackageOwnership::Owned(name),
+                    None => PackageOwnership::Unowned,
+                };
+            }
+            Ok(_) => return PackageOwnership::Unowned,
+        }
+    }
+    PackageOwnership::Unknown
+}
+
+/// On Linux the provenance check is package ownership rather than Authenticode:
+/// `publisher` names the owning package and `is_signed` records whether one exists.
+#[cfg(target_os = "linux")]
+fn enrich_startup_items(items: &mut [StartupItem]) {
+    let search_path = std::env::var_os("PATH");
+    let mut owners: std::collections::HashMap<PathBuf, PackageOwnership> = std::collections::HashMap::new();
+
+    for item in items.iter_mut() {
+        let Some(program) = exec_program(&item.command) else {
+            continue;
+        };
+        let Some(resolved) = resolve_program(&program, search_path.as_deref()) else {
+            item.exe_path = Some(program);
+            item.exe_exists = false;
+            continue;
+        };
+        item.exe_path = Some(resolved.to_string_lossy().into_owned());
+        item.exe_exists = true;
+
+        let resolved = std::fs::canonicalize(&resolved).unwrap_or(resolved);
+        let ownership = owners
+            .entry(resolved.clone())
+            .or_insert_with(|| package_owner(&resolved))
+            .clone();
+        match ownership {
+            PackageOwnership::Owned(package) => {
+                item.publisher = Some(package);
+                item.is_signed = Some(true);
+            }
+            PackageOwnership::Unowned => item.is_signed = Some(false),
+            PackageOwnership::Unknown => {}
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 fn enrich_startup_items(_items: &mut [StartupItem]) {}
 
 // ─── Impact Scoring ──────────────────────────────────────────
@@ -685,7 +1187,10 @@ fn score_startup_items(items: &mut [StartupItem], degrading: &[String]) {
     for item in items.iter_mut() {
         let pub_lower = item.publisher.as_ref().map(|p| p.to_lowercase()).unwrap_or_default();
         let is_ms = ms_keywords.iter().any(|k| pub_lower.contains(k));
-        let is_degrading = degrading.iter().any(|d| d.eq_ignore_ascii_case(&item.name));
+        let unit = item.locator.systemd_unit();
+        let is_degrading = degrading
+            .iter()
+            .any(|d| d.eq_ignore_ascii_case(&item.name) || unit.as_deref() == Some(d.as_str()));
 
         if !item.exe_exists && item.exe_path.is_some() {
             item.impact_tier = ImpactTier::High;
@@ -694,7 +1199,13 @@ fn score_startup_items(items: &mut [StartupItem], degrading: &[String]) {
         } else if is_degrading {
             item.impact_tier = ImpactTier::High;
             item.recommendation = Recommendation::Review;
-            item.reason = "Flagged by Windows boot diagnostics as slowing startup".into();
+            item.reason = if unit.is_some() {
+                "Flagged by systemd-analyze as slowing startup".into()
+            } else {
+                "Flagged by Windows boot diagnostics as slowing startup".into()
+            };
+        } else if item.locator.entry_path().is_some() {
+            score_linux_session_item(item);
         } else if is_ms && item.source.contains("HKLM") {
             item.impact_tier = ImpactTier::Low;
             item.recommendation = Recommendation::Keep;
@@ -719,6 +1230,74 @@ fn score_startup_items(items: &mut [StartupItem], degrading: &[String]) {
     }
 }
 
+/// Linux entries have no signatures to lean on, so trust comes from package
+/// ownership (`is_signed`) and from where the program and its entry live.
+fn score_linux_session_item(item: &mut StartupItem) {
+    let program_writable = item
+        .exe_path
+        .as_deref()
+        .is_some_and(|path| is_user_writable(Path::new(path)));
+    let entry_writable = item
+        .locator
+        .entry_path()
+        .is_some_and(|path| is_user_writable(Path::new(path)));
+
+    let (tier, recommendation, reason) = if program_writable && item.is_signed != Some(true) {
+        (
+            ImpactTier::High,
+            Recommendation::Disable,
+            "Runs a program from a user-writable location".to_string(),
+        )
+    } else if item.is_signed == Some(false) {
+        (
+            ImpactTier::Medium,
+            Recommendation::Review,
+            "Program is not installed by any package".to_string(),
+        )
+    } else if entry_writable {
+        let reason = match &item.publisher {
+            Some(package) => format!("Per-user entry for package {package}"),
+            None => "Per-user startup entry".to_string(),
+        };
+        (ImpactTier::Medium, Recommendation::Review, reason)
+    } else if let (Some(true), Some(package)) = (item.is_signed, &item.publisher) {
+        (
+            ImpactTier::Low,
+            Recommendation::Keep,
+            format!("Installed by package {package}"),
+        )
+    } else {
+        (
+            ImpactTier::Medium,
+            Recommendation::Review,
+            "Review for necessity".to_string(),
+        )
+    };
+    item.impact_tier = tier;
+    item.recommendation = recommendation;
+    item.reason = reason;
+}
+
+/// Whether an unprivileged user could have replaced `path`: it sits in the
+/// home directory or a temp directory, or its folder is world-writable.
+fn is_user_writable(path: &Path) -> bool {
+    let home = directories::BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+    if home.is_some_and(|home| path.starts_with(home)) {
+        return true;
+    }
+    if ["/tmp", "/var/tmp", "/dev/shm"].iter().any(|dir| path.starts_with(dir)) {
+        return true;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Some(parent) = path.parent() {
+            return std::fs::metadata(parent).is_ok_and(|metadata| metadata.permissions().mode() & 0o002 != 0);
+        }
+    }
+    false
+}
+
 // ─── Boot Diagnostics ────────────────────────────────────────
 
 #[cfg(target_os = "windows")]
@@ -769,7 +1348,193 @@ try {
     Some(diag)
 }
 
-#[cfg(not(target_os = "windows"))]
+/// One-line phase breakdown such as `firmware 7.4s · kernel 2.2s · userspace 11.9s`,
+/// or `None` when no phases were measured (Windows reports totals only).
+pub fn boot_phase_summary(diag: &BootDiagnostics) -> Option<String> {
+    let phases = [
+        ("firmware", diag.firmware_ms),
+        ("loader", diag.loader_ms),
+        ("kernel", diag.kernel_ms),
+        ("initrd", diag.initrd_ms),
+        ("userspace", diag.userspace_ms),
+    ];
+    let parts: Vec<String> = phases
+        .iter()
+        .filter_map(|(name, ms)| ms.map(|ms| format!("{name} {:.1}s", ms as f64 / 1000.0)))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" · "))
+}
+
+/// Units slower than this are reported as slowing startup.
+#[cfg(target_os = "linux")]
+const SLOW_UNIT_MS: u64 = 1_000;
+
+/// Upper bound on slow units reported per systemd manager (system and user).
+#[cfg(target_os = "linux")]
+const SLOW_UNIT_LIMIT: usize = 10;
+
+/// Parse a systemd timespan such as `1min 2.345s`, `834ms` or `901us` into milliseconds.
+#[cfg(target_os = "linux")]
+fn parse_timespan(text: &str) -> Option<u64> {
+    const UNITS: [(&str, f64); 6] = [
+        ("ms", 1.0),
+        ("us", 0.001),
+        ("µs", 0.001),
+        ("min", 60_000.0),
+        ("h", 3_600_000.0),
+        ("s", 1_000.0),
+    ];
+    let mut total = 0.0;
+    let mut parsed_any = false;
+    for token in text.split_whitespace() {
+        let (value, scale) = UNITS
+            .iter()
+            .find_map(|(suffix, scale)| Some((token.strip_suffix(suffix)?.parse::<f64>().ok()?, *scale)))?;
+        total += value * scale;
+        parsed_any = true;
+    }
+    parsed_any.then_some(total.round() as u64)
+}
+
+/// Fill the phase split and totals from `systemd-analyze time`. Returns `false`
+/// when boot has not finished yet (or the output is not recognised).
+///
+/// Windows reports the main path (until the desktop is usable) and post-boot
+/// work separately; the closest systemd equivalent splits userspace at the
+/// moment the default target was reached.
+#[cfg(target_os = "linux")]
+fn parse_analyze_time(output: &str, diag: &mut BootDiagnostics) -> bool {
+    let Some(summary) = output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Startup finished in "))
+    else {
+        return false;
+    };
+    let Some((phases, total)) = summary.split_once(" = ") else {
+        return false;
+    };
+    diag.boot_duration_ms = parse_timespan(total);
+
+    for phase in phases.split(" + ") {
+        let Some((time, name)) = phase.trim().rsplit_once(" (") else {
+            continue;
+        };
+        let value = parse_timespan(time);
+        match name.trim_end_matches(')') {
+            "firmware" => diag.firmware_ms = value,
+            "loader" => diag.loader_ms = value,
+            "kernel" => diag.kernel_ms = value,
+            "initrd" => diag.initrd_ms = value,
+            "userspace" => diag.userspace_ms = value,
+            _ => {}
+        }
+    }
+
+    // `graphical.target reached after 1min 9.830s in userspace.`
+    let target_reached = output.lines().find_map(|line| {
+        let (_, rest) = line.split_once(" reached after ")?;
+        parse_timespan(rest.trim().strip_suffix(" in userspace.")?)
+    });
+    if let Some(reached) = target_reached {
+        let before_userspace = diag.kernel_ms.unwrap_or(0) + diag.initrd_ms.unwrap_or(0);
+        diag.main_path_boot_ms = Some(before_userspace + reached);
+        diag.post_boot_ms = diag.userspace_ms.map(|userspace| userspace.saturating_sub(reached));
+    }
+    diag.boot_duration_ms.is_some()
+}
+
+/// `(unit, milliseconds)` rows from `systemd-analyze blame`, slowest first.
+#[cfg(target_os = "linux")]
+fn parse_blame(output: &str) -> Vec<(String, u64)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (time, unit) = line.trim().rsplit_once(char::is_whitespace)?;
+            Some((unit.to_string(), parse_timespan(time)?))
+        })
+        .collect()
+}
+
+/// Units on the `systemd-analyze critical-chain` path with the time they took
+/// to start themselves (the `+` figure). Units that only waited are skipped.
+#[cfg(target_os = "linux")]
+fn parse_critical_chain(output: &str) -> Vec<(String, u64)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start_matches(|c: char| c.is_whitespace() || "└├│─".contains(c));
+            let (unit, timing) = line.split_once(" @")?;
+            let (_, own) = timing.split_once(" +")?;
+            Some((unit.to_string(), parse_timespan(own)?))
+        })
+        .collect()
+}
+
+/// Slow units for `degrading_items`: critical-chain units first, since those
+/// delayed the default target, then the slowest of the rest from blame.
+#[cfg(target_os = "linux")]
+fn slow_units(critical_chain: &[(String, u64)], blame: &[(String, u64)]) -> Vec<String> {
+    let mut chain: Vec<_> = critical_chain.iter().filter(|(_, ms)| *ms >= SLOW_UNIT_MS).collect();
+    chain.sort_by_key(|(_, ms)| std::cmp::Reverse(*ms));
+    let mut units: Vec<String> = Vec::new();
+    for (unit, _) in chain
+        .into_iter()
+        .chain(blame.iter().filter(|(_, ms)| *ms >= SLOW_UNIT_MS))
+    {
+        if units.len() == SLOW_UNIT_LIMIT {
+            break;
+        }
+        if !units.contains(unit) {
+            units.push(unit.clone());
+        }
+    }
+    units
+}
+
+#[cfg(target_os = "linux")]
+fn systemd_analyze(args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new("systemd-analyze")
+        .args(args)
+        .arg("--no-pager")
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_boot_diagnostics() -> Option<BootDiagnostics> {
+    let mut diag = BootDiagnostics {
+        collected_at: chrono::Local::now().to_rfc3339(),
+        ..Default::default()
+    };
+    if !parse_analyze_time(&systemd_analyze(&["time"])?, &mut diag) {
+        return None;
+    }
+
+    // Startup items are user units and autostart entries, which run under the
+    // user manager; system units are still listed so the slowest are visible.
+    for scope in [&[][..], &["--user"][..]] {
+        let query = |command: &str| {
+            let mut args = scope.to_vec();
+            args.push(command);
+            systemd_analyze(&args).unwrap_or_default()
+        };
+        let chain = parse_critical_chain(&query("critical-chain"));
+        let blame = parse_blame(&query("blame"));
+        for unit in slow_units(&chain, &blame) {
+            if !diag.degrading_items.contains(&unit) {
+                diag.degrading_items.push(unit);
+            }
+        }
+    }
+
+    Some(diag)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn get_boot_diagnostics() -> Option<BootDiagnostics> {
     None
 }
@@ -795,6 +1560,16 @@ enum StartupQuarantinePayload {
     ScheduledTask {
         xml_path: String,
     },
+    /// The user file (if any) was moved to `quarantined_path`; a system entry
+    /// was masked by writing a marked `Hidden=true` override in its place.
+    DesktopEntry {
+        quarantined_path: Option<String>,
+        override_written: bool,
+    },
+    /// The unit was disabled; a user-owned unit file is also moved aside.
+    SystemdUnit {
+        quarantined_path: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -1040,6 +1815,9 @@ pub fn disable_startup(locator: &StartupLocator) -> Result<(), String> {
             );
             ps_run_checked(&script).map(|_| ())
         }
+        StartupLocator::XdgAutostart { .. } | StartupLocator::SystemdUserUnit { .. } => {
+            Err("This startup entry belongs to a Linux session".into())
+        }
     }
 }
 
@@ -1126,6 +1904,9 @@ pub fn enable_startup(locator: &StartupLocator) -> Result<(), String> {
             );
             ps_run_checked(&script).map(|_| ())
         }
+        StartupLocator::XdgAutostart { .. } | StartupLocator::SystemdUserUnit { .. } => {
+            Err("This startup entry belongs to a Linux session".into())
+        }
     }
 }
 
@@ -1201,6 +1982,9 @@ pub fn quarantine_startup(item_name: &str, locator: &StartupLocator) -> Result<S
                 xml_path: xml_path.to_string_lossy().into_owned(),
             }
         }
+        StartupLocator::XdgAutostart { .. } | StartupLocator::SystemdUserUnit { .. } => {
+            return Err("This startup entry belongs to a Linux session".into());
+        }
     };
 
     let record = StartupQuarantineRecord {
@@ -1288,6 +2072,9 @@ pub fn quarantine_startup(item_name: &str, locator: &StartupLocator) -> Result<S
                 let _ = std::fs::remove_file(xml_path);
                 let _ = std::fs::remove_file(quarantine_record_path(&id)?);
             }
+            StartupQuarantinePayload::DesktopEntry { .. } | StartupQuarantinePayload::SystemdUnit { .. } => {
+                let _ = std::fs::remove_file(quarantine_record_path(&id)?);
+            }
         }
         return Err(error);
     }
@@ -1372,24 +2159,295 @@ pub fn restore_startup(id: &str) -> Result<StartupQuarantineRecord, String> {
     Ok(record)
 }
 
-#[cfg(not(target_os = "windows"))]
+/// Marks overrides written by quarantine so restore only removes its own file.
+#[cfg(target_os = "linux")]
+const QUARANTINE_MARKER_KEY: &str = "X-SysMon-Quarantine";
+
+#[cfg(target_os = "linux")]
+fn systemctl_user(args: &[&str]) -> Result<String, String> {
+    use crate::services::systemd::{CommandTransport, SystemctlCommand};
+    let mut user_args = vec!["--user"];
+    user_args.extend_from_slice(args);
+    SystemctlCommand.systemctl(&user_args)
+}
+
+#[cfg(target_os = "linux")]
+fn write_file_replacing(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|error| format!("Could not create {}: {error}", parent.display()))?;
+    }
+    let temporary = path.with_extension("desktop.tmp");
+    std::fs::write(&temporary, contents)
+        .map_err(|error| format!("Could not write {}: {error}", temporary.display()))?;
+    std::fs::rename(&temporary, path).map_err(|error| {
+        let _ = std::fs::remove_file(&temporary);
+        format!("Could not replace {}: {error}", path.display())
+    })
+}
+
+/// Toggle an autostart entry via `Hidden=`. System entries are never edited;
+/// the change goes into a same-named user copy, which the session prefers.
+#[cfg(target_os = "linux")]
+fn set_autostart_hidden(user_path: &str, system_path: Option<&str>, hidden: bool) -> Result<(), String> {
+    let user = Path::new(user_path);
+    let source = if user.is_file() {
+        user
+    } else if let Some(system) = system_path.map(Path::new).filter(|path| path.is_file()) {
+        system
+    } else {
+        return Err(format!("Autostart entry was not found at {}", user.display()));
+    };
+    let contents =
+        std::fs::read_to_string(source).map_err(|error| format!("Could not read autostart entry: {error}"))?;
+    let mut updated = set_desktop_key(&contents, "Hidden", if hidden { "true" } else { "false" });
+    if !hidden && parse_desktop_entry(&updated).gnome_autostart_disabled {
+        updated = set_desktop_key(&updated, "X-GNOME-Autostart-enabled", "true");
+    }
+    write_file_replacing(user, &updated)?;
+
+    let written =
+        std::fs::read_to_string(user).map_err(|error| format!("Could not verify autostart entry: {error}"))?;
+    if parse_desktop_entry(&written).is_enabled() == hidden {
+        return Err("Autostart entry did not take the new state".into());
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn set_unit_enabled(unit: &str, enabled: bool) -> Result<(), String> {
+    systemctl_user(&[if enabled { "enable" } else { "disable" }, "--", unit])?;
+    // `is-enabled` exits non-zero for anything but enabled, which the transport reports as Err.
+    let now_enabled = systemctl_user(&["is-enabled", "--", unit]).is_ok_and(|state| state.trim() == "enabled");
+    if now_enabled != enabled {
+        return Err(if enabled {
+            format!("{unit} is still not enabled")
+        } else {
+            format!("{unit} is still enabled; it may be enabled for all users, which needs administrator rights")
+        });
+    }
+    Ok(())
+}
+
+/// Unit files the user owns (`~/.config/systemd/user`) can be moved aside;
+/// packaged units are only disabled.
+#[cfg(target_os = "linux")]
+fn user_owned_unit_file(fragment_path: &str) -> Option<&Path> {
+    let path = Path::new(fragment_path);
+    let config = directories::BaseDirs::new()?.config_dir().join("systemd").join("user");
+    (path.starts_with(config) && path.is_file()).then_some(path)
+}
+
+#[cfg(target_os = "linux")]
+pub fn disable_startup(locator: &StartupLocator) -> Result<(), String> {
+    match locator {
+        StartupLocator::XdgAutostart { user_path, system_path } => {
+            set_autostart_hidden(user_path, system_path.as_deref(), true)
+        }
+        StartupLocator::SystemdUserUnit { unit, .. } => set_unit_enabled(unit, false),
+        _ => Err("This startup entry belongs to Windows".into()),
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn enable_startup(locator: &StartupLocator) -> Result<(), String> {
+    match locator {
+        StartupLocator::XdgAutostart { user_path, system_path } => {
+            set_autostart_hidden(user_path, system_path.as_deref(), false)
+        }
+        StartupLocator::SystemdUserUnit { unit, .. } => set_unit_enabled(unit, true),
+        _ => Err("This startup entry belongs to Windows".into()),
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn mask_autostart_entry(
+    id: &str,
+    user_path: &str,
+    system_path: Option<&str>,
+    quarantined_path: Option<&str>,
+    write_override: bool,
+) -> Result<(), String> {
+    let user = Path::new(user_path);
+    if let Some(quarantined_path) = quarantined_path {
+        move_file_transactional(user, Path::new(quarantined_path))
+            .map_err(|error| format!("Could not move autostart entry into quarantine: {error}"))?;
+    }
+    if let (true, Some(system_path)) = (write_override, system_path) {
+        let contents = std::fs::read_to_string(system_path)
+            .map_err(|error| format!("Could not read system autostart entry: {error}"))?;
+        let masked = set_desktop_key(&set_desktop_key(&contents, "Hidden", "true"), QUARANTINE_MARKER_KEY, id);
+        write_file_replacing(user, &masked)?;
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+pub fn quarantine_startup(item_name: &str, locator: &StartupLocator) -> Result<String, String> {
+    let id = new_quarantine_id();
+    let quarantined_file = |path: &Path| -> Result<String, String> {
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| "Startup file name is invalid".to_string())?;
+        Ok(quarantine_root()?
+            .join("files")
+            .join(format!("{id}-{file_name}"))
+            .to_string_lossy()
+            .into_owned())
+    };
+    let payload = match locator {
+        StartupLocator::XdgAutostart { user_path, system_path } => {
+            let user = Path::new(user_path);
+            let quarantined_path = if user.is_file() {
+                Some(quarantined_file(user)?)
+            } else {
+                None
+            };
+            let override_written = system_path.as_deref().is_some_and(|path| Path::new(path).is_file());
+            if quarantined_path.is_none() && !override_written {
+                return Err("The exact autostart entry no longer exists".into());
+            }
+            StartupQuarantinePayload::DesktopEntry {
+                quarantined_path,
+                override_written,
+            }
+        }
+        StartupLocator::SystemdUserUnit { fragment_path, .. } => StartupQuarantinePayload::SystemdUnit {
+            quarantined_path: user_owned_unit_file(fragment_path).map(&quarantined_file).transpose()?,
+        },
+        _ => return Err("This startup entry belongs to Windows".into()),
+    };
+
+    let record = StartupQuarantineRecord {
+        id: id.clone(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        item_name: item_name.to_string(),
+        locator: locator.clone(),
+        payload,
+    };
+    save_quarantine_record(&record)?;
+
+    let mutation = match (&record.locator, &record.payload) {
+        (
+            StartupLocator::XdgAutostart { user_path, system_path },
+            StartupQuarantinePayload::DesktopEntry {
+                quarantined_path,
+                override_written,
+            },
+        ) => mask_autostart_entry(
+            &id,
+            user_path,
+            system_path.as_deref(),
+            quarantined_path.as_deref(),
+            *override_written,
+        ),
+        (
+            StartupLocator::SystemdUserUnit { unit, fragment_path },
+            StartupQuarantinePayload::SystemdUnit { quarantined_path },
+        ) => set_unit_enabled(unit, false).and_then(|()| match quarantined_path {
+            Some(quarantined_path) => {
+                move_file_transactional(Path::new(fragment_path), Path::new(quarantined_path))
+                    .map_err(|error| format!("Could not move unit file into quarantine: {error}"))?;
+                systemctl_user(&["daemon-reload"]).map(|_| ())
+            }
+            None => Ok(()),
+        }),
+        _ => Err("Quarantine record does not match the startup locator".into()),
+    };
+
+    if let Err(error) = mutation {
+        // Restore tolerates steps that never happened, so it doubles as rollback.
+        let _ = restore_startup(&id);
+        return Err(error);
+    }
+    Ok(id)
+}
+
+#[cfg(target_os = "linux")]
+pub fn restore_startup(id: &str) -> Result<StartupQuarantineRecord, String> {
+    let record = load_quarantine_record(id)?;
+    match (&record.locator, &record.payload) {
+        (
+            StartupLocator::XdgAutostart { user_path, .. },
+            StartupQuarantinePayload::DesktopEntry {
+                quarantined_path,
+                override_written,
+            },
+        ) => {
+            let user = Path::new(user_path);
+            if *override_written {
+                if let Ok(contents) = std::fs::read_to_string(user) {
+                    let marker = format!("{QUARANTINE_MARKER_KEY}={id}");
+                    if !contents.lines().any(|line| line.trim() == marker) {
+                        return Err(format!(
+                            "Cannot restore because {} was changed after quarantine",
+                            user.display()
+                        ));
+                    }
+                    std::fs::remove_file(user)
+                        .map_err(|error| format!("Could not remove quarantine override: {error}"))?;
+                }
+            }
+            if let Some(quarantined_path) = quarantined_path {
+                let quarantined = Path::new(quarantined_path);
+                if quarantined.exists() {
+                    if user.exists() {
+                        return Err(format!("Cannot restore because {} already exists", user.display()));
+                    }
+                    move_file_transactional(quarantined, user)
+                        .map_err(|error| format!("Could not restore autostart entry: {error}"))?;
+                } else if !user.is_file() {
+                    return Err("The quarantined autostart entry is missing".into());
+                }
+            }
+        }
+        (
+            StartupLocator::SystemdUserUnit { unit, fragment_path },
+            StartupQuarantinePayload::SystemdUnit { quarantined_path },
+        ) => {
+            if let Some(quarantined_path) = quarantined_path {
+                let quarantined = Path::new(quarantined_path);
+                let original = Path::new(fragment_path);
+                if quarantined.exists() {
+                    if original.exists() {
+                        return Err(format!("Cannot restore because {} already exists", original.display()));
+                    }
+                    move_file_transactional(quarantined, original)
+                        .map_err(|error| format!("Could not restore unit file: {error}"))?;
+                } else if !original.is_file() {
+                    return Err("The quarantined unit file is missing".into());
+                }
+                systemctl_user(&["daemon-reload"])?;
+            }
+            set_unit_enabled(unit, true)?;
+        }
+        _ => return Err("Quarantine record does not match the startup locator".into()),
+    }
+
+    std::fs::remove_file(quarantine_record_path(id)?).map_err(|error| {
+        format!("Startup item was restored, but its quarantine record could not be removed: {error}")
+    })?;
+    Ok(record)
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn disable_startup(_locator: &StartupLocator) -> Result<(), String> {
-    Err("Startup actions are only supported on Windows".into())
+    Err("Startup actions are not supported on this platform".into())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn enable_startup(_locator: &StartupLocator) -> Result<(), String> {
-    Err("Startup actions are only supported on Windows".into())
+    Err("Startup actions are not supported on this platform".into())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn quarantine_startup(_item_name: &str, _locator: &StartupLocator) -> Result<String, String> {
-    Err("Startup actions are only supported on Windows".into())
+    Err("Startup actions are not supported on this platform".into())
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn restore_startup(_id: &str) -> Result<StartupQuarantineRecord, String> {
-    Err("Startup actions are only supported on Windows".into())
+    Err("Startup actions are not supported on this platform".into())
 }
 
 #[cfg(any())]
@@ -1692,13 +2750,35 @@ pub fn disable_startup_item(_name: &str, _source: &str, _command: &str) -> bool
 pub fn reenable_startup_item(_name: &str, _source: &str) -> bool {
     false
 }
-#[cfg(not(target_os = "windows"))]
+#[cfg(target_os = "linux")]
+pub fn open_file_location(path: &str) {
+    // File managers disagree on a "select" flag, so open the containing folder.
+    let path = Path::new(path.trim());
+    let folder = path.parent().filter(|_| path.is_file()).unwrap_or(path);
+    let _ = std::process::Command::new("xdg-open").arg(folder).spawn();
+}
+#[cfg(target_os = "linux")]
+pub fn search_online(name: &str) {
+    let query = format!("https://www.google.com/search?q=what+is+{}", urlenccode(name));
+    let _ = std::process::Command::new("xdg-open").arg(query).spawn();
+}
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn open_file_location(_path: &str) {}
-#[cfg(not(target_os = "windows"))]
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
 pub fn search_online(_name: &str) {}
 
 // ─── Sorting / Filtering helpers ─────────────────────────────
 
+/// Wording for `StartupItem::is_signed`, which means an Authenticode signature
+/// on Windows and package-manager ownership on Linux.
+pub fn provenance_labels() -> (&'static str, &'static str) {
+    if cfg!(target_os = "windows") {
+        ("Signed", "Unsigned")
+    } else {
+        ("Packaged", "Unpackaged")
+    }
+}
+
 pub fn high_impact_count(items: &[StartupItem]) -> usize {
     items
         .iter()
@@ -1739,6 +2819,7 @@ mod tests {
         assert_eq!(parse_exe_from_command(r#""#), None);
     }
 
+    #[cfg(target_os = "windows")]
     #[test]
     fn test_expand_env_vars() {
         let expanded = expand_env_vars("%SystemDrive%\\Windows");
@@ -1793,6 +2874,282 @@ mod tests {
         std::fs::remove_dir_all(&test_root).expect("remove disposable startup folder");
     }
 
+    #[cfg(target_os = "linux")]
+    fn desktop_fixture(name: &str) -> crate::providers::sysfs::TempTree {
+        let root = crate::providers::sysfs::TempTree::new(&format!("startup-{name}"));
+        std::fs::create_dir_all(root.join("user")).unwrap();
+        std::fs::create_dir_all(root.join("system")).unwrap();
+        root
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn desktop_entries_parse_and_rewrite_main_group_only() {
+        let contents = "# comment\n[Desktop Entry]\nName=Tracker\nName[de]=Verfolger\nExec=tracker-miner --daemon\nX-GNOME-Autostart-enabled=false\n\n[Desktop Action quit]\nHidden=true\n";
+        let entry = parse_desktop_entry(contents);
+        assert_eq!(entry.name.as_deref(), Some("Tracker"));
+        assert_eq!(entry.exec.as_deref(), Some("tracker-miner --daemon"));
+        assert!(!entry.hidden);
+        assert!(!entry.is_enabled());
+
+        let hidden = set_desktop_key(contents, "Hidden", "true");
+        assert!(hidden.starts_with("# comment\n[Desktop Entry]\nHidden=true\nName=Tracker"));
+        assert!(hidden.ends_with("[Desktop Action quit]\nHidden=true\n"));
+        assert!(parse_desktop_entry(&hidden).hidden);
+        let shown = set_desktop_key(&hidden, "Hidden", "false");
+        assert_eq!(shown.matches("Hidden=false").count(), 1);
+        assert!(!parse_desktop_entry(&shown).hidden);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn exec_program_skips_env_prefix_and_quotes() {
+        assert_eq!(
+            exec_program("env GDK_BACKEND=x11 slack -u %U").as_deref(),
+            Some("slack")
+        );
+        assert_eq!(
+            exec_program(r#""/opt/My App/app" --minimized"#).as_deref(),
+            Some("/opt/My App/app")
+        );
+        assert_eq!(exec_program("   "), None);
+        assert_eq!(
+            exec_start_argv(
+                "{ path=/usr/bin/pipewire ; argv[]=/usr/bin/pipewire -v ; ignore_errors=no ; start_time=[n/a] }"
+            ),
+            "/usr/bin/pipewire -v"
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn package_owner_output_names_the_package() {
+        assert_eq!(
+            parse_package_owner("dpkg-query", "pipewire-bin:amd64: /usr/bin/pipewire\n").as_deref(),
+            Some("pipewire-bin")
+        );
+        assert_eq!(
+            parse_package_owner("dpkg-query", "gvfs, gvfs-daemons: /usr/libexec\n").as_deref(),
+            Some("gvfs")
+        );
+        assert_eq!(parse_package_owner("rpm", "pipewire\n").as_deref(), Some("pipewire"));
+        assert_eq!(parse_package_owner("pacman", ""), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn systemd_user_units_parse_listing_and_show_output() {
+        let listing =
+            "pipewire.service enabled enabled\nsyncthing@.service enabled enabled\nbackup.service enabled disabled\n";
+        assert_eq!(parse_unit_files(listing), vec!["pipewire.service", "backup.service"]);
+
+        let show = "Id=pipewire.service\nDescription=PipeWire Multimedia Service\nFragmentPath=/usr/lib/systemd/user/pipewire.service\nExecStart={ path=/usr/bin/pipewire ; argv[]=/usr/bin/pipewire ; ignore_errors=no }\n\nId=backup.service\nDescription=\nFragmentPath=/home/me/.config/systemd/user/backup.service\nExecStart=\n";
+        let units = parse_unit_show(show);
+        assert_eq!(units.len(), 2);
+        assert_eq!(units[0].command, "/usr/bin/pipewire");
+        assert_eq!(units[0].fragment_path, "/usr/lib/systemd/user/pipewire.service");
+        assert_eq!(units[1].id, "backup.service");
+        assert!(units[1].command.is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn xdg_autostart_user_entries_override_system_ones() {
+        let root = desktop_fixture("collect");
+        std::fs::write(
+            root.join("system/tracker.desktop"),
+            "[Desktop Entry]\nName=Tracker\nExec=tracker-miner\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("system/solo.desktop"), "[Desktop Entry]\nExec=solo\n").unwrap();
+        std::fs::write(root.join("user/tracker.desktop"), "[Desktop Entry]\nHidden=true\n").unwrap();
+        std::fs::write(root.join("user/notes.txt"), "not an entry").unwrap();
+
+        let mut items = Vec::new();
+        collect_xdg_autostart(&mut items, &root.join("user"), &[root.join("system")]);
+        assert_eq!(items.len(), 2);
+        let tracker = items.iter().find(|item| item.name == "Tracker").unwrap();
+        assert!(!tracker.enabled);
+        assert_eq!(tracker.command, "tracker-miner");
+        assert_eq!(tracker.source, "XDG Autostart (System)");
+        let solo = items.iter().find(|item| item.name == "solo").unwrap();
+        assert!(solo.enabled);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn xdg_disable_writes_user_override_and_quarantine_restores_both() {
+        let root = desktop_fixture("actions");
+        let system = root.join("system/agent.desktop");
+        let user = root.join("user/agent.desktop");
+        std::fs::write(&system, "[Desktop Entry]\nName=Agent\nExec=agent\n").unwrap();
+        let locator = StartupLocator::XdgAutostart {
+            user_path: user.to_string_lossy().into_owned(),
+            system_path: Some(system.to_string_lossy().into_owned()),
+        };
+
+        disable_startup(&locator).expect("disable autostart entry");
+        assert!(parse_desktop_entry(&std::fs::read_to_string(&user).unwrap()).hidden);
+        assert_eq!(
+            std::fs::read_to_string(&system).unwrap(),
+            "[Desktop Entry]\nName=Agent\nExec=agent\n"
+        );
+        enable_startup(&locator).expect("enable autostart entry");
+        let user_contents = std::fs::read_to_string(&user).unwrap();
+        assert!(parse_desktop_entry(&user_contents).is_enabled());
+
+        let quarantine_id = quarantine_startup("Agent", &locator).expect("quarantine autostart entry");
+        assert!(quarantine_exists(&quarantine_id));
+        let masked = std::fs::read_to_string(&user).unwrap();
+        assert!(parse_desktop_entry(&masked).hidden);
+        assert!(masked.contains(&quarantine_id));
+        restore_startup(&quarantine_id).expect("restore autostart entry");
+        assert_eq!(std::fs::read_to_string(&user).unwrap(), user_contents);
+        assert!(!quarantine_exists(&quarantine_id));
+    }
+
+    #[test]
+    fn linux_scoring_prefers_packaged_programs_in_system_locations() {
+        let mut packaged = new_item(
+            "PipeWire".into(),
+            "/usr/bin/pipewire".into(),
+            true,
+            "systemd (User)".into(),
+            StartupLocator::SystemdUserUnit {
+                unit: "pipewire.service".into(),
+                fragment_path: "/usr/lib/systemd/user/pipewire.service".into(),
+            },
+        );
+        packaged.exe_path = Some("/usr/bin/pipewire".into());
+        packaged.exe_exists = true;
+        packaged.publisher = Some("pipewire".into());
+        packaged.is_signed = Some(true);
+
+        let mut dropped = new_item(
+            "Updater".into(),
+            "/tmp/.cache/updater".into(),
+            true,
+            "systemd (User)".into(),
+            StartupLocator::SystemdUserUnit {
+                unit: "updater.service".into(),
+                fragment_path: "/tmp/updater.service".into(),
+            },
+        );
+        dropped.exe_path = Some("/tmp/.cache/updater".into());
+        dropped.exe_exists = true;
+        dropped.is_signed = Some(false);
+
+        let mut broken = new_item(
+            "Gone".into(),
+            "gone-binary".into(),
+            true,
+            "XDG Autostart (User)".into(),
+            StartupLocator::XdgAutostart {
+                user_path: "/nonexistent/gone.desktop".into(),
+                system_path: None,
+            },
+        );
+        broken.exe_path = Some("gone-binary".into());
+
+        let mut items = vec![packaged, dropped, broken];
+        score_startup_items(&mut items, &[]);
+        assert!(items[0].recommendation == Recommendation::Keep);
+        assert!(items[0].impact_tier == ImpactTier::Low);
+        assert!(items[1].recommendation == Recommendation::Disable);
+        assert!(items[1].impact_tier == ImpactTier::High);
+        assert!(items[2].recommendation == Recommendation::Cleanup);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn systemd_analyze_time_splits_phases_and_main_path() {
+        let mut diag = BootDiagnostics::default();
+        assert!(parse_analyze_time(
+            include_str!("../tests/fixtures/systemd_analyze/time_efi.txt"),
+            &mut diag
+        ));
+        assert_eq!(diag.boot_duration_ms, Some(88_085));
+        assert_eq!(diag.firmware_ms, Some(7_373));
+        assert_eq!(diag.loader_ms, Some(3_130));
+        assert_eq!(diag.userspace_ms, Some(71_857));
+        assert_eq!(diag.main_path_boot_ms, Some(2_197 + 3_526 + 69_830));
+        assert_eq!(diag.post_boot_ms, Some(2_027));
+        assert_eq!(
+            boot_phase_summary(&diag).as_deref(),
+            Some("firmware 7.4s · loader 3.1s · kernel 2.2s · initrd 3.5s · userspace 71.9s")
+        );
+
+        let mut diag = BootDiagnostics::default();
+        assert!(parse_analyze_time(
+            include_str!("../tests/fixtures/systemd_analyze/time_vm.txt"),
+            &mut diag
+        ));
+        assert_eq!(diag.firmware_ms, None);
+        assert_eq!(diag.boot_duration_ms, Some(5_725));
+        assert_eq!(diag.main_path_boot_ms, Some(1_054 + 4_598));
+
+        let mut diag = BootDiagnostics::default();
+        assert!(!parse_analyze_time(
+            include_str!("../tests/fixtures/systemd_analyze/time_unfinished.txt"),
+            &mut diag
+        ));
+        assert_eq!(boot_phase_summary(&diag), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn slow_units_come_from_critical_chain_then_blame() {
+        assert_eq!(parse_timespan("1min 2.441s"), Some(62_441));
+        assert_eq!(parse_timespan("901us"), Some(1));
+        assert_eq!(parse_timespan("soon"), None);
+
+        let blame = parse_blame(include_str!("../tests/fixtures/systemd_analyze/blame.txt"));
+        assert_eq!(blame.len(), 8);
+        assert_eq!(blame[0], ("plymouth-quit-wait.service".to_string(), 62_441));
+        let chain = parse_critical_chain(include_str!("../tests/fixtures/systemd_analyze/critical_chain.txt"));
+        assert_eq!(
+            chain,
+            vec![
+                ("docker.service".to_string(), 2_276),
+                ("NetworkManager-wait-online.service".to_string(), 6_174),
+                ("NetworkManager.service".to_string(), 42),
+                ("snapd.socket".to_string(), 4),
+                ("systemd-udev-settle.service".to_string(), 834),
+            ]
+        );
+        assert_eq!(
+            slow_units(&chain, &blame),
+            vec![
+                "NetworkManager-wait-online.service",
+                "docker.service",
+                "plymouth-quit-wait.service",
+                r"app-org.example.Chat\x2dClient@autostart.service",
+            ]
+        );
+    }
+
+    #[test]
+    fn slow_autostart_entries_are_flagged_through_their_generated_unit() {
+        let locator = StartupLocator::XdgAutostart {
+            user_path: "/home/u/.config/autostart/org.example.Chat-Client.desktop".into(),
+            system_path: None,
+        };
+        let unit = r"app-org.example.Chat\x2dClient@autostart.service";
+        assert_eq!(locator.systemd_unit().as_deref(), Some(unit));
+
+        let mut items = vec![new_item(
+            "Chat".into(),
+            "chat".into(),
+            true,
+            "XDG Autostart".into(),
+            locator,
+        )];
+        score_startup_items(&mut items, &[unit.to_string()]);
+        assert!(items[0].impact_tier == ImpactTier::High);
+        assert_eq!(items[0].reason, "Flagged by systemd-analyze as slowing startup");
+    }
+
+    #[cfg(target_os = "windows")]
     #[test]
     fn test_approved_disabled_logic() {
         assert!(is_approved_disabled(&[0x03, 0x00, 0x00, 0x00]));
@@ -1803,6 +3160,7 @@ mod tests {
         assert!(!is_approved_disabled(&[]));
     }
 
+    #[cfg(target_os = "windows")]
     #[test]
     fn test_get_startup_items_live() {
         let items = get_startup_items();
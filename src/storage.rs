@@ -11,6 +11,7 @@ pub struct PhysicalDiskHealth {
     pub status: String,
     pub smart_status: String,
     pub temperature_c: Option<u32>,
+    /// Rated endurance consumed (NVMe "percentage used"), when the drive reports it.
     pub wear_percentage: Option<u32>,
 }
 
@@ -81,13 +82,254 @@ mod windows_impl {
                     status,
                     smart_status,
                     temperature_c: None,
-                    wear_percentage: Some(100),
+                    wear_percentage: None,
                 }
             })
             .collect()
     }
 }
 
+#[cfg(target_os = "linux")]
+mod linux_impl {
+    use super::*;
+    use crate::providers::sysfs::{read_trimmed, read_u64};
+    use serde_json::Value;
+    use std::path::Path;
+    use std::process::Command;
+
+    /// Block devices that are not physical drives (or have no SMART data).
+    const VIRTUAL_PREFIXES: [&str; 8] = ["loop", "ram", "zram", "dm-", "md", "nbd", "sr", "fd"];
+
+    /// ATA attributes whose normalized value counts remaining life down from 100:
+    /// Wear_Leveling_Count, SSD_Life_Left, Media_Wearout_Indicator,
+    /// Percent_Lifetime_Remain and Remaining_Lifetime_Perc.
+    const LIFE_LEFT_ATTRIBUTES: [u64; 5] = [177, 231, 233, 202, 169];
+
+    /// Health fields recovered from `smartctl --json` or `nvme smart-log -o json`.
+    #[derive(Debug, Default, PartialEq)]
+    pub(super) struct SmartReport {
+        pub passed: Option<bool>,
+        pub temperature_c: Option<u32>,
+        pub wear_percentage: Option<u32>,
+        pub critical_warning: Option<u64>,
+    }
+
+    /// Enumerate physical drives under a `/sys/block`-style directory.
+    pub(super) fn block_devices(root: &Path) -> Vec<PhysicalDiskHealth> {
+        let Ok(entries) = std::fs::read_dir(root) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| !VIRTUAL_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+            .collect();
+        names.sort();
+
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let dir = root.join(&name);
+                if !dir.join("device").exists() {
+                    return None;
+                }
+                let size_bytes = read_u64(&dir.join("size")).unwrap_or(0) * 512;
+                if size_bytes == 0 {
+                    return None;
+                }
+                let device = dir.join("device");
+                let model = read_trimmed(&device.join("model"))
+                    .or_else(|| read_trimmed(&device.join("name")))
+                    .filter(|model| !model.is_empty());
+                let vendor =
+                    read_trimmed(&device.join("vendor")).filter(|vendor| !vendor.is_empty() && vendor != "ATA");
+                let model = match (vendor, model) {
+                    (Some(vendor), Some(model)) if !model.starts_with(&vendor) => format!("{vendor} {model}"),
+                    (_, Some(model)) => model,
+                    (Some(vendor), None) => vendor,
+                    (None, None) => "Generic Physical Disk".to_string(),
+                };
+                let rotational = read_u64(&dir.join("queue").join("rotational")) == Some(1);
+                let removable = read_u64(&dir.join("removable")) == Some(1);
+                let media_type = if name.starts_with("nvme") {
+                    "NVMe SSD"
+                } else if name.starts_with("mmcblk") {
+                    "eMMC / SD Card"
+                } else if removable {
+                    "Removable Media"
+                } else if rotational {
+                    "Hard Disk Drive (HDD)"
+                } else {
+                    "SATA SSD"
+                };
+
+                Some(PhysicalDiskHealth {
+                    device_id: format!("/dev/{name}"),
+                    model,
+                    media_type: media_type.to_string(),
+                    size_bytes,
+                    status: "UNKNOWN".into(),
+                    smart_status: "NOT CHECKED".into(),
+                    temperature_c: None,
+                    wear_percentage: None,
+                })
+            })
+            .collect()
+    }
+
+    fn as_percent(value: u64) -> u32 {
+        value.min(100) as u32
+    }
+
+    /// Parse `smartctl --json -a` output. Fails with smartctl's own error message
+    /// when the device could not be read at all (e.g. permission denied).
+    pub(super) fn parse_smartctl_json(json: &str) -> Result<SmartReport, String> {
+        let root: Value = serde_json::from_str(json).map_err(|error| format!("invalid smartctl JSON: {error}"))?;
+        let nvme_log = &root["nvme_smart_health_information_log"];
+        let report = SmartReport {
+            passed: root["smart_status"]["passed"].as_bool(),
+            temperature_c: root["temperature"]["current"]
+                .as_u64()
+                .or_else(|| nvme_log["temperature"].as_u64())
+                .map(|celsius| celsius as u32),
+            wear_percentage: ata_endurance_indicator(&root)
+                .or_else(|| nvme_log["percentage_used"].as_u64())
+                .or_else(|| ata_wear_attribute(&root))
+                .map(as_percent),
+            critical_warning: nvme_log["critical_warning"].as_u64(),
+        };
+
+        if report == SmartReport::default() {
+            let message = root["smartctl"]["messages"]
+                .as_array()
+                .and_then(|messages| {
+                    messages
+                        .iter()
+                        .find(|message| message["severity"] == "error")
+                        .or_else(|| messages.first())
+                })
+                .and_then(|message| message["string"].as_str())
+                .unwrap_or("smartctl returned no health data");
+            return Err(message.to_string());
+        }
+        Ok(report)
+    }
+
+    /// ATA device statistics "Percentage Used Endurance Indicator" (the ATA
+    /// counterpart of NVMe "percentage used").
+    fn ata_endurance_indicator(root: &Value) -> Option<u64> {
+        root["ata_device_statistics"]["pages"]
+            .as_array()?
+            .iter()
+            .filter_map(|page| page["table"].as_array())
+            .flatten()
+            .find(|entry| entry["name"] == "Percentage Used Endurance Indicator")?["value"]
+            .as_u64()
+    }
+
+    fn ata_wear_attribute(root: &Value) -> Option<u64> {
+        let table = root["ata_smart_attributes"]["table"].as_array()?;
+        LIFE_LEFT_ATTRIBUTES.iter().find_map(|id| {
+            let attribute = table.iter().find(|attribute| attribute["id"].as_u64() == Some(*id))?;
+            let remaining = attribute["value"].as_u64().filter(|value| *value <= 100)?;
+            Some(100 - remaining)
+        })
+    }
+
+    /// Parse `nvme smart-log -o json`. nvme-cli reports the composite
+    /// temperature in Kelvin and has used both `percent_used` and `percentage_used`.
+    pub(super) fn parse_nvme_smart_log(json: &str) -> Result<SmartReport, String> {
+        let root: Value =
+            serde_json::from_str(json).map_err(|error| format!("invalid nvme smart-log JSON: {error}"))?;
+        let critical_warning = root["critical_warning"]
+            .as_u64()
+            .ok_or_else(|| "nvme smart-log returned no health data".to_string())?;
+        Ok(SmartReport {
+            passed: None,
+            temperature_c: root["temperature"]
+                .as_u64()
+                .map(|kelvin| kelvin.saturating_sub(273) as u32),
+            wear_percentage: root["percent_used"]
+                .as_u64()
+                .or_else(|| root["percentage_used"].as_u64())
+                .map(as_percent),
+            critical_warning: Some(critical_warning),
+        })
+    }
+
+    /// Fill in a drive's health fields from a parsed report or a read failure.
+    pub(super) fn apply_report(disk: &mut PhysicalDiskHealth, report: Result<SmartReport, String>) {
+        match report {
+            Ok(report) => {
+                let (status, smart_status) = match (report.passed, report.critical_warning) {
+                    (Some(false), _) => ("CRITICAL", "PREDICTIVE FAILURE".to_string()),
+                    (_, Some(warning)) if warning != 0 => ("WARNING", format!("CRITICAL WARNING 0x{warning:02x}")),
+                    (Some(true), _) | (None, Some(_)) => ("HEALTHY", "PASSED (GOOD)".to_string()),
+                    (None, None) => ("UNKNOWN", "NOT REPORTED".to_string()),
+                };
+                disk.status = status.into();
+                disk.smart_status = smart_status;
+                disk.temperature_c = report.temperature_c;
+                disk.wear_percentage = report.wear_percentage;
+            }
+            Err(error) => {
+                disk.status = "UNKNOWN".into();
+                disk.smart_status = format!("UNAVAILABLE: {error}");
+            }
+        }
+    }
+
+    /// Run a health tool; `Ok(None)` means the tool is not installed. Both tools
+    /// use non-zero exit codes for drive problems, so stdout is parsed regardless.
+    fn run_tool(program: &str, args: &[&str]) -> Result<Option<String>, String> {
+        match Command::new(program).args(args).output() {
+            Ok(output) => Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(format!("could not run {program}: {error}")),
+        }
+    }
+
+    fn query_health(disk: &PhysicalDiskHealth) -> Result<SmartReport, String> {
+        let device = disk.device_id.as_str();
+        if let Some(output) = run_tool("smartctl", &["--json", "-a", "-n", "standby", device])? {
+            return parse_smartctl_json(&output);
+        }
+        if disk.media_type == "NVMe SSD" {
+            match run_tool("nvme", &["smart-log", "-o", "json", device])? {
+                Some(output) if !output.trim().is_empty() => return parse_nvme_smart_log(&output),
+                Some(_) => return Err("nvme smart-log failed (root access is usually required)".into()),
+                None => return Err("neither smartctl (smartmontools) nor nvme-cli is installed".into()),
+            }
+        }
+        Err("smartctl is not installed (install smartmontools)".into())
+    }
+
+    /// Whole-disk name behind a device node (`/dev/nvme0n1p2` → `nvme0n1`,
+    /// `/dev/mapper/root` → `dm-0`), following the `class/block` links below `sys_root`.
+    pub(super) fn block_device_of(sys_root: &Path, device: &Path) -> Option<String> {
+        let name = std::fs::canonicalize(device)
+            .ok()?
+            .file_name()?
+            .to_string_lossy()
+            .into_owned();
+        let entry = sys_root.join("class/block").join(&name);
+        if !entry.join("partition").exists() {
+            return entry.exists().then_some(name);
+        }
+        let resolved = std::fs::canonicalize(&entry).ok()?;
+        Some(resolved.parent()?.file_name()?.to_string_lossy().into_owned())
+    }
+
+    pub fn get_physical_disks_internal() -> Vec<PhysicalDiskHealth> {
+        let mut disks = block_devices(Path::new("/sys/block"));
+        for disk in &mut disks {
+            let report = query_health(disk);
+            apply_report(disk, report);
+        }
+        disks
+    }
+}
+
 /// Retrieve physical disk drive health and SMART status.
 pub fn get_physical_disks() -> Vec<PhysicalDiskHealth> {
     #[cfg(target_os = "windows")]
@@ -95,12 +337,32 @@ pub fn get_physical_disks() -> Vec<PhysicalDiskHealth> {
         windows_impl::get_physical_disks_internal()
     }
 
-    #[cfg(not(target_os = "windows"))]
+    #[cfg(target_os = "linux")]
+    {
+        linux_impl::get_physical_disks_internal()
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
     {
         Vec::new()
     }
 }
 
+/// Kernel name of the disk holding a mounted device such as `/dev/sda1`,
+/// matching the device names in `/proc/diskstats`. Linux only.
+pub fn block_device_of(device: &str) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        linux_impl::block_device_of(std::path::Path::new("/sys"), std::path::Path::new(device))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = device;
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +390,146 @@ mod tests {
             assert!(!d.model.is_empty());
         }
     }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn partitions_and_mapper_nodes_resolve_to_their_disk() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "storage-resolve",
+            &[
+                ("devices/pci0/nvme0n1/nvme0n1p2/partition", "2"),
+                ("devices/virtual/block/dm-0/dm/name", "root"),
+                ("dev/nvme0n1p2", ""),
+                ("dev/dm-0", ""),
+            ],
+        );
+        std::fs::create_dir_all(root.join("class/block")).unwrap();
+        let link = |target: &str, name: &str| std::os::unix::fs::symlink(root.join(target), root.join(name)).unwrap();
+        link("devices/pci0/nvme0n1/nvme0n1p2", "class/block/nvme0n1p2");
+        link("devices/pci0/nvme0n1", "class/block/nvme0n1");
+        link("devices/virtual/block/dm-0", "class/block/dm-0");
+        std::fs::create_dir_all(root.join("dev/mapper")).unwrap();
+        link("dev/dm-0", "dev/mapper/root");
+
+        let resolve = |device: &str| linux_impl::block_device_of(&root, &root.join(device));
+        assert_eq!(resolve("dev/nvme0n1p2").as_deref(), Some("nvme0n1"));
+        assert_eq!(resolve("dev/mapper/root").as_deref(), Some("dm-0"));
+        assert_eq!(resolve("dev/missing"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn sys_block_enumeration_classifies_media() {
+        let root = crate::providers::sysfs::TempTree::with_files(
+            "storage-media",
+            &[
+                ("nvme0n1/size", "1953525168"),
+                ("nvme0n1/queue/rotational", "0"),
+                ("nvme0n1/device/model", "Samsung SSD 980 PRO 1TB"),
+                ("sda/size", "976773168"),
+                ("sda/queue/rotational", "0"),
+                ("sda/device/vendor", "ATA"),
+                ("sda/device/model", "Samsung SSD 860"),
+                ("sdb/size", "1953525168"),
+                ("sdb/queue/rotational", "1"),
+                ("sdb/device/model", "WDC WD10EZEX-08W"),
+                ("loop0/size", "8192"),
+                ("loop0/queue/rotational", "0"),
+                ("sdz/size", "0"),
+                ("sdz/device/model", "Card Reader"),
+            ],
+        );
+
+        let disks = linux_impl::block_devices(&root);
+        let summary: Vec<_> = disks
+            .iter()
+            .map(|disk| (disk.device_id.as_str(), disk.media_type.as_str(), disk.model.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("/dev/nvme0n1", "NVMe SSD", "Samsung SSD 980 PRO 1TB"),
+                ("/dev/sda", "SATA SSD", "Samsung SSD 860"),
+                ("/dev/sdb", "Hard Disk Drive (HDD)", "WDC WD10EZEX-08W"),
+            ]
+        );
+        assert_eq!(disks[0].size_bytes, 1_000_204_886_016);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn smartctl_nvme_reports_percentage_used() {
+        let report = linux_impl::parse_smartctl_json(include_str!("../tests/fixtures/smart/nvme.json")).unwrap();
+        assert_eq!(report.passed, Some(true));
+        assert_eq!(report.temperature_c, Some(41));
+        assert_eq!(report.wear_percentage, Some(3));
+        assert_eq!(report.critical_warning, Some(0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn smartctl_sata_ssd_wear_comes_from_attributes() {
+        let report = linux_impl::parse_smartctl_json(include_str!("../tests/fixtures/smart/sata_ssd.json")).unwrap();
+        assert_eq!(report.wear_percentage, Some(12));
+        assert_eq!(report.temperature_c, Some(34));
+
+        // The device statistics endurance indicator wins over the vendor attribute.
+        let report =
+            linux_impl::parse_smartctl_json(include_str!("../tests/fixtures/smart/sata_ssd_devstat.json")).unwrap();
+        assert_eq!(report.wear_percentage, Some(6));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn smartctl_failing_hdd_is_critical() {
+        let mut disk = PhysicalDiskHealth {
+            device_id: "/dev/sdc".into(),
+            model: "WDC WD10EZEX-08WN4A0".into(),
+            media_type: "Hard Disk Drive (HDD)".into(),
+            size_bytes: 1_000_204_886_016,
+            status: "UNKNOWN".into(),
+            smart_status: "NOT CHECKED".into(),
+            temperature_c: None,
+            wear_percentage: None,
+        };
+        let report = linux_impl::parse_smartctl_json(include_str!("../tests/fixtures/smart/hdd_failing.json"));
+        linux_impl::apply_report(&mut disk, report);
+        assert_eq!(disk.status, "CRITICAL");
+        assert_eq!(disk.smart_status, "PREDICTIVE FAILURE");
+        assert_eq!(disk.temperature_c, Some(39));
+        assert_eq!(disk.wear_percentage, None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn smartctl_errors_are_reported() {
+        let error = linux_impl::parse_smartctl_json(include_str!("../tests/fixtures/smart/permission_denied.json"))
+            .unwrap_err();
+        assert!(error.contains("Permission denied"), "{error}");
+        assert!(linux_impl::parse_smartctl_json("not json").is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn nvme_cli_smart_log_converts_kelvin_and_caps_wear() {
+        let report =
+            linux_impl::parse_nvme_smart_log(include_str!("../tests/fixtures/smart/nvme_cli_smart_log.json")).unwrap();
+        assert_eq!(report.temperature_c, Some(45));
+        assert_eq!(report.wear_percentage, Some(100));
+        assert_eq!(report.critical_warning, Some(4));
+
+        let mut disk = PhysicalDiskHealth {
+            device_id: "/dev/nvme0n1".into(),
+            model: "Generic Physical Disk".into(),
+            media_type: "NVMe SSD".into(),
+            size_bytes: 0,
+            status: "UNKNOWN".into(),
+            smart_status: "NOT CHECKED".into(),
+            temperature_c: None,
+            wear_percentage: None,
+        };
+        linux_impl::apply_report(&mut disk, Ok(report));
+        assert_eq!(disk.status, "WARNING");
+        assert_eq!(disk.smart_status, "CRITICAL WARNING 0x04");
+    }
 }
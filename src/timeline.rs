@@ -3,6 +3,7 @@
 //! SQLite is owned by a dedicated worker thread. The UI and monitoring loop only
 //! exchange small commands and immutable result snapshots with that worker.
 
+use crate::monitoring::snapshot::{LoadSnapshot, PressureSnapshot};
 use crate::monitoring::SystemSnapshot;
 use chrono::Utc;
 use parking_lot::Mutex;
@@ -15,7 +16,7 @@ use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-const SCHEMA_VERSION: i64 = 1;
+const SCHEMA_VERSION: i64 = 4;
 const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
 const MAX_DATABASE_BYTES: u64 = 512 * 1024 * 1024;
 const DAY_MS: i64 = 86_400_000;
@@ -99,6 +100,8 @@ pub(crate) enum TimelineEventKind {
     PowerChanged,
     ServiceChanged,
     StartupChanged,
+    ThrottlingStarted,
+    ThrottlingEnded,
     System,
 }
 
@@ -116,6 +119,8 @@ impl TimelineEventKind {
             Self::PowerChanged => "power_changed",
             Self::ServiceChanged => "service_changed",
             Self::StartupChanged => "startup_changed",
+            Self::ThrottlingStarted => "throttling_started",
+            Self::ThrottlingEnded => "throttling_ended",
             Self::System => "system",
         }
     }
@@ -133,6 +138,8 @@ impl TimelineEventKind {
             "power_changed" => Self::PowerChanged,
             "service_changed" => Self::ServiceChanged,
             "startup_changed" => Self::StartupChanged,
+            "throttling_started" => Self::ThrottlingStarted,
+            "throttling_ended" => Self::ThrottlingEnded,
             _ => Self::System,
         }
     }
@@ -201,6 +208,10 @@ pub(crate) struct TimelineMetricSample {
     pub(crate) network_down_bps: f64,
     pub(crate) network_up_bps: f64,
     pub(crate) paused: bool,
+    #[serde(default)]
+    pub(crate) pressure: Option<PressureSnapshot>,
+    #[serde(default)]
+    pub(crate) load: Option<LoadSnapshot>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -213,6 +224,8 @@ pub(crate) struct TimelineProcessSample {
     pub(crate) memory_bytes: u64,
     pub(crate) disk_read_bytes: u64,
     pub(crate) disk_write_bytes: u64,
+    pub(crate) gpu_pct: f64,
+    pub(crate) gpu_memory_bytes: u64,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -492,6 +505,7 @@ fn run_worker(
     let mut previous_providers: HashMap<String, bool> = HashMap::new();
     let mut previous_paused: Option<bool> = None;
     let mut previous_power: Option<String> = None;
+    let mut previous_throttled = false;
 
     if enabled {
         if let Err(error) = ensure_connection(&mut connection, &path, true) {
@@ -510,6 +524,7 @@ fn run_worker(
                         &mut previous_providers,
                         &mut previous_paused,
                         &mut previous_power,
+                        &mut previous_throttled,
                     )?;
                     write_snapshot(conn, &snapshot)?;
                     if last_prune.elapsed() >= Duration::from_secs(86_400) || storage_bytes(&path) > MAX_DATABASE_BYTES
@@ -675,6 +690,46 @@ fn migrate(conn: &Connection) -> Result<(), String> {
         )
         .map_err(|error| format!("Could not create timeline schema: {error}"))?;
     }
+    if version < 2 {
+        conn.execute_batch(
+            "BEGIN;
+             ALTER TABLE process_samples ADD COLUMN gpu_pct REAL NOT NULL DEFAULT 0;
+             ALTER TABLE process_samples ADD COLUMN gpu_memory_bytes INTEGER NOT NULL DEFAULT 0;
+             PRAGMA user_version=2;
+             COMMIT;",
+        )
+        .map_err(|error| format!("Could not migrate timeline schema: {error}"))?;
+    }
+    if version < 3 {
+        conn.execute_batch(
+            "BEGIN;
+             ALTER TABLE metric_samples ADD COLUMN psi_cpu_some_pct REAL;
+             ALTER TABLE metric_samples ADD COLUMN psi_memory_some_pct REAL;
+             ALTER TABLE metric_samples ADD COLUMN psi_memory_full_pct REAL;
+             ALTER TABLE metric_samples ADD COLUMN psi_io_some_pct REAL;
+             ALTER TABLE metric_samples ADD COLUMN psi_io_full_pct REAL;
+             PRAGMA user_version=3;
+             COMMIT;",
+        )
+        .map_err(|error| format!("Could not migrate timeline schema: {error}"))?;
+    }
+    if version < 4 {
+        conn.execute_batch(
+            "BEGIN;
+             ALTER TABLE metric_samples ADD COLUMN load_1m REAL;
+             ALTER TABLE metric_samples ADD COLUMN load_5m REAL;
+             ALTER TABLE metric_samples ADD COLUMN load_15m REAL;
+             ALTER TABLE metric_samples ADD COLUMN procs_running INTEGER;
+             ALTER TABLE metric_samples ADD COLUMN procs_blocked INTEGER;
+             ALTER TABLE metric_samples ADD COLUMN context_switches_per_sec REAL;
+             ALTER TABLE metric_samples ADD COLUMN interrupts_per_sec REAL;
+             ALTER TABLE metric_samples ADD COLUMN softirqs_per_sec REAL;
+             ALTER TABLE metric_samples ADD COLUMN forks_per_sec REAL;
+             PRAGMA user_version=4;
+             COMMIT;",
+        )
+        .map_err(|error| format!("Could not migrate timeline schema: {error}"))?;
+    }
     Ok(())
 }
 
@@ -689,8 +744,12 @@ fn write_snapshot(conn: &mut Connection, snapshot: &SystemSnapshot) -> Result<()
         .execute(
             "INSERT OR REPLACE INTO metric_samples
              (timestamp_ms, cpu_pct, memory_pct, gpu_pct, cpu_temp_c, gpu_temp_c,
-              disk_read_bps, disk_write_bps, network_down_bps, network_up_bps, paused)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+              disk_read_bps, disk_write_bps, network_down_bps, network_up_bps, paused,
+              psi_cpu_some_pct, psi_memory_some_pct, psi_memory_full_pct, psi_io_some_pct, psi_io_full_pct,
+              load_1m, load_5m, load_15m, procs_running, procs_blocked,
+              context_switches_per_sec, interrupts_per_sec, softirqs_per_sec, forks_per_sec)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                     ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)",
             params![
                 timestamp_ms,
                 metric.cpu_pct,
@@ -702,7 +761,21 @@ fn write_snapshot(conn: &mut Connection, snapshot: &SystemSnapshot) -> Result<()
                 metric.disk_write_bps,
                 metric.network_down_bps,
                 metric.network_up_bps,
-                metric.paused
+                metric.paused,
+                metric.pressure.map(|pressure| pressure.cpu_some),
+                metric.pressure.map(|pressure| pressure.memory_some),
+                metric.pressure.map(|pressure| pressure.memory_full),
+                metric.pressure.map(|pressure| pressure.io_some),
+                metric.pressure.map(|pressure| pressure.io_full),
+                metric.load.map(|load| load.load_1m),
+                metric.load.map(|load| load.load_5m),
+                metric.load.map(|load| load.load_15m),
+                metric.load.map(|load| load.procs_running),
+                metric.load.map(|load| load.procs_blocked),
+                metric.load.map(|load| load.context_switches_per_sec),
+                metric.load.map(|load| load.interrupts_per_sec),
+                metric.load.map(|load| load.softirqs_per_sec),
+                metric.load.map(|load| load.forks_per_sec)
             ],
         )
         .map_err(|error| format!("Could not write timeline metrics: {error}"))?;
@@ -713,8 +786,9 @@ fn write_snapshot(conn: &mut Connection, snapshot: &SystemSnapshot) -> Result<()
         let mut statement = transaction
             .prepare_cached(
                 "INSERT INTO process_samples
-                 (timestamp_ms, pid, start_time, name, cpu_pct, memory_bytes, disk_read_bytes, disk_write_bytes)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                 (timestamp_ms, pid, start_time, name, cpu_pct, memory_bytes, disk_read_bytes, disk_write_bytes,
+                  gpu_pct, gpu_memory_bytes)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             )
             .map_err(|error| format!("Could not prepare timeline process write: {error}"))?;
         for process in processes {
@@ -727,7 +801,9 @@ fn write_snapshot(conn: &mut Connection, snapshot: &SystemSnapshot) -> Result<()
                     process.cpu_pct,
                     to_sql_i64(process.memory_bytes),
                     to_sql_i64(process.disk_read_bytes),
-                    to_sql_i64(process.disk_write_bytes)
+                    to_sql_i64(process.disk_write_bytes),
+                    process.gpu_pct,
+                    to_sql_i64(process.gpu_memory_bytes)
                 ])
                 .map_err(|error| format!("Could not write timeline process sample: {error}"))?;
         }
@@ -762,6 +838,8 @@ fn metric_from_snapshot(snapshot: &SystemSnapshot) -> TimelineMetricSample {
             .map(|network| network.transmitted_bytes_per_second)
             .sum(),
         paused: snapshot.paused,
+        pressure: snapshot.pressure,
+        load: snapshot.load,
     }
 }
 
@@ -770,15 +848,19 @@ fn select_process_union(snapshot: &SystemSnapshot, timestamp_ms: i64, limit: usi
     let mut by_cpu: Vec<_> = snapshot.processes.iter().collect();
     let mut by_memory = by_cpu.clone();
     let mut by_disk = by_cpu.clone();
+    let mut by_gpu = by_cpu.clone();
     by_cpu.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
     by_memory.sort_by_key(|process| std::cmp::Reverse(process.memory));
     by_disk
         .sort_by_key(|process| std::cmp::Reverse(process.disk_read_bytes.saturating_add(process.disk_written_bytes)));
+    by_gpu.retain(|process| process.gpu_usage > 0.0 || process.gpu_memory > 0);
+    by_gpu.sort_by(|a, b| b.gpu_usage.total_cmp(&a.gpu_usage));
     for process in by_cpu
         .into_iter()
         .take(limit)
         .chain(by_memory.into_iter().take(limit))
         .chain(by_disk.into_iter().take(limit))
+        .chain(by_gpu.into_iter().take(limit))
     {
         selected.insert((process.pid, process.start_time), process);
     }
@@ -793,6 +875,8 @@ fn select_process_union(snapshot: &SystemSnapshot, timestamp_ms: i64, limit: usi
             memory_bytes: process.memory,
             disk_read_bytes: process.disk_read_bytes,
             disk_write_bytes: process.disk_written_bytes,
+            gpu_pct: process.gpu_usage as f64,
+            gpu_memory_bytes: process.gpu_memory,
         })
         .collect()
 }
@@ -803,6 +887,7 @@ fn record_derived_events(
     previous_providers: &mut HashMap<String, bool>,
     previous_paused: &mut Option<bool>,
     previous_power: &mut Option<String>,
+    previous_throttled: &mut bool,
 ) -> Result<(), String> {
     for (name, provider) in &snapshot.provider_status {
         if let Some(previous) = previous_providers.insert(name.clone(), provider.available) {
@@ -885,6 +970,32 @@ fn record_derived_events(
     if power.is_some() {
         *previous_power = power;
     }
+
+    let throttled = snapshot.throttle.is_some_and(|throttle| throttle.sustained);
+    if throttled != std::mem::replace(previous_throttled, throttled) {
+        insert_event(
+            conn,
+            &TimelineEvent {
+                id: None,
+                timestamp_ms: system_time_ms(snapshot.sampled_at),
+                kind: if throttled {
+                    TimelineEventKind::ThrottlingStarted
+                } else {
+                    TimelineEventKind::ThrottlingEnded
+                },
+                source: "cpu".into(),
+                severity: if throttled { "warning" } else { "info" }.into(),
+                summary: if throttled {
+                    "CPU throttling sustained".into()
+                } else {
+                    "CPU throttling cleared".into()
+                },
+                evidence: snapshot
+                    .throttle
+                    .map_or_else(|| "Clock telemetry stopped".into(), |throttle| throttle.evidence()),
+            },
+        )?;
+    }
     Ok(())
 }
 
@@ -910,7 +1021,10 @@ fn query_window(conn: &Connection, query: TimelineQuery) -> Result<TimelineWindo
     let mut metrics_statement = conn
         .prepare_cached(
             "SELECT timestamp_ms, cpu_pct, memory_pct, gpu_pct, cpu_temp_c, gpu_temp_c,
-                    disk_read_bps, disk_write_bps, network_down_bps, network_up_bps, paused
+                    disk_read_bps, disk_write_bps, network_down_bps, network_up_bps, paused,
+                    psi_cpu_some_pct, psi_memory_some_pct, psi_memory_full_pct, psi_io_some_pct, psi_io_full_pct,
+                    load_1m, load_5m, load_15m, procs_running, procs_blocked,
+                    context_switches_per_sec, interrupts_per_sec, softirqs_per_sec, forks_per_sec
              FROM metric_samples WHERE timestamp_ms BETWEEN ?1 AND ?2 ORDER BY timestamp_ms",
         )
         .map_err(|error| format!("Could not prepare timeline metric query: {error}"))?;
@@ -928,6 +1042,8 @@ fn query_window(conn: &Connection, query: TimelineQuery) -> Result<TimelineWindo
                 network_down_bps: row.get(8)?,
                 network_up_bps: row.get(9)?,
                 paused: row.get(10)?,
+                pressure: pressure_from_row(row, 11)?,
+                load: load_from_row(row, 16)?,
             })
         })
         .map_err(|error| format!("Could not query timeline metrics: {error}"))?
@@ -991,7 +1107,8 @@ fn query_window(conn: &Connection, query: TimelineQuery) -> Result<TimelineWindo
     let mut processes = Vec::new();
     let mut process_statement = conn
         .prepare_cached(
-            "SELECT timestamp_ms, pid, start_time, name, cpu_pct, memory_bytes, disk_read_bytes, disk_write_bytes
+            "SELECT timestamp_ms, pid, start_time, name, cpu_pct, memory_bytes, disk_read_bytes, disk_write_bytes,
+                    gpu_pct, gpu_memory_bytes
              FROM process_samples WHERE timestamp_ms = ?1 ORDER BY cpu_pct DESC",
         )
         .map_err(|error| format!("Could not prepare timeline process query: {error}"))?;
@@ -1007,6 +1124,8 @@ fn query_window(conn: &Connection, query: TimelineQuery) -> Result<TimelineWindo
                     memory_bytes: from_sql_i64(row.get(5)?),
                     disk_read_bytes: from_sql_i64(row.get(6)?),
                     disk_write_bytes: from_sql_i64(row.get(7)?),
+                    gpu_pct: row.get(8)?,
+                    gpu_memory_bytes: from_sql_i64(row.get(9)?),
                 })
             })
             .map_err(|error| format!("Could not query timeline processes: {error}"))?;
@@ -1024,6 +1143,44 @@ fn query_window(conn: &Connection, query: TimelineQuery) -> Result<TimelineWindo
     })
 }
 
+/// Rows are written with all five pressure columns or none of them.
+fn pressure_from_row(row: &rusqlite::Row<'_>, first: usize) -> rusqlite::Result<Option<PressureSnapshot>> {
+    let Some(cpu_some) = row.get::<_, Option<f64>>(first)? else {
+        return Ok(None);
+    };
+    let column =
+        |offset: usize| -> rusqlite::Result<f64> { Ok(row.get::<_, Option<f64>>(first + offset)?.unwrap_or_default()) };
+    Ok(Some(PressureSnapshot {
+        cpu_some,
+        memory_some: column(1)?,
+        memory_full: column(2)?,
+        io_some: column(3)?,
+        io_full: column(4)?,
+    }))
+}
+
+/// Rows are written with all nine load columns or none of them.
+fn load_from_row(row: &rusqlite::Row<'_>, first: usize) -> rusqlite::Result<Option<LoadSnapshot>> {
+    let Some(load_1m) = row.get::<_, Option<f64>>(first)? else {
+        return Ok(None);
+    };
+    let real =
+        |offset: usize| -> rusqlite::Result<f64> { Ok(row.get::<_, Option<f64>>(first + offset)?.unwrap_or_default()) };
+    let count =
+        |offset: usize| -> rusqlite::Result<u32> { Ok(row.get::<_, Option<u32>>(first + offset)?.unwrap_or_default()) };
+    Ok(Some(LoadSnapshot {
+        load_1m,
+        load_5m: real(1)?,
+        load_15m: real(2)?,
+        procs_running: count(3)?,
+        procs_blocked: count(4)?,
+        context_switches_per_sec: real(5)?,
+        interrupts_per_sec: real(6)?,
+        softirqs_per_sec: real(7)?,
+        forks_per_sec: real(8)?,
+    }))
+}
+
 pub(crate) fn analyze_window(window: &TimelineWindow, timestamp_ms: i64) -> IncidentAnalysis {
     let Some(peak) = window
         .metrics
@@ -1109,12 +1266,31 @@ pub(crate) fn analyze_window(window: &TimelineWindow, timestamp_ms: i64) -> Inci
     } else {
         "low"
     };
+    let title = format!("{} change near selected time", primary.0);
+    let summary = primary.2.clone();
+    let mut evidence: Vec<String> = ranked.into_iter().map(|entry| entry.2).collect();
+    if let Some(pressure) = peak.pressure {
+        let stalled: Vec<_> = baseline.iter().filter_map(|sample| sample.pressure).collect();
+        let stall_base = |select: fn(&PressureSnapshot) -> f64| {
+            stalled.iter().map(select).sum::<f64>() / stalled.len().max(1) as f64
+        };
+        evidence.push(format!(
+            "Tasks were stalled on memory {:.1}% (baseline {:.1}%), I/O {:.1}% (baseline {:.1}%) and CPU {:.1}% \
+             (baseline {:.1}%) of the time.",
+            pressure.memory_some,
+            stall_base(|pressure| pressure.memory_some),
+            pressure.io_some,
+            stall_base(|pressure| pressure.io_some),
+            pressure.cpu_some,
+            stall_base(|pressure| pressure.cpu_some)
+        ));
+    }
     IncidentAnalysis {
         timestamp_ms: peak.timestamp_ms,
-        title: format!("{} change near selected time", primary.0),
-        summary: primary.2.clone(),
+        title,
+        summary,
         confidence: confidence.into(),
-        evidence: ranked.into_iter().map(|entry| entry.2).collect(),
+        evidence,
         contributors: contributors_near(window, peak.timestamp_ms),
     }
 }
@@ -1241,22 +1417,57 @@ fn export_window(window: &TimelineWindow, destination: &Path) -> Result<PathBuf,
             "network_down_bps",
             "network_up_bps",
             "paused",
+            "psi_cpu_some_pct",
+            "psi_memory_some_pct",
+            "psi_memory_full_pct",
+            "psi_io_some_pct",
+            "psi_io_full_pct",
+            "load_1m",
+            "load_5m",
+            "load_15m",
+            "procs_running",
+            "procs_blocked",
+            "context_switches_per_sec",
+            "interrupts_per_sec",
+            "softirqs_per_sec",
+            "forks_per_sec",
         ])
         .map_err(|error| format!("Could not write metric header: {error}"))?;
     for sample in &window.metrics {
+        // Serde stops at 16-element tuples; the csv writer flattens the nesting.
         metrics
             .serialize((
-                timestamp_rfc3339(sample.timestamp_ms),
-                sample.cpu_pct,
-                sample.memory_pct,
-                sample.gpu_pct,
-                sample.cpu_temp_c,
-                sample.gpu_temp_c,
-                sample.disk_read_bps,
-                sample.disk_write_bps,
-                sample.network_down_bps,
-                sample.network_up_bps,
-                sample.paused,
+                (
+                    timestamp_rfc3339(sample.timestamp_ms),
+                    sample.cpu_pct,
+                    sample.memory_pct,
+                    sample.gpu_pct,
+                    sample.cpu_temp_c,
+                    sample.gpu_temp_c,
+                    sample.disk_read_bps,
+                    sample.disk_write_bps,
+                    sample.network_down_bps,
+                    sample.network_up_bps,
+                    sample.paused,
+                ),
+                (
+                    sample.pressure.map(|pressure| pressure.cpu_some),
+                    sample.pressure.map(|pressure| pressure.memory_some),
+                    sample.pressure.map(|pressure| pressure.memory_full),
+                    sample.pressure.map(|pressure| pressure.io_some),
+                    sample.pressure.map(|pressure| pressure.io_full),
+                ),
+                (
+                    sample.load.map(|load| load.load_1m),
+                    sample.load.map(|load| load.load_5m),
+                    sample.load.map(|load| load.load_15m),
+                    sample.load.map(|load| load.procs_running),
+                    sample.load.map(|load| load.procs_blocked),
+                    sample.load.map(|load| load.context_switches_per_sec),
+                    sample.load.map(|load| load.interrupts_per_sec),
+                    sample.load.map(|load| load.softirqs_per_sec),
+                    sample.load.map(|load| load.forks_per_sec),
+                ),
             ))
             .map_err(|error| format!("Could not write metric export: {error}"))?;
     }
@@ -1276,6 +1487,8 @@ fn export_window(window: &TimelineWindow, destination: &Path) -> Result<PathBuf,
             "memory_bytes",
             "disk_read_bytes",
             "disk_write_bytes",
+            "gpu_pct",
+            "gpu_memory_bytes",
         ])
         .map_err(|error| format!("Could not write process header: {error}"))?;
     for process in &window.processes {
@@ -1289,6 +1502,8 @@ fn export_window(window: &TimelineWindow, destination: &Path) -> Result<PathBuf,
                 process.memory_bytes,
                 process.disk_read_bytes,
                 process.disk_write_bytes,
+                process.gpu_pct,
+                process.gpu_memory_bytes,
             ))
             .map_err(|error| format!("Could not write process export: {error}"))?;
     }
@@ -1367,6 +1582,8 @@ mod tests {
                 status: "Run".into(),
                 disk_read_bytes: 20,
                 disk_written_bytes: 30,
+                gpu_usage: 12.5,
+                gpu_memory: 4096,
             }],
             ..Default::default()
         }
@@ -1408,6 +1625,8 @@ mod tests {
         .unwrap();
         assert_eq!(window.metrics.len(), 1);
         assert_eq!(window.processes[0].name, "worker.exe");
+        assert_eq!(window.processes[0].gpu_pct, 12.5);
+        assert_eq!(window.processes[0].gpu_memory_bytes, 4096);
 
         let columns: String = conn
             .as_ref()
@@ -1426,6 +1645,118 @@ mod tests {
         let _ = std::fs::remove_file(path);
     }
 
+    #[test]
+    fn version_one_history_gains_gpu_columns() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE metric_samples (
+               timestamp_ms INTEGER PRIMARY KEY,
+               cpu_pct REAL NOT NULL,
+               memory_pct REAL NOT NULL
+             );
+             CREATE TABLE process_samples (
+               timestamp_ms INTEGER NOT NULL,
+               pid INTEGER NOT NULL,
+               start_time INTEGER NOT NULL,
+               name TEXT NOT NULL,
+               cpu_pct REAL NOT NULL,
+               memory_bytes INTEGER NOT NULL,
+               disk_read_bytes INTEGER NOT NULL,
+               disk_write_bytes INTEGER NOT NULL
+             );
+             INSERT INTO process_samples VALUES (1, 42, 1234, 'worker.exe', 5.0, 100, 0, 0);
+             PRAGMA user_version=1;",
+        )
+        .unwrap();
+        migrate(&conn).unwrap();
+
+        let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+        let (gpu_pct, gpu_memory): (f64, i64) = conn
+            .query_row("SELECT gpu_pct, gpu_memory_bytes FROM process_samples", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!((gpu_pct, gpu_memory), (0.0, 0));
+    }
+
+    #[test]
+    fn pressure_columns_round_trip_and_stay_empty_without_psi() {
+        let conn = Connection::open_in_memory().unwrap();
+        migrate(&conn).unwrap();
+        let mut conn = Some(conn);
+        let pressure = PressureSnapshot {
+            cpu_some: 1.5,
+            memory_some: 22.0,
+            memory_full: 9.5,
+            io_some: 40.0,
+            io_full: 12.25,
+        };
+        write_snapshot(conn.as_mut().unwrap(), &snapshot(10_000)).unwrap();
+        write_snapshot(
+            conn.as_mut().unwrap(),
+            &SystemSnapshot {
+                pressure: Some(pressure),
+                ..snapshot(11_000)
+            },
+        )
+        .unwrap();
+        let window = query_window(
+            conn.as_ref().unwrap(),
+            TimelineQuery {
+                start_ms: 0,
+                end_ms: 20_000,
+            },
+        )
+        .unwrap();
+        assert_eq!(window.metrics[0].pressure, None);
+        assert_eq!(window.metrics[1].pressure, Some(pressure));
+    }
+
+    #[test]
+    fn load_columns_round_trip_and_export() {
+        let conn = Connection::open_in_memory().unwrap();
+        migrate(&conn).unwrap();
+        let mut conn = Some(conn);
+        let load = LoadSnapshot {
+            load_1m: 3.5,
+            load_5m: 2.25,
+            load_15m: 1.0,
+            procs_running: 6,
+            procs_blocked: 2,
+            context_switches_per_sec: 48_000.0,
+            interrupts_per_sec: 21_000.0,
+            softirqs_per_sec: 9_500.0,
+            forks_per_sec: 12.0,
+        };
+        write_snapshot(conn.as_mut().unwrap(), &snapshot(10_000)).unwrap();
+        write_snapshot(
+            conn.as_mut().unwrap(),
+            &SystemSnapshot {
+                load: Some(load),
+                ..snapshot(11_000)
+            },
+        )
+        .unwrap();
+        let window = query_window(
+            conn.as_ref().unwrap(),
+            TimelineQuery {
+                start_ms: 0,
+                end_ms: 20_000,
+            },
+        )
+        .unwrap();
+        assert_eq!(window.metrics[0].load, None);
+        assert_eq!(window.metrics[1].load, Some(load));
+
+        let destination = crate::providers::sysfs::TempTree::new("timeline-export");
+        let directory = export_window(&window, &destination).unwrap();
+        let csv = std::fs::read_to_string(directory.join("metrics.csv")).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert!(rows[0].ends_with("softirqs_per_sec,forks_per_sec"));
+        assert!(rows[2].ends_with(",3.5,2.25,1.0,6,2,48000.0,21000.0,9500.0,12.0"));
+    }
+
     #[test]
     fn analysis_refuses_to_guess_without_baseline() {
         let window = TimelineWindow {
@@ -1465,6 +1796,47 @@ mod tests {
         assert_eq!(decoded.source, "gpu");
     }
 
+    #[test]
+    fn sustained_throttling_transitions_become_events() {
+        let path = temp_db("throttle");
+        let mut conn = None;
+        ensure_connection(&mut conn, &path, true).unwrap();
+        let conn = conn.unwrap();
+        let throttle = |sustained| crate::monitoring::snapshot::ThrottleSnapshot {
+            average_mhz: 1900.0,
+            max_mhz: Some(3800.0),
+            sustained,
+            ..Default::default()
+        };
+        let (mut providers, mut paused, mut power, mut throttled) = (HashMap::new(), None, None, false);
+        let start = now_ms();
+        for (offset, sustained) in [(0, false), (1_000, true), (2_000, true), (3_000, false)] {
+            let sample = SystemSnapshot {
+                throttle: Some(throttle(sustained)),
+                ..snapshot(start + offset)
+            };
+            record_derived_events(&conn, &sample, &mut providers, &mut paused, &mut power, &mut throttled).unwrap();
+        }
+
+        let window = query_window(
+            &conn,
+            TimelineQuery {
+                start_ms: start - 1,
+                end_ms: start + 5_000,
+            },
+        )
+        .unwrap();
+        // Newest first.
+        let kinds: Vec<_> = window.events.iter().map(|event| event.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![TimelineEventKind::ThrottlingEnded, TimelineEventKind::ThrottlingStarted]
+        );
+        assert!(window.events[1].evidence.contains("50% of the 3800 MHz maximum"));
+        drop(conn);
+        remove_database_files(&path);
+    }
+
     #[test]
     fn retention_prunes_expired_metrics_and_processes() {
         let path = temp_db("retention");
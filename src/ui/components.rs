@@ -170,6 +170,50 @@ pub(crate) fn paint_progress_bar(ui: &mut egui::Ui, fraction: f32, fill: egui::C
     }
 }
 
+/// CPU affinity choices for a process menu: every core, then the topology's
+/// groups (core types, NUMA nodes, one thread per core) or single cores when
+/// the topology is unknown. Choices an affinity mask cannot hold, such as all
+/// cores past the 64th, are left out. Returns the group the user clicked.
+pub(crate) fn affinity_menu(ui: &mut egui::Ui, data: &crate::SystemData) -> Option<crate::cpu_topology::CpuGroup> {
+    use crate::cpu_topology::CpuGroup;
+
+    let num_cores = data.cpu_cores.len().max(1);
+    let mut choices = vec![CpuGroup {
+        label: "All Cores".into(),
+        cpus: (0..num_cores).collect(),
+    }];
+    match &data.cpu_topology {
+        Some(topology) => choices.extend(topology.affinity_groups()),
+        None if num_cores > 1 => {
+            for core in 0..2 {
+                choices.push(CpuGroup {
+                    label: format!("Core {core} Only"),
+                    cpus: vec![core],
+                });
+            }
+            if num_cores >= 4 {
+                choices.push(CpuGroup {
+                    label: format!("First {} Cores", num_cores / 2),
+                    cpus: (0..num_cores / 2).collect(),
+                });
+            }
+        }
+        None => {}
+    }
+
+    let mut chosen = None;
+    for choice in choices.into_iter().filter(CpuGroup::fits_affinity_mask) {
+        if ui
+            .button(format!("{} ({:#x})", choice.label, choice.affinity_mask()))
+            .clicked()
+        {
+            ui.close_menu();
+            chosen = Some(choice);
+        }
+    }
+    chosen
+}
+
 /// Monospace-aligned details grid row.
 pub(crate) fn details_row(ui: &mut egui::Ui, label: &str, value: &str, is_dark: bool) {
     ui.label(
@@ -55,7 +55,10 @@ pub(crate) fn render_action_confirmation(app: &mut SystemMonitorApp, ctx: &egui:
         app.pending_action_plan = None;
     } else if confirm {
         app.pending_action_plan = None;
-        if matches!(plan.command, app::commands::ActionCommand::CleanRam) {
+        if matches!(
+            plan.command,
+            app::commands::ActionCommand::CleanRam | app::commands::ActionCommand::DropPageCache
+        ) {
             app.ram_cleaner_state.is_cleaning = true;
         }
         match app.app_channels.action_sender.send(plan.command) {
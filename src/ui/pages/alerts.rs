@@ -293,29 +293,52 @@ fn paint_proximity_matrix(app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data
         ui.add_space(8.0);
 
         // 2. Memory Exhaustion Metric
-        let mem_curr = data.memory_percentage;
-        let mem_thresh = app.settings.notification_memory_threshold;
-        let mem_headroom = (mem_thresh - mem_curr).max(0.0);
-        let mem_color = if mem_curr >= mem_thresh {
-            ThemePalette::STATUS_CRITICAL
-        } else if mem_curr >= mem_thresh * 0.85 {
-            ThemePalette::STATUS_WARNING
-        } else {
-            ThemePalette::STATUS_HEALTHY
+        let mem_row = match app.settings.memory_alert_basis {
+            MemoryAlertBasis::Used => {
+                let mem_curr = data.memory_percentage;
+                let mem_thresh = app.settings.notification_memory_threshold;
+                let mem_headroom = (mem_thresh - mem_curr).max(0.0);
+                let mem_color = if mem_curr >= mem_thresh {
+                    ThemePalette::STATUS_CRITICAL
+                } else if mem_curr >= mem_thresh * 0.85 {
+                    ThemePalette::STATUS_WARNING
+                } else {
+                    ThemePalette::STATUS_HEALTHY
+                };
+                ProximityRow {
+                    title: "Memory Exhaustion Limit",
+                    current_value: format!("{:.1}%", mem_curr),
+                    threshold_value: format!("> {:.0}%", mem_thresh),
+                    headroom: format!("+{:.1}% Headroom", mem_headroom),
+                    fraction: mem_curr / 100.0,
+                    color: mem_color,
+                }
+            }
+            MemoryAlertBasis::Available => {
+                let available = if data.memory_total > 0 {
+                    (data.memory_available as f64 / data.memory_total as f64 * 100.0) as f32
+                } else {
+                    100.0
+                };
+                let floor = app.settings.notification_memory_available_threshold;
+                let mem_color = if available <= floor {
+                    ThemePalette::STATUS_CRITICAL
+                } else if available <= floor * 1.5 {
+                    ThemePalette::STATUS_WARNING
+                } else {
+                    ThemePalette::STATUS_HEALTHY
+                };
+                ProximityRow {
+                    title: "Available Memory Floor",
+                    current_value: format!("{:.1}% free", available),
+                    threshold_value: format!("< {:.0}%", floor),
+                    headroom: format!("+{:.1}% Headroom", (available - floor).max(0.0)),
+                    fraction: 1.0 - available / 100.0,
+                    color: mem_color,
+                }
+            }
         };
-
-        paint_proximity_row(
-            ui,
-            ProximityRow {
-                title: "Memory Exhaustion Limit",
-                current_value: format!("{:.1}%", mem_curr),
-                threshold_value: format!("> {:.0}%", mem_thresh),
-                headroom: format!("+{:.1}% Headroom", mem_headroom),
-                fraction: mem_curr / 100.0,
-                color: mem_color,
-            },
-            is_dark,
-        );
+        paint_proximity_row(ui, mem_row, is_dark);
 
         ui.add_space(8.0);
         ui.separator();
@@ -1,7 +1,9 @@
+use crate::cpu_topology::{CoreType, CpuTopology, LogicalCpu};
 use crate::ui::components::*;
 use crate::ui::theme::ThemePalette;
 use crate::*;
 use eframe::egui;
+use egui_plot::{HLine, Line, LineStyle, Plot, PlotPoints};
 
 pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &SystemData) {
     let is_dark = ui.visuals().dark_mode;
@@ -101,11 +103,30 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
                     );
                     ui.end_row();
                 });
+
+            if let Some(topology) = &data.cpu_topology {
+                ui.add_space(6.0);
+                egui::Grid::new("cpu_topology_summary_grid")
+                    .num_columns(2)
+                    .spacing([24.0, 4.0])
+                    .show(ui, |ui| {
+                        details_row(ui, "Layout:", &layout_summary(topology), is_dark);
+                        if !topology.caches.is_empty() {
+                            details_row(ui, "Caches:", &cache_summary(topology), is_dark);
+                        }
+                    });
+            }
         });
 
+        // ── 2. Clock Speed History & Throttling ──
+        if data.cpu_freq_history.iter().any(|history| !history.is_empty()) {
+            ui.add_space(10.0);
+            paint_clock_history(ui, data, is_dark);
+        }
+
         ui.add_space(10.0);
 
-        // ── 2. Per-Core Topology Grid ──
+        // ── 3. Per-Core Topology Grid ──
         ui.horizontal(|ui| {
             ui.label(
                 egui::RichText::new("LOGICAL PROCESSOR TOPOLOGY & REAL-TIME LOAD")
@@ -125,59 +146,44 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
         });
         ui.add_space(6.0);
 
-        let avail_w = ui.available_width();
-        let cores_per_row = if avail_w >= 1100.0 {
-            6
-        } else if avail_w >= 750.0 {
-            4
-        } else {
-            2
-        };
-        let mut core_index = 0;
-
-        while core_index < data.cpu_cores.len() {
-            ui.columns(cores_per_row, |cols| {
-                for col in cols.iter_mut() {
-                    if core_index >= data.cpu_cores.len() {
-                        break;
+        match &data.cpu_topology {
+            Some(topology) => {
+                for group in topology.groups() {
+                    let cores: Vec<&CpuCoreInfo> = data
+                        .cpu_cores
+                        .iter()
+                        .filter(|core| group.cpus.contains(&core.core_id))
+                        .collect();
+                    if cores.is_empty() {
+                        continue;
                     }
-
-                    let core = &data.cpu_cores[core_index];
-                    let color = get_usage_color(core.usage);
-                    let frac = (core.usage / 100.0).clamp(0.0, 1.0);
-
-                    card_frame(is_dark)
-                        .inner_margin(egui::Margin::symmetric(10.0, 8.0))
-                        .show(col, |ui| {
-                            ui.horizontal(|ui| {
-                                let (dot, _) = ui.allocate_exact_size(egui::vec2(5.0, 5.0), egui::Sense::hover());
-                                ui.painter().circle_filled(dot.center(), 2.0, color);
-                                ui.label(
-                                    egui::RichText::new(format!("C{:02}", core.core_id))
-                                        .monospace()
-                                        .strong()
-                                        .size(11.0)
-                                        .color(ThemePalette::text_dimmed(is_dark)),
-                                );
-                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                    ui.label(
-                                        egui::RichText::new(format!("{:.1}%", core.usage))
-                                            .monospace()
-                                            .strong()
-                                            .size(12.5)
-                                            .color(color),
-                                    );
-                                });
-                            });
-
-                            ui.add_space(4.0);
-                            paint_progress_bar(ui, frac, color, 4.0, is_dark);
-                        });
-
-                    core_index += 1;
+                    let physical = group
+                        .cpus
+                        .iter()
+                        .filter_map(|id| topology.cpu(*id))
+                        .map(|cpu| (cpu.package, cpu.core))
+                        .collect::<std::collections::BTreeSet<_>>()
+                        .len();
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{}  ·  {} threads on {} cores",
+                            group.label,
+                            cores.len(),
+                            physical
+                        ))
+                        .size(11.0)
+                        .strong()
+                        .color(ThemePalette::text_secondary(is_dark)),
+                    );
+                    ui.add_space(4.0);
+                    paint_core_grid(ui, &cores, Some(topology), is_dark);
+                    ui.add_space(4.0);
                 }
-            });
-            ui.add_space(6.0);
+            }
+            None => {
+                let cores: Vec<&CpuCoreInfo> = data.cpu_cores.iter().collect();
+                paint_core_grid(ui, &cores, None, is_dark);
+            }
         }
 
         if data.cpu_cores.is_empty() {
@@ -190,3 +196,222 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
         }
     });
 }
+
+/// Per-core clock lines against the rated maximum, headed by the throttling verdict.
+fn paint_clock_history(ui: &mut egui::Ui, data: &SystemData, is_dark: bool) {
+    card_frame(is_dark).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("CLOCK SPEED HISTORY")
+                    .size(11.0)
+                    .strong()
+                    .color(ThemePalette::text_secondary(is_dark)),
+            );
+            if let Some(throttle) = &data.throttle {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let (text, color) = if throttle.sustained {
+                        ("THROTTLING", ThemePalette::STATUS_CRITICAL)
+                    } else if throttle.throttled_samples > 0 {
+                        ("INTERMITTENT THROTTLING", ThemePalette::STATUS_WARNING)
+                    } else {
+                        ("CLOCKS NOMINAL", ThemePalette::STATUS_HEALTHY)
+                    };
+                    ui.label(egui::RichText::new(text).size(10.5).strong().color(color))
+                        .on_hover_text(throttle.evidence());
+                    let clock = match throttle.clock_ratio() {
+                        Some(ratio) => format!("{:.0} MHz avg · {:.0}% of max", throttle.average_mhz, ratio * 100.0),
+                        None => format!("{:.0} MHz avg", throttle.average_mhz),
+                    };
+                    ui.label(
+                        egui::RichText::new(clock)
+                            .monospace()
+                            .strong()
+                            .color(ThemePalette::text_primary(is_dark)),
+                    );
+                });
+            }
+        });
+
+        ui.add_space(4.0);
+        let max_mhz = data.throttle.and_then(|throttle| throttle.max_mhz);
+        Plot::new("cpu_clock_plot")
+            .height(180.0)
+            .allow_zoom(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .include_y(0.0)
+            .include_y(max_mhz.unwrap_or_default())
+            .y_axis_label("MHz")
+            .show(ui, |plot_ui| {
+                for (id, history) in data.cpu_freq_history.iter().enumerate() {
+                    let points: PlotPoints = history.iter().map(|p| [p.time, p.value]).collect();
+                    plot_ui.line(Line::new(points).name(format!("CPU {id}")).width(1.0));
+                }
+                if let Some(max) = max_mhz {
+                    plot_ui.hline(
+                        HLine::new(max)
+                            .name("Rated maximum")
+                            .style(LineStyle::dashed_dense())
+                            .color(ThemePalette::text_dimmed(is_dark)),
+                    );
+                }
+            });
+    });
+}
+
+fn paint_core_grid(ui: &mut egui::Ui, cores: &[&CpuCoreInfo], topology: Option<&CpuTopology>, is_dark: bool) {
+    let avail_w = ui.available_width();
+    let cores_per_row = if avail_w >= 1100.0 {
+        6
+    } else if avail_w >= 750.0 {
+        4
+    } else {
+        2
+    };
+
+    for row in cores.chunks(cores_per_row) {
+        ui.columns(cores_per_row, |cols| {
+            for (col, core) in cols.iter_mut().zip(row) {
+                paint_core_tile(
+                    col,
+                    core,
+                    topology.and_then(|topology| topology.cpu(core.core_id)),
+                    is_dark,
+                );
+            }
+        });
+        ui.add_space(6.0);
+    }
+}
+
+fn paint_core_tile(ui: &mut egui::Ui, core: &CpuCoreInfo, cpu: Option<&LogicalCpu>, is_dark: bool) {
+    let color = get_usage_color(core.usage);
+    let frac = (core.usage / 100.0).clamp(0.0, 1.0);
+
+    card_frame(is_dark)
+        .inner_margin(egui::Margin::symmetric(10.0, 8.0))
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let (dot, _) = ui.allocate_exact_size(egui::vec2(5.0, 5.0), egui::Sense::hover());
+                ui.painter().circle_filled(dot.center(), 2.0, color);
+                ui.label(
+                    egui::RichText::new(format!("C{:02}", core.core_id))
+                        .monospace()
+                        .strong()
+                        .size(11.0)
+                        .color(ThemePalette::text_dimmed(is_dark)),
+                );
+                if let Some(badge) = cpu.and_then(|cpu| cpu.core_type.badge()) {
+                    ui.label(
+                        egui::RichText::new(badge)
+                            .monospace()
+                            .strong()
+                            .size(10.0)
+                            .color(ThemePalette::ACCENT_PRIMARY),
+                    );
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(
+                        egui::RichText::new(format!("{:.1}%", core.usage))
+                            .monospace()
+                            .strong()
+                            .size(12.5)
+                            .color(color),
+                    );
+                });
+            });
+
+            ui.add_space(4.0);
+            paint_progress_bar(ui, frac, color, 4.0, is_dark);
+
+            let max_mhz = cpu.and_then(|cpu| cpu.max_mhz);
+            let frequency = match (core.frequency_mhz, max_mhz) {
+                (0, None) => None,
+                (current, Some(max)) => Some(format!("{current} / {max} MHz")),
+                (current, None) => Some(format!("{current} MHz")),
+            };
+            let siblings = cpu.filter(|cpu| !cpu.siblings.is_empty()).map(|cpu| {
+                let ids: Vec<String> = cpu.siblings.iter().map(|id| format!("C{id:02}")).collect();
+                format!("SMT {}", ids.join(","))
+            });
+            if frequency.is_some() || siblings.is_some() {
+                ui.add_space(2.0);
+                ui.horizontal(|ui| {
+                    if let Some(frequency) = frequency {
+                        ui.label(
+                            egui::RichText::new(frequency)
+                                .monospace()
+                                .size(10.0)
+                                .color(ThemePalette::text_dimmed(is_dark)),
+                        );
+                    }
+                    if let Some(siblings) = siblings {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                egui::RichText::new(siblings)
+                                    .monospace()
+                                    .size(10.0)
+                                    .color(ThemePalette::text_dimmed(is_dark)),
+                            );
+                        });
+                    }
+                });
+            }
+        });
+}
+
+fn layout_summary(topology: &CpuTopology) -> String {
+    let plural = |count: usize, noun: &str| format!("{count} {noun}{}", if count == 1 { "" } else { "s" });
+    let mut parts = vec![plural(topology.packages().len(), "package")];
+    let nodes = topology.numa_nodes().len();
+    if nodes > 0 {
+        parts.push(plural(nodes, "NUMA node"));
+    }
+    if topology.is_hybrid() {
+        let count = |core_type: CoreType| {
+            topology
+                .cpus
+                .iter()
+                .filter(|cpu| cpu.core_type == core_type)
+                .map(|cpu| (cpu.package, cpu.core))
+                .collect::<std::collections::BTreeSet<_>>()
+                .len()
+        };
+        parts.push(format!(
+            "{} P-cores + {} E-cores",
+            count(CoreType::Performance),
+            count(CoreType::Efficient)
+        ));
+    } else {
+        parts.push(plural(topology.physical_cores(), "core"));
+    }
+    parts.push(if topology.has_smt() { "SMT on" } else { "no SMT" }.to_string());
+    parts.join("  ·  ")
+}
+
+/// One entry per cache level and type, e.g. `L2 2.0 MB ×8`.
+fn cache_summary(topology: &CpuTopology) -> String {
+    let mut levels: std::collections::BTreeMap<(u8, &str), (u64, usize)> = std::collections::BTreeMap::new();
+    for cache in &topology.caches {
+        let entry = levels.entry((cache.level, cache.kind.as_str())).or_default();
+        entry.0 = entry.0.max(cache.size_bytes);
+        entry.1 += 1;
+    }
+    levels
+        .into_iter()
+        .map(|((level, kind), (size, count))| {
+            let suffix = match kind {
+                "Data" => "d",
+                "Instruction" => "i",
+                _ => "",
+            };
+            let instances = if count > 1 {
+                format!(" ×{count}")
+            } else {
+                String::new()
+            };
+            format!("L{level}{suffix} {}{instances}", bytes_to_human(size))
+        })
+        .collect::<Vec<_>>()
+        .join("  ·  ")
+}
@@ -208,9 +208,7 @@ pub(crate) fn paint_network_interfaces(ui: &mut egui::Ui, data: &SystemData, is_
         card_frame(is_dark).show(ui, |ui| {
             // Header row
             ui.horizontal(|ui| {
-                let icon = if network.interface.to_lowercase().contains("wi-fi")
-                    || network.interface.to_lowercase().contains("wireless")
-                {
+                let icon = if network.details.kind == crate::network::InterfaceKind::Wifi {
                     "📶"
                 } else {
                     "🌐"
@@ -230,6 +228,19 @@ pub(crate) fn paint_network_interfaces(ui: &mut egui::Ui, data: &SystemData, is_
                 } else {
                     status_pill(ui, "IDLE", ThemePalette::text_dimmed(is_dark), is_dark);
                 }
+                match network.details.link_up() {
+                    Some(true) => status_pill(ui, "LINK UP", ThemePalette::STATUS_HEALTHY, is_dark),
+                    Some(false) => status_pill(ui, "LINK DOWN", ThemePalette::STATUS_CRITICAL, is_dark),
+                    None => {}
+                }
+                if network.details.kind != crate::network::InterfaceKind::Unknown {
+                    status_pill(
+                        ui,
+                        &network.details.kind.label().to_uppercase(),
+                        ThemePalette::text_secondary(is_dark),
+                        is_dark,
+                    );
+                }
 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     ui.label(
@@ -320,9 +331,103 @@ pub(crate) fn paint_network_interfaces(ui: &mut egui::Ui, data: &SystemData, is_
                             .color(ThemePalette::text_primary(is_dark)),
                     );
                     ui.end_row();
+
+                    let details = &network.details;
+                    let key = |ui: &mut egui::Ui, text: &str| {
+                        ui.label(
+                            egui::RichText::new(text)
+                                .size(11.5)
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                    };
+                    let value = |ui: &mut egui::Ui, text: String, color: egui::Color32| {
+                        ui.label(egui::RichText::new(text).monospace().color(color));
+                    };
+                    let primary = ThemePalette::text_primary(is_dark);
+                    let dimmed = ThemePalette::text_dimmed(is_dark);
+
+                    key(ui, "Link:");
+                    value(ui, link_summary(details), primary);
+                    key(ui, "MAC Address:");
+                    value(ui, details.mac.clone().unwrap_or_else(|| "—".to_string()), primary);
+                    ui.end_row();
+
+                    key(ui, "IPv4:");
+                    value(ui, address_list(&details.ipv4), primary);
+                    key(ui, "IPv6:");
+                    value(ui, address_list(&details.ipv6), primary);
+                    ui.end_row();
+
+                    key(ui, "Packets:");
+                    value(
+                        ui,
+                        format!("↓ {}   ↑ {}", details.counters.rx_packets, details.counters.tx_packets),
+                        primary,
+                    );
+                    key(ui, "Errors / Drops:");
+                    let fault_color = |rate: f64, total: u64| {
+                        if rate > 0.0 {
+                            ThemePalette::STATUS_WARNING
+                        } else if total > 0 {
+                            primary
+                        } else {
+                            dimmed
+                        }
+                    };
+                    ui.horizontal(|ui| {
+                        value(
+                            ui,
+                            format!("{} ({:.1}/s)", details.counters.errors(), network.error_rate),
+                            fault_color(network.error_rate, details.counters.errors()),
+                        );
+                        value(ui, "/".to_string(), dimmed);
+                        value(
+                            ui,
+                            format!("{} ({:.1}/s)", details.counters.dropped(), network.drop_rate),
+                            fault_color(network.drop_rate, details.counters.dropped()),
+                        );
+                    });
+                    ui.end_row();
                 });
         });
 
         ui.add_space(8.0);
     }
 }
+
+/// Operational state, speed, duplex and MTU, skipping whatever is unknown.
+fn link_summary(details: &crate::network::InterfaceDetails) -> String {
+    let mut parts = Vec::new();
+    if let Some(state) = &details.operstate {
+        parts.push(state.clone());
+    }
+    if details.carrier == Some(false) {
+        parts.push("no carrier".to_string());
+    }
+    if let Some(speed) = details.speed_mbps {
+        parts.push(if speed >= 1000 && speed % 1000 == 0 {
+            format!("{} Gb/s", speed / 1000)
+        } else {
+            format!("{speed} Mb/s")
+        });
+    }
+    if let Some(duplex) = &details.duplex {
+        parts.push(format!("{duplex} duplex"));
+    }
+    if let Some(mtu) = details.mtu {
+        parts.push(format!("MTU {mtu}"));
+    }
+    if parts.is_empty() {
+        "—".to_string()
+    } else {
+        parts.join(" · ")
+    }
+}
+
+fn address_list<T: std::fmt::Display>(addresses: &[T]) -> String {
+    if addresses.is_empty() {
+        "—".to_string()
+    } else {
+        addresses.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    }
+}
@@ -63,6 +63,24 @@ mod tests {
                     transmitted: 512 * 1024 * 1024,
                     received_rate: 1.5,
                     transmitted_rate: 0.8,
+                    details: crate::network::InterfaceDetails {
+                        kind: crate::network::InterfaceKind::Ethernet,
+                        operstate: Some("up".to_string()),
+                        carrier: Some(true),
+                        speed_mbps: Some(1000),
+                        duplex: Some("full".to_string()),
+                        mtu: Some(1500),
+                        mac: Some("52:54:00:12:34:56".to_string()),
+                        ipv4: vec![std::net::Ipv4Addr::new(192, 168, 1, 20)],
+                        ipv6: vec![std::net::Ipv6Addr::LOCALHOST],
+                        counters: crate::network::InterfaceCounters {
+                            rx_packets: 1200,
+                            rx_errors: 2,
+                            ..Default::default()
+                        },
+                    },
+                    error_rate: 0.5,
+                    drop_rate: 0.0,
                 },
                 crate::NetworkInfo {
                     interface: "Wi-Fi".to_string(),
@@ -70,6 +88,9 @@ mod tests {
                     transmitted: 10 * 1024 * 1024,
                     received_rate: 0.0,
                     transmitted_rate: 0.0,
+                    details: Default::default(),
+                    error_rate: 0.0,
+                    drop_rate: 0.0,
                 },
             ],
             network_download_history: VecDeque::from([
@@ -90,6 +111,10 @@ mod tests {
                 disk_read_bytes: 0,
                 disk_written_bytes: 0,
                 status: "Running".to_string(),
+                gpu_usage: 0.0,
+                gpu_memory: 0,
+                cgroup: None,
+                container: None,
             }],
             ..Default::default()
         };
@@ -118,8 +143,20 @@ mod tests {
     #[test]
     fn test_network_page_socket_filter() {
         let mut app = crate::SystemMonitorApp::test_app();
-        app.network_socket_search = "127.0.0.1".to_string();
-        let data = SystemData::default();
+        app.network_socket_search = "::1".to_string();
+        app.network_socket_family = Some(crate::network::AddressFamily::Ipv6);
+        let data = SystemData {
+            socket_connections: vec![crate::network::SocketConnection {
+                protocol: "TCP",
+                family: crate::network::AddressFamily::Ipv6,
+                local_addr: "[::1]:8080".parse().unwrap(),
+                remote_addr: None,
+                state: "LISTEN",
+                pid: 1234,
+                process_name: Some("server".into()),
+            }],
+            ..Default::default()
+        };
 
         let ctx = egui::Context::default();
         let _ = ctx.run(Default::default(), |ctx| {
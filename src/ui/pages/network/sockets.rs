@@ -1,3 +1,4 @@
+use crate::network::AddressFamily;
 use crate::ui::components::*;
 use crate::ui::theme::ThemePalette;
 use crate::SystemData;
@@ -11,7 +12,8 @@ pub(crate) fn paint_socket_connections(
     is_dark: bool,
 ) {
     let all_conns = &data.socket_connections;
-    let filtered_conns = crate::network::filter_connections(all_conns, &app.network_socket_search);
+    let filtered_conns =
+        crate::network::filter_connections(all_conns, &app.network_socket_search, app.network_socket_family);
 
     card_frame(is_dark).show(ui, |ui| {
         ui.horizontal(|ui| {
@@ -49,6 +51,32 @@ pub(crate) fn paint_socket_connections(
             if !app.network_socket_search.is_empty() && ui.small_button("×").clicked() {
                 app.network_socket_search.clear();
             }
+
+            ui.add_space(8.0);
+            ui.label(
+                egui::RichText::new("Family:")
+                    .size(11.5)
+                    .strong()
+                    .color(ThemePalette::text_secondary(is_dark)),
+            );
+            egui::ComboBox::from_id_source("network_socket_family_filter")
+                .selected_text(app.network_socket_family.map_or("All", AddressFamily::label))
+                .show_ui(ui, |ui: &mut egui::Ui| {
+                    if ui
+                        .selectable_label(app.network_socket_family.is_none(), "All")
+                        .clicked()
+                    {
+                        app.network_socket_family = None;
+                    }
+                    for family in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+                        if ui
+                            .selectable_label(app.network_socket_family == Some(family), family.label())
+                            .clicked()
+                        {
+                            app.network_socket_family = Some(family);
+                        }
+                    }
+                });
         });
 
         ui.add_space(8.0);
@@ -79,6 +107,11 @@ pub(crate) fn paint_socket_connections(
                                     .strong()
                                     .color(ThemePalette::text_secondary(is_dark)),
                             );
+                            ui.label(
+                                egui::RichText::new("Family")
+                                    .strong()
+                                    .color(ThemePalette::text_secondary(is_dark)),
+                            );
                             ui.label(
                                 egui::RichText::new("Local Address")
                                     .strong()
@@ -119,12 +152,17 @@ pub(crate) fn paint_socket_connections(
                                         .color(proto_color),
                                 );
                                 ui.label(
-                                    egui::RichText::new(&conn.local_addr)
+                                    egui::RichText::new(conn.family.label())
+                                        .monospace()
+                                        .color(ThemePalette::text_secondary(is_dark)),
+                                );
+                                ui.label(
+                                    egui::RichText::new(conn.local_display())
                                         .monospace()
                                         .color(ThemePalette::text_primary(is_dark)),
                                 );
                                 ui.label(
-                                    egui::RichText::new(&conn.remote_addr)
+                                    egui::RichText::new(conn.remote_display())
                                         .monospace()
                                         .color(ThemePalette::text_primary(is_dark)),
                                 );
@@ -143,11 +143,13 @@ mod tests {
                 core_id: 0,
                 usage: 30.0,
                 name: "Core 0".to_string(),
+                frequency_mhz: 0,
             },
             crate::CpuCoreInfo {
                 core_id: 1,
                 usage: 21.0,
                 name: "Core 1".to_string(),
+                frequency_mhz: 0,
             },
         ];
         data.gpu_info = vec![crate::GpuInfo {
@@ -167,6 +169,7 @@ mod tests {
             available_space: 400_000_000_000,
             usage_percentage: 60.0,
             file_system: "NTFS".to_string(),
+            block_device: None,
         }];
         data.top_processes = vec![crate::processes::ProcessInfo {
             parent_pid: None,
@@ -178,6 +181,10 @@ mod tests {
             disk_read_bytes: 1000,
             disk_written_bytes: 2000,
             status: "Running".to_string(),
+            gpu_usage: 0.0,
+            gpu_memory: 0,
+            cgroup: None,
+            container: None,
         }];
 
         let _ = ctx.run(Default::default(), |ctx| {
@@ -0,0 +1,70 @@
+use crate::monitoring::snapshot::LoadSnapshot;
+use crate::ui::components::*;
+use crate::ui::theme::ThemePalette;
+use crate::SystemData;
+use eframe::egui;
+
+/// Renders run-queue length and scheduler churn from `/proc/loadavg` and `/proc/stat`.
+pub(crate) fn paint_load_summary(ui: &mut egui::Ui, data: &SystemData, is_dark: bool) {
+    let Some(load) = data.load else {
+        return;
+    };
+    let cores = data.system_info.cpu_count.max(1) as f64;
+
+    card_frame(is_dark).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("SYSTEM LOAD & SCHEDULER")
+                    .size(11.0)
+                    .strong()
+                    .color(ThemePalette::text_secondary(is_dark)),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                // A load above the core count means tasks are queueing for a CPU.
+                let per_core = load.load_1m / cores;
+                let color = if per_core >= 2.0 {
+                    ThemePalette::STATUS_CRITICAL
+                } else if per_core >= 1.0 {
+                    ThemePalette::STATUS_WARNING
+                } else {
+                    ThemePalette::STATUS_HEALTHY
+                };
+                status_pill(ui, &format!("{per_core:.2} per core"), color, is_dark);
+            });
+        });
+        ui.add_space(6.0);
+
+        ui.columns(2, |cols| {
+            egui::Grid::new("load_summary_queue")
+                .num_columns(2)
+                .spacing([24.0, 4.0])
+                .show(&mut cols[0], |ui| paint_queue_rows(ui, &load, is_dark));
+            egui::Grid::new("load_summary_rates")
+                .num_columns(2)
+                .spacing([24.0, 4.0])
+                .show(&mut cols[1], |ui| paint_rate_rows(ui, &load, is_dark));
+        });
+    });
+}
+
+fn paint_queue_rows(ui: &mut egui::Ui, load: &LoadSnapshot, is_dark: bool) {
+    details_row(
+        ui,
+        "Load (1 / 5 / 15 min):",
+        &format!("{:.2} / {:.2} / {:.2}", load.load_1m, load.load_5m, load.load_15m),
+        is_dark,
+    );
+    details_row(ui, "Running Tasks:", &load.procs_running.to_string(), is_dark);
+    details_row(ui, "Blocked on I/O:", &load.procs_blocked.to_string(), is_dark);
+}
+
+fn paint_rate_rows(ui: &mut egui::Ui, load: &LoadSnapshot, is_dark: bool) {
+    for (label, value) in [
+        ("Context Switches:", load.context_switches_per_sec),
+        ("Interrupts:", load.interrupts_per_sec),
+        ("Softirqs:", load.softirqs_per_sec),
+        ("Forks:", load.forks_per_sec),
+    ] {
+        details_row(ui, label, &format!("{value:.0} /s"), is_dark);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::monitoring::snapshot::MemoryBreakdownSnapshot;
+use crate::ui::components::*;
+use crate::ui::theme::ThemePalette;
+use crate::SystemData;
+use eframe::egui;
+
+/// Fill colour for each `MemoryBreakdownSnapshot::composition` segment, in order.
+fn segment_color(index: usize, is_dark: bool) -> egui::Color32 {
+    match index {
+        0 => ThemePalette::STATUS_HEALTHY,
+        1 => ThemePalette::ACCENT_ACTIVE,
+        2 => ThemePalette::STATUS_CRITICAL,
+        3 => ThemePalette::STATUS_WARNING,
+        4 => ThemePalette::TEXT_SECONDARY,
+        5 => ThemePalette::TEXT_LABEL,
+        6 => ThemePalette::TEXT_TERTIARY,
+        _ => ThemePalette::bg_track(is_dark),
+    }
+}
+
+/// Renders the stacked memory composition bar with its legend and paging activity.
+pub(crate) fn paint_memory_composition(ui: &mut egui::Ui, data: &SystemData, is_dark: bool) {
+    let Some(breakdown) = data.memory_breakdown else {
+        return;
+    };
+    let parts = breakdown.composition(data.memory_total);
+
+    card_frame(is_dark).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("MEMORY COMPOSITION")
+                    .size(11.0)
+                    .strong()
+                    .color(ThemePalette::text_secondary(is_dark)),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} available of {}",
+                        bytes_to_human(data.memory_available),
+                        bytes_to_human(data.memory_total)
+                    ))
+                    .monospace()
+                    .strong()
+                    .color(ThemePalette::text_primary(is_dark)),
+                );
+            });
+        });
+        ui.add_space(6.0);
+
+        paint_stacked_bar(ui, &parts, data.memory_total, is_dark);
+        ui.add_space(8.0);
+
+        egui::Grid::new("memory_composition_legend")
+            .num_columns(4)
+            .spacing([24.0, 6.0])
+            .show(ui, |ui| {
+                for (index, (label, bytes)) in parts.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        let (swatch, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                        ui.painter().rect_filled(swatch, 2.0, segment_color(index, is_dark));
+                        ui.label(
+                            egui::RichText::new(*label)
+                                .size(11.5)
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                    });
+                    ui.label(
+                        egui::RichText::new(bytes_to_human(*bytes))
+                            .monospace()
+                            .size(11.5)
+                            .color(ThemePalette::text_primary(is_dark)),
+                    );
+                    if index % 2 == 1 {
+                        ui.end_row();
+                    }
+                }
+            });
+
+        ui.add_space(8.0);
+        ui.separator();
+        ui.add_space(6.0);
+        paint_paging_details(ui, &breakdown, is_dark);
+    });
+}
+
+fn paint_stacked_bar(ui: &mut egui::Ui, parts: &[(&str, u64)], total: u64, is_dark: bool) {
+    let width = ui.available_width();
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, 18.0), egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 3.0, ThemePalette::bg_deepest(is_dark));
+    if total == 0 {
+        return;
+    }
+
+    let mut x = rect.left();
+    let mut hovered = None;
+    for (index, (label, bytes)) in parts.iter().enumerate() {
+        let segment_width = width * (*bytes as f64 / total as f64) as f32;
+        if segment_width <= 0.0 {
+            continue;
+        }
+        let segment = egui::Rect::from_min_max(
+            egui::pos2(x, rect.top()),
+            egui::pos2((x + segment_width).min(rect.right()), rect.bottom()),
+        );
+        painter.rect_filled(segment, 0.0, segment_color(index, is_dark));
+        if response.hover_pos().is_some_and(|pos| segment.contains(pos)) {
+            hovered = Some((*label, *bytes));
+        }
+        x = segment.right();
+    }
+    painter.rect_stroke(rect, 3.0, egui::Stroke::new(1.0, ThemePalette::bg_track(is_dark)));
+
+    if let Some((label, bytes)) = hovered {
+        response.on_hover_text(format!(
+            "{label}: {} ({:.1}%)",
+            bytes_to_human(bytes),
+            bytes as f64 / total as f64 * 100.0
+        ));
+    }
+}
+
+fn paint_paging_details(ui: &mut egui::Ui, breakdown: &MemoryBreakdownSnapshot, is_dark: bool) {
+    egui::Grid::new("memory_paging_details")
+        .num_columns(2)
+        .spacing([24.0, 4.0])
+        .show(ui, |ui| {
+            let commit = if breakdown.commit_limit > 0 {
+                format!(
+                    "{} of {} limit ({:.0}%)",
+                    bytes_to_human(breakdown.committed),
+                    bytes_to_human(breakdown.commit_limit),
+                    breakdown.committed as f64 / breakdown.commit_limit as f64 * 100.0
+                )
+            } else {
+                bytes_to_human(breakdown.committed)
+            };
+            details_row(ui, "Commit Charge:", &commit, is_dark);
+            details_row(
+                ui,
+                "Dirty / Writeback:",
+                &format!(
+                    "{} / {}",
+                    bytes_to_human(breakdown.dirty),
+                    bytes_to_human(breakdown.writeback)
+                ),
+                is_dark,
+            );
+            if breakdown.hugepages_total > 0 {
+                details_row(
+                    ui,
+                    "Huge Pages:",
+                    &format!(
+                        "{} free of {}",
+                        bytes_to_human(breakdown.hugepages_free),
+                        bytes_to_human(breakdown.hugepages_total)
+                    ),
+                    is_dark,
+                );
+            }
+            details_row(
+                ui,
+                "Swap In / Out:",
+                &format!(
+                    "{:.0} / {:.0} pages/s",
+                    breakdown.swap_in_per_sec, breakdown.swap_out_per_sec
+                ),
+                is_dark,
+            );
+            details_row(
+                ui,
+                "Major Faults:",
+                &format!("{:.0} /s", breakdown.major_faults_per_sec),
+                is_dark,
+            );
+        });
+}
@@ -1,3 +1,5 @@
+pub(crate) mod load_summary;
+pub(crate) mod memory_composition;
 pub(crate) mod plots;
 pub(crate) mod rate_summary;
 
@@ -16,6 +18,16 @@ pub(crate) fn show(app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Syst
         rate_summary::paint_history_summary(ui, data, is_dark);
         ui.add_space(10.0);
 
+        if data.load.is_some() {
+            load_summary::paint_load_summary(ui, data, is_dark);
+            ui.add_space(10.0);
+        }
+
+        if data.memory_breakdown.is_some() {
+            memory_composition::paint_memory_composition(ui, data, is_dark);
+            ui.add_space(10.0);
+        }
+
         // ── 2. Time-Series Performance Plots ──
         if app.settings.show_graphs {
             plots::paint_performance_plots(ui, data, is_dark);
@@ -123,7 +135,28 @@ mod tests {
 
     #[test]
     fn test_subcomponents_direct() {
-        let data = SystemData::default();
+        let data = SystemData {
+            memory_total: 16 << 30,
+            memory_available: 6 << 30,
+            memory_breakdown: Some(crate::monitoring::snapshot::MemoryBreakdownSnapshot {
+                free: 2 << 30,
+                cached: 5 << 30,
+                commit_limit: 20 << 30,
+                committed: 12 << 30,
+                hugepages_total: 1 << 30,
+                ..Default::default()
+            }),
+            load: Some(crate::monitoring::snapshot::LoadSnapshot {
+                load_1m: 6.5,
+                load_5m: 4.0,
+                load_15m: 2.0,
+                procs_running: 7,
+                procs_blocked: 1,
+                context_switches_per_sec: 25_000.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
         let ctx = egui::Context::default();
         let _ = ctx.run(Default::default(), |ctx| {
             egui::CentralPanel::default().show(ctx, |ui| {
@@ -131,6 +164,10 @@ mod tests {
                 rate_summary::paint_history_summary(ui, &data, false);
                 plots::paint_performance_plots(ui, &data, true);
                 plots::paint_performance_plots(ui, &data, false);
+                memory_composition::paint_memory_composition(ui, &data, true);
+                memory_composition::paint_memory_composition(ui, &data, false);
+                load_summary::paint_load_summary(ui, &data, true);
+                load_summary::paint_load_summary(ui, &data, false);
             });
         });
     }
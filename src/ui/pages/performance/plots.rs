@@ -277,4 +277,53 @@ pub(crate) fn paint_performance_plots(ui: &mut egui::Ui, data: &SystemData, is_d
                 });
         });
     });
+
+    // ── Row 4: Per-device disk utilisation (Linux /proc/diskstats) ──
+    if !data.disk_util_history.is_empty() {
+        ui.add_space(10.0);
+        card_frame(is_dark).show(ui, |ui| {
+            let busiest = data
+                .block_devices
+                .iter()
+                .max_by(|a, b| a.util_percent.total_cmp(&b.util_percent));
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new("DISK UTILISATION HISTORY")
+                        .size(11.0)
+                        .strong()
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                if let Some(device) = busiest {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "{} {:.0}% busy · {:.1} ms await",
+                                device.name, device.util_percent, device.await_ms
+                            ))
+                            .monospace()
+                            .strong()
+                            .color(ThemePalette::text_primary(is_dark)),
+                        );
+                    });
+                }
+            });
+
+            ui.add_space(4.0);
+            Plot::new("disk_util_plot")
+                .height(180.0)
+                .allow_zoom(false)
+                .allow_drag(false)
+                .allow_scroll(false)
+                .include_y(0.0)
+                .include_y(100.0)
+                .legend(egui_plot::Legend::default())
+                .y_axis_label("Busy %")
+                .show(ui, |plot_ui| {
+                    for (name, history) in &data.disk_util_history {
+                        let points: PlotPoints = history.iter().map(|p| [p.time, p.value]).collect();
+                        plot_ui.line(Line::new(points).name(name).width(1.5));
+                    }
+                });
+        });
+    }
 }
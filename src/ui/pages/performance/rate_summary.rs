@@ -61,6 +61,16 @@ pub(crate) fn paint_history_summary(ui: &mut egui::Ui, data: &SystemData, is_dar
                 if let Some(key) = gpu_key {
                     show_metric_stats(ui, data, "GPU Util", key, "%", 1.0, is_dark);
                 }
+                show_metric_stats(ui, data, "Load (1 min)", "load.avg_1m", "", 1.0, is_dark);
+                show_metric_stats(
+                    ui,
+                    data,
+                    "Context Switches",
+                    "load.context_switches_per_sec",
+                    " /s",
+                    1.0,
+                    is_dark,
+                );
             });
     });
 }
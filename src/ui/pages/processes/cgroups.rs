@@ -0,0 +1,204 @@
+use crate::processes::{CgroupRow, ProcessSortColumn};
+use crate::ui::components::*;
+use crate::ui::format::bytes_to_human;
+use crate::ui::theme::ThemePalette;
+use eframe::egui;
+
+pub(super) fn paint_cgroup_table(
+    app: &mut crate::SystemMonitorApp,
+    ui: &mut egui::Ui,
+    rows: &[CgroupRow],
+    is_dark: bool,
+) {
+    card_frame(is_dark).show(ui, |ui| {
+        if rows.is_empty() {
+            ui.label(
+                egui::RichText::new("No cgroup v2 accounting is available for the listed processes.")
+                    .color(ThemePalette::text_dimmed(is_dark)),
+            );
+            return;
+        }
+
+        let total_w = ui.available_width().max(720.0);
+        let spacing = 8.0;
+        let procs_w = 50.0;
+        let cpu_w = 105.0;
+        let mem_w = 150.0;
+        let limit_w = 110.0;
+        let io_w = 85.0;
+        let pids_w = 80.0;
+        let fixed_w = procs_w + cpu_w + mem_w + limit_w + io_w * 2.0 + pids_w + 7.0 * spacing;
+        let name_w = (total_w - fixed_w).max(200.0);
+
+        let sort_col = app.process_sort_column;
+        let sort_asc = app.process_sort_ascending;
+        let mut clicked_column = None;
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = spacing;
+            let mut header = |ui: &mut egui::Ui, label: &str, width: f32, column: Option<ProcessSortColumn>| {
+                let text = match column {
+                    Some(column) => super::sort_header_label(label, column, sort_col, sort_asc),
+                    None => label.to_string(),
+                };
+                let color = if column == Some(sort_col) {
+                    ThemePalette::ACCENT_PRIMARY
+                } else {
+                    ThemePalette::text_primary(is_dark)
+                };
+                let button = egui::Button::new(egui::RichText::new(text).strong().size(11.5).color(color))
+                    .fill(egui::Color32::TRANSPARENT)
+                    .stroke(egui::Stroke::NONE);
+                if ui.add_sized([width, 22.0], button).clicked() && column.is_some() {
+                    clicked_column = column;
+                }
+            };
+            header(ui, "Cgroup", name_w, Some(ProcessSortColumn::Name));
+            header(ui, "Procs", procs_w, None);
+            header(ui, "CPU %", cpu_w, Some(ProcessSortColumn::Cpu));
+            header(ui, "Memory", mem_w, Some(ProcessSortColumn::Memory));
+            header(ui, "Limit use", limit_w, None);
+            header(ui, "Read", io_w, Some(ProcessSortColumn::Disk));
+            header(ui, "Write", io_w, Some(ProcessSortColumn::Disk));
+            header(ui, "PIDs", pids_w, None);
+        });
+        if let Some(column) = clicked_column {
+            if app.process_sort_column == column {
+                app.process_sort_ascending = !app.process_sort_ascending;
+            } else {
+                app.process_sort_column = column;
+                app.process_sort_ascending = column == ProcessSortColumn::Name;
+            }
+        }
+
+        ui.add_space(4.0);
+        ui.separator();
+        ui.add_space(4.0);
+
+        let row_height = 26.0;
+        egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            for row in rows {
+                let group = row.cgroup;
+                let expanded = app.expanded_cgroups.contains(&group.path);
+                let value = |text: String, color: egui::Color32| {
+                    egui::Label::new(egui::RichText::new(text).monospace().size(11.5).color(color))
+                };
+                let primary = ThemePalette::text_primary(is_dark);
+                let dimmed = ThemePalette::text_dimmed(is_dark);
+
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = spacing;
+
+                    let marker = if row.processes.is_empty() {
+                        "  "
+                    } else if expanded {
+                        "▾ "
+                    } else {
+                        "▸ "
+                    };
+                    let indent = "   ".repeat(row.depth);
+                    let name = egui::Button::new(
+                        egui::RichText::new(format!("{indent}{marker}{}", group.name()))
+                            .monospace()
+                            .size(11.5)
+                            .color(primary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .stroke(egui::Stroke::NONE);
+                    let response = ui.add_sized([name_w, row_height], name).on_hover_text(format!(
+                        "{}\n{} process(es) in this group, {} including subgroups",
+                        group.path,
+                        row.processes.len(),
+                        row.subtree_processes
+                    ));
+                    if response.clicked() && !row.processes.is_empty() {
+                        if expanded {
+                            app.expanded_cgroups.remove(&group.path);
+                        } else {
+                            app.expanded_cgroups.insert(group.path.clone());
+                        }
+                    }
+
+                    ui.add_sized([procs_w, row_height], value(row.subtree_processes.to_string(), primary));
+
+                    let cpu_text = match group.cpu_limit {
+                        Some(limit) => format!("{:.1}% / {limit:.0}%", group.cpu_usage),
+                        None => format!("{:.1}%", group.cpu_usage),
+                    };
+                    ui.add_sized([cpu_w, row_height], value(cpu_text, primary))
+                        .on_hover_text("Share of all logical CPUs, against the cpu.max quota when one is set");
+
+                    let memory_text = match group.memory_max {
+                        Some(limit) => format!("{} / {}", bytes_to_human(group.memory_current), bytes_to_human(limit)),
+                        None => bytes_to_human(group.memory_current),
+                    };
+                    ui.add_sized([mem_w, row_height], value(memory_text, primary));
+
+                    ui.allocate_ui_with_layout(
+                        egui::vec2(limit_w, row_height),
+                        egui::Layout::left_to_right(egui::Align::Center),
+                        |ui| match group.limit_utilization() {
+                            Some(percent) => {
+                                let color = if percent >= 90.0 {
+                                    ThemePalette::STATUS_CRITICAL
+                                } else if percent >= 70.0 {
+                                    ThemePalette::STATUS_WARNING
+                                } else {
+                                    ThemePalette::STATUS_HEALTHY
+                                };
+                                ui.set_width(limit_w);
+                                ui.vertical(|ui| {
+                                    ui.label(
+                                        egui::RichText::new(format!("{percent:.0}%"))
+                                            .monospace()
+                                            .size(10.5)
+                                            .color(color),
+                                    );
+                                    paint_progress_bar(ui, (percent / 100.0).min(1.0), color, 4.0, is_dark);
+                                });
+                            }
+                            None => {
+                                ui.label(egui::RichText::new("no limit").size(10.5).color(dimmed));
+                            }
+                        },
+                    );
+
+                    for rate in [group.io_read_rate, group.io_write_rate] {
+                        let (text, color) = if rate > 0.0 {
+                            (format_rate(rate / 1_048_576.0), primary)
+                        } else {
+                            ("—".to_string(), dimmed)
+                        };
+                        ui.add_sized([io_w, row_height], value(text, color));
+                    }
+
+                    let pids_text = match group.pids_max {
+                        Some(limit) => format!("{}/{limit}", group.pids_current),
+                        None => group.pids_current.to_string(),
+                    };
+                    ui.add_sized([pids_w, row_height], value(pids_text, primary));
+                });
+
+                if expanded {
+                    for process in &row.processes {
+                        ui.horizontal(|ui| {
+                            ui.add_space(24.0 + 18.0 * row.depth as f32);
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "{:>7}  {:<28} {:>6.1}%  {}",
+                                    process.pid,
+                                    process.name,
+                                    process.cpu_usage,
+                                    bytes_to_human(process.memory)
+                                ))
+                                .monospace()
+                                .size(11.0)
+                                .color(ThemePalette::text_secondary(is_dark)),
+                            );
+                        });
+                    }
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,221 @@
+use crate::containers::{ContainerControlAction, ContainerInfo};
+use crate::processes::ProcessInfo;
+use crate::ui::components::*;
+use crate::ui::format::bytes_to_human;
+use crate::ui::theme::ThemePalette;
+use eframe::egui;
+
+pub(super) fn paint_container_table(
+    app: &mut crate::SystemMonitorApp,
+    ui: &mut egui::Ui,
+    containers: &[ContainerInfo],
+    processes: &[&ProcessInfo],
+    is_dark: bool,
+) {
+    card_frame(is_dark).show(ui, |ui| {
+        let search = app.process_search.to_lowercase();
+        let visible: Vec<&ContainerInfo> = containers
+            .iter()
+            .filter(|container| {
+                search.is_empty()
+                    || container.name.to_lowercase().contains(&search)
+                    || container.image.to_lowercase().contains(&search)
+                    || container.id.starts_with(&search)
+            })
+            .collect();
+        if visible.is_empty() {
+            ui.label(
+                egui::RichText::new("No containers match the current filter.")
+                    .color(ThemePalette::text_dimmed(is_dark)),
+            );
+            return;
+        }
+
+        let total_w = ui.available_width().max(760.0);
+        let spacing = 8.0;
+        let image_w = 150.0;
+        let state_w = 120.0;
+        let cpu_w = 60.0;
+        let mem_w = 150.0;
+        let rate_w = 150.0;
+        let actions_w = 120.0;
+        let fixed_w = image_w + state_w + cpu_w + mem_w + rate_w * 2.0 + actions_w + 7.0 * spacing;
+        let name_w = (total_w - fixed_w).max(160.0);
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = spacing;
+            for (label, width) in [
+                ("Container", name_w),
+                ("Image", image_w),
+                ("State", state_w),
+                ("CPU %", cpu_w),
+                ("Memory", mem_w),
+                ("Network ↓ / ↑", rate_w),
+                ("Disk R / W", rate_w),
+                ("Actions", actions_w),
+            ] {
+                ui.add_sized(
+                    [width, 22.0],
+                    egui::Label::new(
+                        egui::RichText::new(label)
+                            .strong()
+                            .size(11.5)
+                            .color(ThemePalette::text_primary(is_dark)),
+                    ),
+                );
+            }
+        });
+
+        ui.add_space(4.0);
+        ui.separator();
+        ui.add_space(4.0);
+
+        let row_height = 26.0;
+        let mut requested = None;
+        egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            for container in visible {
+                let members: Vec<&&ProcessInfo> = processes
+                    .iter()
+                    .filter(|process| process.container.as_deref() == Some(container.name.as_str()))
+                    .collect();
+                let expanded = app.expanded_containers.contains(&container.id);
+                let primary = ThemePalette::text_primary(is_dark);
+                let dimmed = ThemePalette::text_dimmed(is_dark);
+                let value = |text: String, color: egui::Color32| {
+                    egui::Label::new(egui::RichText::new(text).monospace().size(11.5).color(color))
+                };
+
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = spacing;
+
+                    let marker = if members.is_empty() {
+                        "  "
+                    } else if expanded {
+                        "▾ "
+                    } else {
+                        "▸ "
+                    };
+                    let name = egui::Button::new(
+                        egui::RichText::new(format!("{marker}{}", container.name))
+                            .monospace()
+                            .size(11.5)
+                            .color(primary),
+                    )
+                    .fill(egui::Color32::TRANSPARENT)
+                    .stroke(egui::Stroke::NONE);
+                    let pid = container
+                        .pid
+                        .map_or_else(|| "not running".to_string(), |pid| format!("init PID {pid}"));
+                    let response = ui.add_sized([name_w, row_height], name).on_hover_text(format!(
+                        "{}\n{pid}\n{} listed process(es)",
+                        container.short_id(),
+                        members.len()
+                    ));
+                    if response.clicked() && !members.is_empty() {
+                        if expanded {
+                            app.expanded_containers.remove(&container.id);
+                        } else {
+                            app.expanded_containers.insert(container.id.clone());
+                        }
+                    }
+
+                    ui.add_sized([image_w, row_height], value(container.image.clone(), primary))
+                        .on_hover_text(&container.image);
+
+                    let state_color = match container.state.as_str() {
+                        "running" => ThemePalette::STATUS_HEALTHY,
+                        "paused" | "restarting" => ThemePalette::STATUS_WARNING,
+                        "dead" => ThemePalette::STATUS_CRITICAL,
+                        _ => dimmed,
+                    };
+                    ui.add_sized([state_w, row_height], value(container.state.clone(), state_color))
+                        .on_hover_text(&container.status);
+
+                    if container.is_running() {
+                        ui.add_sized(
+                            [cpu_w, row_height],
+                            value(format!("{:.1}", container.cpu_usage), primary),
+                        )
+                        .on_hover_text("Share of all logical CPUs");
+                        let memory_text = match container.memory_limit {
+                            Some(limit) => {
+                                format!("{} / {}", bytes_to_human(container.memory_usage), bytes_to_human(limit))
+                            }
+                            None => bytes_to_human(container.memory_usage),
+                        };
+                        ui.add_sized([mem_w, row_height], value(memory_text, primary));
+                        for (first, second) in [
+                            (container.net_rx_rate, container.net_tx_rate),
+                            (container.block_read_rate, container.block_write_rate),
+                        ] {
+                            let text = format!(
+                                "{} / {}",
+                                format_rate(first / 1_048_576.0),
+                                format_rate(second / 1_048_576.0)
+                            );
+                            ui.add_sized([rate_w, row_height], value(text, primary));
+                        }
+                    } else {
+                        for width in [cpu_w, mem_w, rate_w, rate_w] {
+                            ui.add_sized([width, row_height], value("—".into(), dimmed));
+                        }
+                    }
+
+                    ui.allocate_ui_with_layout(
+                        egui::vec2(actions_w, row_height),
+                        egui::Layout::left_to_right(egui::Align::Center),
+                        |ui| {
+                            if container.is_running() {
+                                if ui.small_button("Stop").on_hover_text("Stop this container").clicked() {
+                                    requested = Some((container, ContainerControlAction::Stop));
+                                }
+                                if ui
+                                    .small_button("Restart")
+                                    .on_hover_text("Stop and start this container")
+                                    .clicked()
+                                {
+                                    requested = Some((container, ContainerControlAction::Restart));
+                                }
+                            } else if ui.small_button("Start").on_hover_text("Start this container").clicked() {
+                                requested = Some((container, ContainerControlAction::Start));
+                            }
+                        },
+                    );
+                });
+
+                if expanded {
+                    for process in &members {
+                        ui.horizontal(|ui| {
+                            ui.add_space(24.0);
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "{:>7}  {:<28} {:>6.1}%  {}",
+                                    process.pid,
+                                    process.name,
+                                    process.cpu_usage,
+                                    bytes_to_human(process.memory)
+                                ))
+                                .monospace()
+                                .size(11.0)
+                                .color(ThemePalette::text_secondary(is_dark)),
+                            );
+                        });
+                    }
+                }
+            }
+        });
+
+        if let Some((container, action)) = requested {
+            match crate::containers::resolve_socket(&app.settings.container_socket) {
+                Some(socket) => app.queue_action(crate::app::commands::ActionCommand::ControlContainer {
+                    socket,
+                    id: container.id.clone(),
+                    name: container.name.clone(),
+                    action,
+                }),
+                None => app.action_status = Some("No container engine socket is configured or detected.".into()),
+            }
+        }
+    });
+}
@@ -1,3 +1,5 @@
+mod cgroups;
+mod containers;
 mod table;
 mod toolbar;
 
@@ -30,12 +32,26 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
     processes::sort_processes_refs(&mut filtered_processes, app.process_sort_column, ascending);
 
     // ── Integrated Toolbar Container ──
-    toolbar::paint_process_toolbar(app, ui, filtered_processes.len(), data.top_processes.len(), is_dark);
+    toolbar::paint_process_toolbar(
+        app,
+        ui,
+        filtered_processes.len(),
+        data.top_processes.len(),
+        data.containers.len(),
+        is_dark,
+    );
 
     ui.add_space(8.0);
 
     // ── Responsive Process Table ──
-    table::paint_process_table(app, ui, &filtered_processes, data, is_dark);
+    if app.process_group_by_container && !data.containers.is_empty() {
+        containers::paint_container_table(app, ui, &data.containers, &filtered_processes, is_dark);
+    } else if app.process_group_by_cgroup && cfg!(target_os = "linux") {
+        let rows = processes::build_cgroup_rows(&data.cgroups, &filtered_processes, app.process_sort_column, ascending);
+        cgroups::paint_cgroup_table(app, ui, &rows, is_dark);
+    } else {
+        table::paint_process_table(app, ui, &filtered_processes, data, is_dark);
+    }
 }
 
 #[cfg(test)]
@@ -87,6 +103,10 @@ mod tests {
                 disk_read_bytes: 5_000_000,
                 disk_written_bytes: 2_500_000,
                 status: "Running".to_string(),
+                gpu_usage: 0.0,
+                gpu_memory: 0,
+                cgroup: None,
+                container: None,
             },
             crate::processes::ProcessInfo {
                 parent_pid: None,
@@ -98,6 +118,10 @@ mod tests {
                 disk_read_bytes: 1_000_000,
                 disk_written_bytes: 500_000,
                 status: "Running".to_string(),
+                gpu_usage: 0.0,
+                gpu_memory: 0,
+                cgroup: None,
+                container: None,
             },
             crate::processes::ProcessInfo {
                 parent_pid: None,
@@ -109,6 +133,10 @@ mod tests {
                 disk_read_bytes: 100_000,
                 disk_written_bytes: 50_000,
                 status: "Running".to_string(),
+                gpu_usage: 0.0,
+                gpu_memory: 0,
+                cgroup: None,
+                container: None,
             },
         ];
 
@@ -158,21 +186,25 @@ mod tests {
                     core_id: 0,
                     usage: 10.0,
                     name: "Core 0".to_string(),
+                    frequency_mhz: 0,
                 },
                 crate::CpuCoreInfo {
                     core_id: 1,
                     usage: 20.0,
                     name: "Core 1".to_string(),
+                    frequency_mhz: 0,
                 },
                 crate::CpuCoreInfo {
                     core_id: 2,
                     usage: 30.0,
                     name: "Core 2".to_string(),
+                    frequency_mhz: 0,
                 },
                 crate::CpuCoreInfo {
                     core_id: 3,
                     usage: 40.0,
                     name: "Core 3".to_string(),
+                    frequency_mhz: 0,
                 },
             ],
             top_processes: vec![crate::processes::ProcessInfo {
@@ -185,6 +217,10 @@ mod tests {
                 disk_read_bytes: 0,
                 disk_written_bytes: 0,
                 status: "Running".to_string(),
+                gpu_usage: 0.0,
+                gpu_memory: 0,
+                cgroup: None,
+                container: None,
             }],
             ..Default::default()
         };
@@ -194,7 +230,7 @@ mod tests {
         let ctx = egui::Context::default();
         let _ = ctx.run(Default::default(), |ctx| {
             egui::CentralPanel::default().show(ctx, |ui| {
-                toolbar::paint_process_toolbar(&mut app, ui, 1, 1, true);
+                toolbar::paint_process_toolbar(&mut app, ui, 1, 1, 2, true);
                 let refs: Vec<_> = data.top_processes.iter().collect();
                 table::paint_process_table(&mut app, ui, &refs, &data, true);
             });
@@ -17,11 +17,15 @@ pub(super) fn paint_process_table(
         let pid_w = 60.0;
         let mem_w = 85.0;
         let cpu_w = 70.0;
+        // Per-process GPU accounting only exists on Linux (DRM fdinfo).
+        let show_gpu = cfg!(target_os = "linux");
+        let gpu_w = if show_gpu { 70.0 } else { 0.0 };
         let disk_read_w = 85.0;
         let disk_write_w = 85.0;
         let action_w = 175.0;
 
-        let fixed_w = pid_w + mem_w + cpu_w + disk_read_w + disk_write_w + action_w + (6.0 * spacing);
+        let column_count = if show_gpu { 7.0 } else { 6.0 };
+        let fixed_w = pid_w + mem_w + cpu_w + gpu_w + disk_read_w + disk_write_w + action_w + (column_count * spacing);
         let name_w = (total_w - fixed_w).max(180.0);
 
         // Sticky Header with sortable columns
@@ -87,6 +91,15 @@ pub(super) fn paint_process_table(
                 }
             }
 
+            if show_gpu && header_button(ui, "GPU %", gpu_w, ProcessSortColumn::Gpu, sort_col, sort_asc).clicked() {
+                if app.process_sort_column == ProcessSortColumn::Gpu {
+                    app.process_sort_ascending = !app.process_sort_ascending;
+                } else {
+                    app.process_sort_column = ProcessSortColumn::Gpu;
+                    app.process_sort_ascending = false;
+                }
+            }
+
             if header_button(ui, "Disk Read", disk_read_w, ProcessSortColumn::Disk, sort_col, sort_asc).clicked() {
                 if app.process_sort_column == ProcessSortColumn::Disk {
                     app.process_sort_ascending = !app.process_sort_ascending;
@@ -202,8 +215,15 @@ pub(super) fn paint_process_table(
                             if ui
                                 .add_sized([name_w, row_height], name_btn)
                                 .on_hover_text(format!(
-                                    "Click to inspect {}\nPID: {}\nStatus: {}",
-                                    process.name, process.pid, process.status
+                                    "Click to inspect {}\nPID: {}\nStatus: {}{}",
+                                    process.name,
+                                    process.pid,
+                                    process.status,
+                                    process
+                                        .container
+                                        .as_deref()
+                                        .map(|name| format!("\nContainer: {name}"))
+                                        .unwrap_or_default()
                                 ))
                                 .clicked()
                             {
@@ -254,6 +274,30 @@ pub(super) fn paint_process_table(
                                 ),
                             );
 
+                            // GPU % (busiest engine), with VRAM on hover
+                            if show_gpu {
+                                let gpu_color = if process.gpu_usage > 50.0 {
+                                    ThemePalette::STATUS_CRITICAL
+                                } else if process.gpu_usage > 15.0 {
+                                    ThemePalette::STATUS_WARNING
+                                } else if process.gpu_usage > 0.05 || process.gpu_memory > 0 {
+                                    ThemePalette::text_primary(is_dark)
+                                } else {
+                                    ThemePalette::text_dimmed(is_dark)
+                                };
+
+                                ui.add_sized(
+                                    [gpu_w, row_height],
+                                    egui::Label::new(
+                                        egui::RichText::new(format!("{:.1}%", process.gpu_usage))
+                                            .monospace()
+                                            .size(11.5)
+                                            .color(gpu_color),
+                                    ),
+                                )
+                                .on_hover_text(format!("GPU memory: {:.1} MB", bytes_to_mb(process.gpu_memory)));
+                            }
+
                             // Disk Read & Write
                             let refresh_interval = app.settings.refresh_interval.max(1);
                             let effective_elapsed = if refresh_interval > 0 {
@@ -356,32 +400,8 @@ pub(super) fn paint_process_table(
                                         });
 
                                         ui.menu_button("Set CPU Affinity ▸", |ui| {
-                                            let num_cores = data.cpu_cores.len().max(1);
-                                            let all_mask = if num_cores >= 64 {
-                                                usize::MAX
-                                            } else {
-                                                (1usize << num_cores) - 1
-                                            };
-                                            if ui.button("All Cores (Default)").clicked() {
-                                                app.affinity_change = Some((process.pid, all_mask));
-                                                ui.close_menu();
-                                            }
-                                            if num_cores > 1 {
-                                                if ui.button("Core 0 Only (0x1)").clicked() {
-                                                    app.affinity_change = Some((process.pid, 1));
-                                                    ui.close_menu();
-                                                }
-                                                if ui.button("Core 1 Only (0x2)").clicked() {
-                                                    app.affinity_change = Some((process.pid, 2));
-                                                    ui.close_menu();
-                                                }
-                                                if num_cores >= 4 {
-                                                    let half_mask = (1usize << (num_cores / 2)) - 1;
-                                                    if ui.button(format!("First {} Cores", num_cores / 2)).clicked() {
-                                                        app.affinity_change = Some((process.pid, half_mask));
-                                                        ui.close_menu();
-                                                    }
-                                                }
+                                            if let Some(group) = affinity_menu(ui, data) {
+                                                app.affinity_change = Some((process.pid, group));
                                             }
                                         });
 
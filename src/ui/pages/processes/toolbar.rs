@@ -7,6 +7,7 @@ pub(super) fn paint_process_toolbar(
     ui: &mut egui::Ui,
     filtered_count: usize,
     total_count: usize,
+    container_count: usize,
     is_dark: bool,
 ) {
     card_frame(is_dark).show(ui, |ui| {
@@ -32,6 +33,34 @@ pub(super) fn paint_process_toolbar(
             let count_label = format!("Showing {} / {}", filtered_count, total_count);
             status_pill(ui, &count_label, ThemePalette::ACCENT_PRIMARY, is_dark);
 
+            // cgroup v2 accounting only exists on Linux.
+            if cfg!(target_os = "linux") {
+                ui.add_space(8.0);
+                if ui
+                    .selectable_label(app.process_group_by_cgroup, "Group by cgroup")
+                    .on_hover_text("Show per-cgroup totals and limit usage for slices, services and containers")
+                    .clicked()
+                {
+                    app.process_group_by_cgroup = !app.process_group_by_cgroup;
+                    app.process_group_by_container = false;
+                }
+            }
+
+            if container_count > 0 {
+                ui.add_space(4.0);
+                if ui
+                    .selectable_label(
+                        app.process_group_by_container,
+                        format!("Containers ({container_count})"),
+                    )
+                    .on_hover_text("Show Docker/Podman containers with their usage and member processes")
+                    .clicked()
+                {
+                    app.process_group_by_container = !app.process_group_by_container;
+                    app.process_group_by_cgroup = false;
+                }
+            }
+
             // Right-aligned management actions
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 if ui
@@ -84,7 +84,11 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
             });
             ui.add_space(4.0);
             ui.label(
-                egui::RichText::new("Frees physical memory by trimming working sets. Windows will smoothly reload active pages as needed.")
+                egui::RichText::new(if cfg!(target_os = "windows") {
+                    "Frees physical memory by trimming working sets. Windows will smoothly reload active pages as needed."
+                } else {
+                    "Frees physical memory by paging out idle process memory. The kernel reloads pages on next use."
+                })
                     .size(12.0)
                     .color(ThemePalette::text_secondary(is_dark)),
             );
@@ -104,6 +108,17 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
                 if ui.add_sized([ui.available_width(), 34.0], btn).clicked() {
                     app.start_ram_clean(ui.ctx());
                 }
+
+                if cfg!(target_os = "linux") {
+                    ui.add_space(6.0);
+                    if ui
+                        .button("Drop Page Cache (requires root)")
+                        .on_hover_text("Flushes dirty pages and discards cached file data via vm.drop_caches")
+                        .clicked()
+                    {
+                        app.queue_action(crate::app::commands::ActionCommand::DropPageCache);
+                    }
+                }
             });
         });
 
@@ -197,7 +212,11 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
                 if ui
                     .checkbox(
                         &mut app.ram_cleaner_state.auto_clean_smart_only,
-                        "Smart Clean (Skip focused foreground application)",
+                        if cfg!(target_os = "windows") {
+                            "Smart Clean (Skip focused foreground application)"
+                        } else {
+                            "Smart Clean (Skip processes that are currently running)"
+                        },
                     )
                     .changed()
                 {
@@ -3,12 +3,13 @@ use crate::ui::theme::ThemePalette;
 use crate::*;
 use eframe::egui;
 
-/// Resolves semantic color for Windows service states.
+/// Resolves semantic color for service states (Windows SCM and systemd labels).
 pub(crate) fn service_state_color(state: &str, is_dark: bool) -> egui::Color32 {
     match state.to_lowercase().as_str() {
         "running" => ThemePalette::STATUS_HEALTHY,
         "stopped" => ThemePalette::text_dimmed(is_dark),
-        "paused" | "start pending" | "stop pending" | "continue pending" | "pause pending" => {
+        "failed" => ThemePalette::STATUS_CRITICAL,
+        "paused" | "start pending" | "stop pending" | "continue pending" | "pause pending" | "restarting" => {
             ThemePalette::STATUS_WARNING
         }
         _ => ThemePalette::text_secondary(is_dark),
@@ -37,10 +38,14 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
     ui.add_space(2.0);
     ui.horizontal(|ui| {
         ui.label(
-            egui::RichText::new("Windows Services")
-                .size(15.5)
-                .strong()
-                .color(ThemePalette::text_primary(is_dark)),
+            egui::RichText::new(if cfg!(target_os = "windows") {
+                "Windows Services"
+            } else {
+                "System Services"
+            })
+            .size(15.5)
+            .strong()
+            .color(ThemePalette::text_primary(is_dark)),
         );
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -59,12 +64,12 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
             ui.add_space(8.0);
 
             // Open Windows Services MMC Console
+            #[cfg(target_os = "windows")]
             if ui
                 .button(egui::RichText::new("⚙ services.msc").size(11.0).strong())
                 .on_hover_text("Open Windows Services Management Console (services.msc)")
                 .clicked()
             {
-                #[cfg(target_os = "windows")]
                 let _ = std::process::Command::new("cmd")
                     .args(["/c", "start", "services.msc"])
                     .spawn();
@@ -92,8 +97,7 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
         card_frame(is_dark).show(ui, |ui| {
             ui.horizontal(|ui| {
                 ui.label(
-                    egui::RichText::new("Loading Windows services telemetry...")
-                        .color(ThemePalette::text_secondary(is_dark)),
+                    egui::RichText::new("Loading services telemetry...").color(ThemePalette::text_secondary(is_dark)),
                 );
             });
         });
@@ -1,3 +1,4 @@
+use crate::app::models::MemoryAlertBasis;
 use crate::ui::components::*;
 use crate::ui::theme::ThemePalette;
 use eframe::egui;
@@ -30,12 +31,47 @@ pub(super) fn paint_alerts_settings(
                     .changed();
                 ui.end_row();
 
-                ui.label(
-                    egui::RichText::new("Memory usage alert threshold:").color(ThemePalette::text_secondary(is_dark)),
-                );
-                *changed |= ui
-                    .add(egui::Slider::new(&mut app.settings.notification_memory_threshold, 50.0..=100.0).suffix(" %"))
-                    .changed();
+                ui.label(egui::RichText::new("Memory alert based on:").color(ThemePalette::text_secondary(is_dark)));
+                ui.horizontal(|ui| {
+                    for (basis, label) in [
+                        (MemoryAlertBasis::Used, "Used memory"),
+                        (MemoryAlertBasis::Available, "Available memory"),
+                    ] {
+                        *changed |= ui
+                            .selectable_value(&mut app.settings.memory_alert_basis, basis, label)
+                            .changed();
+                    }
+                })
+                .response
+                .on_hover_text("Page cache counts as used but is released on demand. Available memory only drops when applications actually need more.");
+                ui.end_row();
+
+                match app.settings.memory_alert_basis {
+                    MemoryAlertBasis::Used => {
+                        ui.label(
+                            egui::RichText::new("Memory usage alert threshold:")
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                        *changed |= ui
+                            .add(
+                                egui::Slider::new(&mut app.settings.notification_memory_threshold, 50.0..=100.0)
+                                    .suffix(" %"),
+                            )
+                            .changed();
+                    }
+                    MemoryAlertBasis::Available => {
+                        ui.label(
+                            egui::RichText::new("Alert when available memory below:")
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                        *changed |= ui
+                            .add(
+                                egui::Slider::new(&mut app.settings.notification_memory_available_threshold, 1.0..=50.0)
+                                    .suffix(" %"),
+                            )
+                            .changed();
+                    }
+                }
                 ui.end_row();
 
                 ui.label(
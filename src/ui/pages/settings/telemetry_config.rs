@@ -40,6 +40,25 @@ pub(super) fn paint_telemetry_settings(
                     .changed();
             });
         });
+
+        // The Engine API client speaks HTTP over a Unix socket.
+        if cfg!(target_os = "linux") {
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                ui.label("Container engine socket:");
+                *changed |= ui
+                    .add(
+                        egui::TextEdit::singleline(&mut app.settings.container_socket)
+                            .hint_text("auto-detect Docker / Podman")
+                            .desired_width(260.0),
+                    )
+                    .on_hover_text(
+                        "Path to a Docker-compatible API socket, e.g. /var/run/docker.sock or \
+                         $XDG_RUNTIME_DIR/podman/podman.sock. Leave empty to try $DOCKER_HOST and the usual locations.",
+                    )
+                    .changed();
+            });
+        }
     });
 
     ui.add_space(10.0);
@@ -71,10 +71,11 @@ pub(crate) fn paint_filter_bar(app: &mut crate::SystemMonitorApp, ui: &mut egui:
                     .strong()
                     .color(ThemePalette::text_secondary(is_dark)),
             );
+            let (signed_label, unsigned_label) = crate::startup::provenance_labels();
             egui::ComboBox::from_id_source("startup_signed_filter")
                 .selected_text(match app.startup_filter_signed {
-                    Some(true) => "Signed",
-                    Some(false) => "Unsigned",
+                    Some(true) => signed_label,
+                    Some(false) => unsigned_label,
                     None => "All",
                 })
                 .show_ui(ui, |ui: &mut egui::Ui| {
@@ -85,13 +86,13 @@ pub(crate) fn paint_filter_bar(app: &mut crate::SystemMonitorApp, ui: &mut egui:
                         app.startup_filter_signed = None;
                     }
                     if ui
-                        .selectable_label(app.startup_filter_signed == Some(true), "Signed")
+                        .selectable_label(app.startup_filter_signed == Some(true), signed_label)
                         .clicked()
                     {
                         app.startup_filter_signed = Some(true);
                     }
                     if ui
-                        .selectable_label(app.startup_filter_signed == Some(false), "Unsigned")
+                        .selectable_label(app.startup_filter_signed == Some(false), unsigned_label)
                         .clicked()
                     {
                         app.startup_filter_signed = Some(false);
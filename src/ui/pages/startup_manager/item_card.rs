@@ -25,12 +25,18 @@ pub(crate) fn paint_startup_item_card(
             status_pill(ui, badge_text, badge_color, is_dark);
 
             // Signed/Verified Publisher Badge
+            let (signed_label, unsigned_label) = startup::provenance_labels();
             match item.is_signed {
                 Some(true) => {
-                    status_pill(ui, "SIGNED", ThemePalette::STATUS_HEALTHY, is_dark);
+                    status_pill(ui, &signed_label.to_uppercase(), ThemePalette::STATUS_HEALTHY, is_dark);
                 }
                 Some(false) => {
-                    status_pill(ui, "UNSIGNED", ThemePalette::STATUS_CRITICAL, is_dark);
+                    status_pill(
+                        ui,
+                        &unsigned_label.to_uppercase(),
+                        ThemePalette::STATUS_CRITICAL,
+                        is_dark,
+                    );
                 }
                 None => {}
             }
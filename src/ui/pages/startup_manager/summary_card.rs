@@ -45,9 +45,17 @@ pub(crate) fn paint_summary_card(app: &mut crate::SystemMonitorApp, ui: &mut egu
                     status_pill(ui, &format!("BOOT: {:.1}s", secs), c, is_dark);
                     boot_shown = true;
                 }
+                if let Some(phases) = startup::boot_phase_summary(bd) {
+                    ui.label(
+                        egui::RichText::new(phases)
+                            .size(11.0)
+                            .color(ThemePalette::text_secondary(is_dark)),
+                    );
+                }
             }
             if !boot_shown {
-                if privilege::is_app_elevated() {
+                // systemd-analyze needs no privileges; on Windows the event log does.
+                if privilege::is_app_elevated() || cfg!(target_os = "linux") {
                     status_pill(ui, "BOOT: UNKNOWN", ThemePalette::STATUS_WARNING, is_dark);
                 } else {
                     status_pill(ui, "BOOT: ADMIN REQ", ThemePalette::STATUS_WARNING, is_dark);
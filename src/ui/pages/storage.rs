@@ -63,7 +63,70 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
 
         ui.add_space(10.0);
 
-        // ── 2. Storage Volume Cards ──
+        // ── 2. Per-Device Block I/O (Linux /proc/diskstats) ──
+        if !data.block_devices.is_empty() {
+            card_frame(is_dark).show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new("BLOCK DEVICE ACTIVITY")
+                        .size(11.0)
+                        .strong()
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                ui.add_space(8.0);
+
+                egui::Grid::new("block_device_grid")
+                    .num_columns(7)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        for heading in ["Device", "Busy", "Read", "Write", "IOPS (R/W)", "Await", "Queue"] {
+                            ui.label(
+                                egui::RichText::new(heading)
+                                    .size(11.0)
+                                    .strong()
+                                    .color(ThemePalette::text_secondary(is_dark)),
+                            );
+                        }
+                        ui.end_row();
+
+                        for device in &data.block_devices {
+                            let busy_color = get_usage_color(device.util_percent as f32);
+                            let value = |ui: &mut egui::Ui, text: String| {
+                                ui.label(
+                                    egui::RichText::new(text)
+                                        .monospace()
+                                        .color(ThemePalette::text_primary(is_dark)),
+                                );
+                            };
+                            ui.label(
+                                egui::RichText::new(&device.name)
+                                    .monospace()
+                                    .strong()
+                                    .color(ThemePalette::text_primary(is_dark)),
+                            );
+                            ui.horizontal(|ui| {
+                                ui.set_width(110.0);
+                                ui.label(
+                                    egui::RichText::new(format!("{:>3.0}%", device.util_percent))
+                                        .monospace()
+                                        .strong()
+                                        .color(busy_color),
+                                );
+                                paint_progress_bar(ui, (device.util_percent / 100.0) as f32, busy_color, 6.0, is_dark);
+                            });
+                            value(ui, format_rate(device.read_bytes_per_second / 1_048_576.0));
+                            value(ui, format_rate(device.written_bytes_per_second / 1_048_576.0));
+                            value(ui, format!("{:.0} / {:.0}", device.read_iops, device.write_iops));
+                            value(ui, format!("{:.1} ms", device.await_ms));
+                            value(ui, format!("{:.2}", device.queue_depth));
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            ui.add_space(10.0);
+        }
+
+        // ── 3. Storage Volume Cards ──
         for disk in &data.disk_info {
             let color = get_usage_color(disk.usage_percentage);
             let used_bytes = disk.total_space.saturating_sub(disk.available_space);
@@ -157,6 +220,29 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
                         );
                         ui.end_row();
 
+                        let device = disk
+                            .block_device
+                            .as_deref()
+                            .and_then(|name| data.block_devices.iter().find(|device| device.name == name));
+                        ui.label(
+                            egui::RichText::new("Block Device:")
+                                .size(11.5)
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                        ui.label(
+                            egui::RichText::new(device.map_or_else(
+                                || disk.block_device.clone().unwrap_or_else(|| "—".to_string()),
+                                |device| {
+                                    format!(
+                                        "{} · {:.0}% busy · {:.1} ms",
+                                        device.name, device.util_percent, device.await_ms
+                                    )
+                                },
+                            ))
+                            .monospace()
+                            .color(ThemePalette::text_primary(is_dark)),
+                        );
+
                         ui.label(
                             egui::RichText::new("Total Capacity:")
                                 .size(11.5)
@@ -191,7 +277,7 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
             ui.add_space(8.0);
         }
 
-        // ── 3. Physical Drive Hardware & S.M.A.R.T. Health (Cached from background thread) ──
+        // ── 4. Physical Drive Hardware & S.M.A.R.T. Health (Cached from background thread) ──
         let physical_drives = &data.physical_disks;
         if !physical_drives.is_empty() {
             ui.add_space(6.0);
@@ -227,10 +313,10 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
                             status_pill(ui, &drive.media_type, ThemePalette::ACCENT_PRIMARY, is_dark);
 
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                let smart_color = if drive.status == "HEALTHY" {
-                                    ThemePalette::STATUS_HEALTHY
-                                } else {
-                                    ThemePalette::STATUS_CRITICAL
+                                let smart_color = match drive.status.as_str() {
+                                    "HEALTHY" => ThemePalette::STATUS_HEALTHY,
+                                    "WARNING" | "UNKNOWN" => ThemePalette::STATUS_WARNING,
+                                    _ => ThemePalette::STATUS_CRITICAL,
                                 };
                                 status_pill(ui, &drive.smart_status, smart_color, is_dark);
                             });
@@ -252,14 +338,30 @@ pub(crate) fn show(_app: &crate::SystemMonitorApp, ui: &mut egui::Ui, data: &Sys
                                     .size(10.5)
                                     .color(ThemePalette::text_dimmed(is_dark)),
                             );
+                            if let Some(temp) = drive.temperature_c {
+                                ui.add_space(12.0);
+                                ui.label(
+                                    egui::RichText::new(format!("Temp: {}°C", temp))
+                                        .monospace()
+                                        .size(11.0)
+                                        .color(ThemePalette::text_secondary(is_dark)),
+                                );
+                            }
                             if let Some(wear) = drive.wear_percentage {
+                                let wear_color = if wear < 70 {
+                                    ThemePalette::STATUS_HEALTHY
+                                } else if wear < 90 {
+                                    ThemePalette::STATUS_WARNING
+                                } else {
+                                    ThemePalette::STATUS_CRITICAL
+                                };
                                 ui.add_space(12.0);
                                 ui.label(
-                                    egui::RichText::new(format!("Estimated Life: {}%", wear))
+                                    egui::RichText::new(format!("Wear: {}% used", wear))
                                         .monospace()
                                         .size(11.0)
                                         .strong()
-                                        .color(ThemePalette::STATUS_HEALTHY),
+                                        .color(wear_color),
                                 );
                             }
                         });
@@ -54,9 +54,13 @@ pub(crate) fn paint_power_schemes_card(
         // Active Power Plan Switcher
         ui.horizontal(|ui| {
             ui.label(
-                egui::RichText::new("Windows Power Scheme:")
-                    .size(11.5)
-                    .color(ThemePalette::text_secondary(is_dark)),
+                egui::RichText::new(if cfg!(target_os = "windows") {
+                    "Windows Power Scheme:"
+                } else {
+                    "Power Profile:"
+                })
+                .size(11.5)
+                .color(ThemePalette::text_secondary(is_dark)),
             );
             for plan in power_plans {
                 let is_active = plan.is_active;
@@ -103,58 +107,85 @@ pub(crate) fn paint_power_schemes_card(
 }
 
 /// Renders the detailed Battery Health & Power Management diagnostics card if a battery is present.
+/// Capacity figures come from `battery_health`, falling back to `Win32_Battery` when
+/// the power-status query could not read them.
 pub(crate) fn paint_battery_diagnostics_card(ui: &mut egui::Ui, data: &SystemData, is_dark: bool) {
-    if let Some(bat) = &data.battery_info {
-        if bat.present {
-            card_frame(is_dark).show(ui, |ui| {
+    let health = &data.battery_health;
+    let legacy = data.battery_info.as_ref().filter(|bat| bat.present);
+    if !health.has_battery && legacy.is_none() {
+        return;
+    }
+    let legacy_capacity =
+        |value: fn(&BatteryInfo) -> u32| legacy.map(value).filter(|capacity| *capacity > 0).map(u64::from);
+    let design_mwh = health
+        .design_capacity_mwh
+        .or_else(|| legacy_capacity(|bat| bat.design_capacity));
+    let full_mwh = health
+        .full_charge_mwh
+        .or_else(|| legacy_capacity(|bat| bat.full_charge_capacity));
+    let wear = health
+        .health_percent
+        .or_else(|| match (full_mwh, design_mwh) {
+            (Some(full), Some(design)) => Some(full as f32 / design as f32 * 100.0),
+            _ => None,
+        })
+        .map(|percent| (100.0 - percent).max(0.0));
+    let power_state = legacy.and_then(|bat| bat.discharge_state.clone()).unwrap_or_else(|| {
+        if health.is_charging {
+            "Charging".to_string()
+        } else if health.ac_online {
+            "AC Power".to_string()
+        } else {
+            "Discharging".to_string()
+        }
+    });
+    let mwh_label = |value: Option<u64>| value.map_or_else(|| "N/A".to_string(), |mwh| format!("{mwh} mWh"));
+
+    card_frame(is_dark).show(ui, |ui| {
+        ui.label(
+            egui::RichText::new("BATTERY HEALTH & POWER MANAGEMENT")
+                .size(11.0)
+                .strong()
+                .color(ThemePalette::text_secondary(is_dark)),
+        );
+        ui.add_space(8.0);
+
+        egui::Grid::new("sysinfo_battery_grid")
+            .num_columns(4)
+            .spacing([24.0, 6.0])
+            .show(ui, |ui| {
                 ui.label(
-                    egui::RichText::new("BATTERY HEALTH & POWER MANAGEMENT")
-                        .size(11.0)
-                        .strong()
+                    egui::RichText::new("Design Capacity:")
+                        .size(11.5)
                         .color(ThemePalette::text_secondary(is_dark)),
                 );
-                ui.add_space(8.0);
-
-                egui::Grid::new("sysinfo_battery_grid")
-                    .num_columns(4)
-                    .spacing([24.0, 6.0])
-                    .show(ui, |ui| {
-                        ui.label(
-                            egui::RichText::new("Design Capacity:")
-                                .size(11.5)
-                                .color(ThemePalette::text_secondary(is_dark)),
-                        );
-                        ui.label(
-                            egui::RichText::new(format!("{} mWh", bat.design_capacity))
-                                .monospace()
-                                .strong()
-                                .color(ThemePalette::text_primary(is_dark)),
-                        );
-
-                        ui.label(
-                            egui::RichText::new("Full Charge Capacity:")
-                                .size(11.5)
-                                .color(ThemePalette::text_secondary(is_dark)),
-                        );
-                        ui.label(
-                            egui::RichText::new(format!("{} mWh", bat.full_charge_capacity))
-                                .monospace()
-                                .strong()
-                                .color(ThemePalette::text_primary(is_dark)),
-                        );
-                        ui.end_row();
+                ui.label(
+                    egui::RichText::new(mwh_label(design_mwh))
+                        .monospace()
+                        .strong()
+                        .color(ThemePalette::text_primary(is_dark)),
+                );
 
-                        let wear = if bat.design_capacity > 0 {
-                            100.0 - ((bat.full_charge_capacity as f32 / bat.design_capacity as f32) * 100.0)
-                        } else {
-                            0.0
-                        };
+                ui.label(
+                    egui::RichText::new("Full Charge Capacity:")
+                        .size(11.5)
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                ui.label(
+                    egui::RichText::new(mwh_label(full_mwh))
+                        .monospace()
+                        .strong()
+                        .color(ThemePalette::text_primary(is_dark)),
+                );
+                ui.end_row();
 
-                        ui.label(
-                            egui::RichText::new("Battery Wear Level:")
-                                .size(11.5)
-                                .color(ThemePalette::text_secondary(is_dark)),
-                        );
+                ui.label(
+                    egui::RichText::new("Battery Wear Level:")
+                        .size(11.5)
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                match wear {
+                    Some(wear) => {
                         let wear_color = if wear < 15.0 {
                             ThemePalette::STATUS_HEALTHY
                         } else if wear < 30.0 {
@@ -168,20 +199,43 @@ pub(crate) fn paint_battery_diagnostics_card(ui: &mut egui::Ui, data: &SystemDat
                                 .strong()
                                 .color(wear_color),
                         );
-
+                    }
+                    None => {
                         ui.label(
-                            egui::RichText::new("Power State:")
-                                .size(11.5)
-                                .color(ThemePalette::text_secondary(is_dark)),
-                        );
-                        ui.label(
-                            egui::RichText::new(bat.discharge_state.as_deref().unwrap_or("N/A"))
+                            egui::RichText::new("N/A")
                                 .monospace()
-                                .color(ThemePalette::text_primary(is_dark)),
+                                .color(ThemePalette::text_dimmed(is_dark)),
                         );
-                        ui.end_row();
-                    });
+                    }
+                }
+
+                ui.label(
+                    egui::RichText::new("Power State:")
+                        .size(11.5)
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                ui.label(
+                    egui::RichText::new(power_state)
+                        .monospace()
+                        .color(ThemePalette::text_primary(is_dark)),
+                );
+                ui.end_row();
+
+                ui.label(
+                    egui::RichText::new("Cycle Count:")
+                        .size(11.5)
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                ui.label(
+                    egui::RichText::new(
+                        health
+                            .cycle_count
+                            .map_or_else(|| "N/A".to_string(), |count| count.to_string()),
+                    )
+                    .monospace()
+                    .color(ThemePalette::text_primary(is_dark)),
+                );
+                ui.end_row();
             });
-        }
-    }
+    });
 }
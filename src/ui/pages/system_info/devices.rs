@@ -0,0 +1,118 @@
+use crate::ui::components::*;
+use crate::ui::theme::ThemePalette;
+use crate::*;
+use eframe::egui;
+
+/// Renders the PCI and USB device lists reported by the Linux inventory provider.
+pub(crate) fn paint_devices_card(ui: &mut egui::Ui, data: &SystemData, is_dark: bool) {
+    let hardware = &data.hardware;
+    if hardware.pci_devices.is_empty() && hardware.usb_devices.is_empty() {
+        return;
+    }
+
+    card_frame(is_dark).show(ui, |ui| {
+        if !hardware.pci_devices.is_empty() {
+            section_title(ui, &format!("PCI DEVICES ({})", hardware.pci_devices.len()), is_dark);
+            egui::Grid::new("sysinfo_pci_grid")
+                .num_columns(4)
+                .spacing([18.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    header_row(ui, &["Address", "Class", "Device", "Driver"], is_dark);
+                    for device in &hardware.pci_devices {
+                        let ids = format!("{}:{}", device.vendor_id, device.device_id);
+                        let name = match (&device.vendor, &device.name) {
+                            (Some(vendor), Some(name)) => format!("{vendor} {name}"),
+                            (Some(vendor), None) => format!("{vendor} [{ids}]"),
+                            _ => format!("[{ids}]"),
+                        };
+                        cell(ui, &device.address, true, is_dark);
+                        cell(ui, &device.class, false, is_dark);
+                        cell(ui, &name, false, is_dark).on_hover_text(format!("Vendor:Device ID {ids}"));
+                        cell(ui, device.driver.as_deref().unwrap_or("—"), true, is_dark);
+                        ui.end_row();
+                    }
+                });
+        }
+
+        if !hardware.usb_devices.is_empty() {
+            if !hardware.pci_devices.is_empty() {
+                ui.add_space(8.0);
+                ui.separator();
+                ui.add_space(6.0);
+            }
+            section_title(ui, &format!("USB DEVICES ({})", hardware.usb_devices.len()), is_dark);
+            egui::Grid::new("sysinfo_usb_grid")
+                .num_columns(4)
+                .spacing([18.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    header_row(ui, &["Port", "Device", "ID", "Speed"], is_dark);
+                    for device in &hardware.usb_devices {
+                        let name = match (&device.manufacturer, &device.product) {
+                            (Some(manufacturer), Some(product)) => format!("{manufacturer} {product}"),
+                            (None, Some(product)) => product.clone(),
+                            (Some(manufacturer), None) => manufacturer.clone(),
+                            (None, None) => "Unnamed device".into(),
+                        };
+                        cell(ui, &device.address, true, is_dark);
+                        cell(ui, &name, false, is_dark);
+                        cell(
+                            ui,
+                            &format!("{}:{}", device.vendor_id, device.product_id),
+                            true,
+                            is_dark,
+                        );
+                        cell(ui, &usb_speed_label(device.speed_mbps.as_deref()), false, is_dark);
+                        ui.end_row();
+                    }
+                });
+        }
+    });
+}
+
+/// USB generation for a link speed in Mb/s as reported by sysfs.
+fn usb_speed_label(speed_mbps: Option<&str>) -> String {
+    match speed_mbps {
+        Some("1.5") => "Low speed (1.5 Mb/s)".into(),
+        Some("12") => "Full speed (12 Mb/s)".into(),
+        Some("480") => "High speed (480 Mb/s)".into(),
+        Some("5000") => "SuperSpeed (5 Gb/s)".into(),
+        Some("10000") => "SuperSpeed+ (10 Gb/s)".into(),
+        Some("20000") => "SuperSpeed+ (20 Gb/s)".into(),
+        Some(other) => format!("{other} Mb/s"),
+        None => "—".into(),
+    }
+}
+
+fn section_title(ui: &mut egui::Ui, title: &str, is_dark: bool) {
+    ui.label(
+        egui::RichText::new(title)
+            .size(11.0)
+            .strong()
+            .color(ThemePalette::text_secondary(is_dark)),
+    );
+    ui.add_space(6.0);
+}
+
+fn header_row(ui: &mut egui::Ui, headers: &[&str], is_dark: bool) {
+    for header in headers {
+        ui.label(
+            egui::RichText::new(*header)
+                .size(10.5)
+                .strong()
+                .color(ThemePalette::text_dimmed(is_dark)),
+        );
+    }
+    ui.end_row();
+}
+
+fn cell(ui: &mut egui::Ui, text: &str, monospace: bool, is_dark: bool) -> egui::Response {
+    let mut text = egui::RichText::new(text)
+        .size(11.5)
+        .color(ThemePalette::text_primary(is_dark));
+    if monospace {
+        text = text.monospace();
+    }
+    ui.label(text)
+}
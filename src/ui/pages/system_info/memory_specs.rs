@@ -140,5 +140,67 @@ pub(crate) fn paint_memory_specs_card(ui: &mut egui::Ui, data: &SystemData, is_d
                 is_dark,
             );
         }
+
+        if !data.hardware.memory_modules.is_empty() {
+            ui.add_space(10.0);
+            ui.separator();
+            ui.add_space(6.0);
+            paint_memory_modules(ui, &data.hardware.memory_modules, is_dark);
+        }
     });
 }
+
+/// Installed DIMMs as reported by `dmidecode` (Linux, root only).
+fn paint_memory_modules(ui: &mut egui::Ui, modules: &[MemoryModuleInfo], is_dark: bool) {
+    ui.label(
+        egui::RichText::new(format!("MEMORY MODULES ({})", modules.len()))
+            .size(11.0)
+            .strong()
+            .color(ThemePalette::text_secondary(is_dark)),
+    );
+    ui.add_space(6.0);
+    egui::Grid::new("sysinfo_dimm_grid")
+        .num_columns(5)
+        .spacing([18.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            for header in ["Slot", "Size", "Type / Speed", "Manufacturer", "Part Number"] {
+                ui.label(
+                    egui::RichText::new(header)
+                        .size(10.5)
+                        .strong()
+                        .color(ThemePalette::text_dimmed(is_dark)),
+                );
+            }
+            ui.end_row();
+            for module in modules {
+                let slot = match &module.bank {
+                    Some(bank) => format!("{} ({bank})", module.locator),
+                    None => module.locator.clone(),
+                };
+                let kind = match (&module.kind, module.speed_mts) {
+                    (Some(kind), Some(speed)) => format!("{kind}-{speed}"),
+                    (Some(kind), None) => kind.clone(),
+                    (None, Some(speed)) => format!("{speed} MT/s"),
+                    (None, None) => "—".into(),
+                };
+                let size = format!("{:.0} GB", bytes_to_gb(module.size_bytes));
+                for (value, monospace) in [
+                    (slot.as_str(), true),
+                    (size.as_str(), true),
+                    (kind.as_str(), true),
+                    (module.manufacturer.as_deref().unwrap_or("—"), false),
+                    (module.part_number.as_deref().unwrap_or("—"), true),
+                ] {
+                    let mut text = egui::RichText::new(value)
+                        .size(11.5)
+                        .color(ThemePalette::text_primary(is_dark));
+                    if monospace {
+                        text = text.monospace();
+                    }
+                    ui.label(text);
+                }
+                ui.end_row();
+            }
+        });
+}
@@ -1,5 +1,6 @@
 mod battery_diag;
 mod cpu_arch;
+mod devices;
 mod gpu_display;
 mod memory_specs;
 mod os_platform;
@@ -34,7 +35,13 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui, data: &
         gpu_display::paint_gpu_display_card(ui, data, is_dark);
         ui.add_space(10.0);
 
-        // ── 5. Battery Health (if present) ──
+        // ── 5. PCI & USB Devices (Linux inventory) ──
+        if !data.hardware.pci_devices.is_empty() || !data.hardware.usb_devices.is_empty() {
+            devices::paint_devices_card(ui, data, is_dark);
+            ui.add_space(10.0);
+        }
+
+        // ── 6. Battery Health (if present) ──
         battery_diag::paint_battery_diagnostics_card(ui, data, is_dark);
     });
 }
@@ -56,6 +63,7 @@ mod tests {
                 cpu_brand: "12th Gen Intel(R) Core(TM) i9-12900H".to_string(),
                 cpu_count: 14,
                 os_build: Some("22631.3007".to_string()),
+                hypervisor: None,
                 motherboard: Some("Micro-Star International Co., Ltd. MS-1582".to_string()),
                 bios_version: Some("E1582IMS.10B".to_string()),
                 gpu_driver: Some("551.86".to_string()),
@@ -65,11 +73,13 @@ mod tests {
                     core_id: 0,
                     usage: 12.5,
                     name: "Core 0".to_string(),
+                    frequency_mhz: 0,
                 },
                 CpuCoreInfo {
                     core_id: 1,
                     usage: 8.0,
                     name: "Core 1".to_string(),
+                    frequency_mhz: 0,
                 },
             ],
             cpu_usage: 10.25,
@@ -123,6 +133,7 @@ mod tests {
                 cpu_brand: "AMD EPYC 7763".to_string(),
                 cpu_count: 64,
                 os_build: None,
+                hypervisor: None,
                 motherboard: None,
                 bios_version: None,
                 gpu_driver: None,
@@ -161,6 +172,7 @@ mod tests {
                 cpu_brand: "AMD Ryzen 9 7950X 16-Core Processor".to_string(),
                 cpu_count: 16,
                 os_build: Some("26100.1150".to_string()),
+                hypervisor: None,
                 motherboard: Some("ASUS ROG CROSSHAIR X670E HERO".to_string()),
                 bios_version: Some("2007".to_string()),
                 gpu_driver: Some("552.22".to_string()),
@@ -207,6 +219,47 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_system_info_render_linux_inventory() {
+        let mut app = crate::SystemMonitorApp::test_app();
+        let data = SystemData {
+            hardware: HardwareInventory {
+                pci_devices: vec![PciDeviceInfo {
+                    address: "0000:00:02.0".into(),
+                    vendor_id: "8086".into(),
+                    device_id: "a7a0".into(),
+                    class: "Display controller".into(),
+                    vendor: Some("Intel Corporation".into()),
+                    name: Some("Raptor Lake-P [Iris Xe Graphics]".into()),
+                    driver: Some("i915".into()),
+                }],
+                usb_devices: vec![UsbDeviceInfo {
+                    address: "1-2".into(),
+                    vendor_id: "046d".into(),
+                    product_id: "c52b".into(),
+                    manufacturer: Some("Logitech".into()),
+                    product: Some("USB Receiver".into()),
+                    speed_mbps: Some("12".into()),
+                }],
+                memory_modules: vec![MemoryModuleInfo {
+                    locator: "DIMM_A1".into(),
+                    kind: Some("DDR5".into()),
+                    size_bytes: 16 << 30,
+                    speed_mts: Some(4800),
+                    ..Default::default()
+                }],
+            },
+            ..Default::default()
+        };
+
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                show(&mut app, ui, &data);
+            });
+        });
+    }
+
     #[test]
     fn test_individual_subcomponents() {
         let mut app = crate::SystemMonitorApp::test_app();
@@ -220,6 +273,7 @@ mod tests {
                 cpu_arch::paint_cpu_arch_card(ui, &data, true);
                 memory_specs::paint_memory_specs_card(ui, &data, true);
                 gpu_display::paint_gpu_display_card(ui, &data, true);
+                devices::paint_devices_card(ui, &data, true);
                 battery_diag::paint_battery_diagnostics_card(ui, &data, true);
             });
         });
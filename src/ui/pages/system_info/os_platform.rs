@@ -126,6 +126,35 @@ pub(crate) fn paint_os_platform_card(ui: &mut egui::Ui, data: &SystemData, is_da
                 } else if data.system_info.motherboard.is_some() {
                     ui.end_row();
                 }
+
+                ui.label(
+                    egui::RichText::new("Virtualization:")
+                        .size(11.5)
+                        .color(ThemePalette::text_secondary(is_dark)),
+                );
+                ui.label(
+                    egui::RichText::new(data.system_info.hypervisor.as_deref().unwrap_or("None detected"))
+                        .monospace()
+                        .color(ThemePalette::text_primary(is_dark)),
+                );
+                if let Some(steal) = data
+                    .telemetry_history_stats
+                    .get("cpu.steal_percent")
+                    .filter(|_| data.system_info.hypervisor.is_some())
+                {
+                    ui.label(
+                        egui::RichText::new("CPU Steal (60s avg):")
+                            .size(11.5)
+                            .color(ThemePalette::text_secondary(is_dark)),
+                    );
+                    ui.label(
+                        egui::RichText::new(format!("{:.1}%", steal.sixty_seconds.avg))
+                            .monospace()
+                            .color(ThemePalette::text_primary(is_dark)),
+                    )
+                    .on_hover_text("CPU time the hypervisor gave to other guests while this one had work queued");
+                }
+                ui.end_row();
             });
     });
 }
@@ -163,6 +163,112 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ui: &mut egui::Ui) {
                 });
         });
 
+        if window.metrics.iter().any(|sample| sample.pressure.is_some()) {
+            ui.add_space(10.0);
+            card_frame(is_dark).show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new("PRESSURE STALLS")
+                        .strong()
+                        .size(11.0)
+                        .color(ThemePalette::STATUS_CRITICAL),
+                );
+                let series = |select: fn(&crate::monitoring::snapshot::PressureSnapshot) -> f64| -> PlotPoints {
+                    window
+                        .metrics
+                        .iter()
+                        .step_by(plot_step)
+                        .filter_map(|sample| {
+                            sample
+                                .pressure
+                                .map(|pressure| [seconds_from(origin, sample.timestamp_ms), select(&pressure)])
+                        })
+                        .collect()
+                };
+                Plot::new("timeline_pressure")
+                    .height(190.0)
+                    .legend(Legend::default())
+                    .allow_scroll(false)
+                    .include_y(0.0)
+                    .x_axis_label("Seconds in selected range")
+                    .y_axis_label("Stalled %")
+                    .show(ui, |plot| {
+                        plot.line(
+                            Line::new(series(|pressure| pressure.cpu_some))
+                                .name("CPU")
+                                .color(ThemePalette::ACCENT_PRIMARY),
+                        );
+                        plot.line(
+                            Line::new(series(|pressure| pressure.memory_some))
+                                .name("Memory")
+                                .color(ThemePalette::STATUS_HEALTHY),
+                        );
+                        plot.line(
+                            Line::new(series(|pressure| pressure.memory_full))
+                                .name("Memory (all tasks)")
+                                .color(ThemePalette::STATUS_CRITICAL),
+                        );
+                        plot.line(
+                            Line::new(series(|pressure| pressure.io_some))
+                                .name("I/O")
+                                .color(ThemePalette::STATUS_WARNING),
+                        );
+                        plot.line(
+                            Line::new(series(|pressure| pressure.io_full))
+                                .name("I/O (all tasks)")
+                                .color(ThemePalette::text_secondary(is_dark)),
+                        );
+                    });
+            });
+        }
+
+        if window.metrics.iter().any(|sample| sample.load.is_some()) {
+            ui.add_space(10.0);
+            card_frame(is_dark).show(ui, |ui| {
+                ui.label(
+                    egui::RichText::new("RUN QUEUE")
+                        .strong()
+                        .size(11.0)
+                        .color(ThemePalette::STATUS_WARNING),
+                );
+                let series = |select: fn(&crate::monitoring::snapshot::LoadSnapshot) -> f64| -> PlotPoints {
+                    window
+                        .metrics
+                        .iter()
+                        .step_by(plot_step)
+                        .filter_map(|sample| {
+                            sample
+                                .load
+                                .map(|load| [seconds_from(origin, sample.timestamp_ms), select(&load)])
+                        })
+                        .collect()
+                };
+                Plot::new("timeline_load")
+                    .height(190.0)
+                    .legend(Legend::default())
+                    .allow_scroll(false)
+                    .include_y(0.0)
+                    .x_axis_label("Seconds in selected range")
+                    .y_axis_label("Tasks")
+                    .show(ui, |plot| {
+                        plot.line(
+                            Line::new(series(|load| load.load_1m))
+                                .name("Load (1 min)")
+                                .color(ThemePalette::ACCENT_PRIMARY),
+                        );
+                        plot.line(
+                            Line::new(series(|load| load.procs_running as f64))
+                                .name("Running")
+                                .color(ThemePalette::STATUS_HEALTHY),
+                        );
+                        plot.line(
+                            Line::new(series(|load| load.procs_blocked as f64))
+                                .name("Blocked on I/O")
+                                .color(ThemePalette::STATUS_CRITICAL),
+                        );
+                    });
+            });
+        }
+
         ui.add_space(10.0);
         card_frame(is_dark).show(ui, |ui| {
             ui.label(
@@ -310,6 +416,8 @@ fn event_kind_label(kind: TimelineEventKind) -> &'static str {
         TimelineEventKind::PowerChanged => "Power changed",
         TimelineEventKind::ServiceChanged => "Service changed",
         TimelineEventKind::StartupChanged => "Startup changed",
+        TimelineEventKind::ThrottlingStarted => "Throttling started",
+        TimelineEventKind::ThrottlingEnded => "Throttling ended",
         TimelineEventKind::System => "System event",
     }
 }
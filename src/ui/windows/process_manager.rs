@@ -318,25 +318,8 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ctx: &egui::Context, data:
                                             }
                                         });
                                         ui.menu_button("Set CPU Affinity ▸", |ui| {
-                                            let num_cores = data.cpu_cores.len().max(1);
-                                            let all_mask = if num_cores >= 64 {
-                                                usize::MAX
-                                            } else {
-                                                (1usize << num_cores) - 1
-                                            };
-                                            if ui.button("All Cores (Default)").clicked() {
-                                                app.affinity_change = Some((process.pid, all_mask));
-                                                ui.close_menu();
-                                            }
-                                            if num_cores > 1 {
-                                                if ui.button("Core 0 Only (0x1)").clicked() {
-                                                    app.affinity_change = Some((process.pid, 1));
-                                                    ui.close_menu();
-                                                }
-                                                if ui.button("Core 1 Only (0x2)").clicked() {
-                                                    app.affinity_change = Some((process.pid, 2));
-                                                    ui.close_menu();
-                                                }
+                                            if let Some(group) = affinity_menu(ui, data) {
+                                                app.affinity_change = Some((process.pid, group));
                                             }
                                         });
                                     })
@@ -516,25 +499,8 @@ pub(crate) fn show(app: &mut crate::SystemMonitorApp, ctx: &egui::Context, data:
                                             }
                                         });
                                         ui.menu_button("Set CPU Affinity ▸", |ui| {
-                                            let num_cores = data.cpu_cores.len().max(1);
-                                            let all_mask = if num_cores >= 64 {
-                                                usize::MAX
-                                            } else {
-                                                (1usize << num_cores) - 1
-                                            };
-                                            if ui.button("All Cores (Default)").clicked() {
-                                                app.affinity_change = Some((r.process.pid, all_mask));
-                                                ui.close_menu();
-                                            }
-                                            if num_cores > 1 {
-                                                if ui.button("Core 0 Only (0x1)").clicked() {
-                                                    app.affinity_change = Some((r.process.pid, 1));
-                                                    ui.close_menu();
-                                                }
-                                                if ui.button("Core 1 Only (0x2)").clicked() {
-                                                    app.affinity_change = Some((r.process.pid, 2));
-                                                    ui.close_menu();
-                                                }
+                                            if let Some(group) = affinity_menu(ui, data) {
+                                                app.affinity_change = Some((r.process.pid, group));
                                             }
                                         });
                                     })